@@ -0,0 +1,91 @@
+use clap::{Arg, Command};
+use mahjong_core::{
+    deck::DEFAULT_DECK,
+    game_summary::GameSummary,
+    render::TileFormat,
+    shanten::{ShantenAnalysis, TileAcceptance},
+    Game, TileId,
+};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalyzeHandOpts {
+    pub format: TileFormat,
+    pub hand: String,
+}
+
+fn format_tile(tile: TileId, format: TileFormat) -> String {
+    DEFAULT_DECK.get_sure(tile).render(format)
+}
+
+fn format_acceptance(acceptance: &[TileAcceptance], format: TileFormat) -> String {
+    let total = acceptance.iter().map(|a| a.unseen).sum::<usize>();
+    let tiles = acceptance
+        .iter()
+        .map(|a| format!("{}({})", format_tile(a.tile, format), a.unseen))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!("{} tiles: {}", total, tiles)
+}
+
+fn print_analysis(analysis: &ShantenAnalysis, format: TileFormat) {
+    println!("Shanten: {}", analysis.shanten);
+
+    if analysis.discards.is_empty() {
+        println!(
+            "Acceptance: {}",
+            format_acceptance(&analysis.acceptance, format)
+        );
+        return;
+    }
+
+    for discard in analysis.discards.iter() {
+        println!(
+            "- Discard {}: shanten {}, {}",
+            format_tile(discard.tile, format),
+            discard.shanten,
+            format_acceptance(&discard.acceptance, format)
+        );
+    }
+}
+
+pub fn analyze_hand(opts: AnalyzeHandOpts) {
+    let game = Game::from_summary(&format!("- P1: {}", opts.hand));
+    let game_summary = GameSummary::from_game(&game, &game.players.0[0]).unwrap();
+
+    match game_summary.get_shanten_analysis() {
+        Some(analysis) => print_analysis(&analysis, opts.format),
+        None => println!("Error: the hand could not be analyzed"),
+    }
+}
+
+pub fn get_analyze_hand_command() -> Command {
+    Command::new("analyze-hand")
+        .about("Print the shanten and the tile acceptance of a hand")
+        .arg(
+            Arg::new("hand")
+                .short('t')
+                .long("hand")
+                .required(true)
+                .help("The hand in the summary format or in the MPSZ notation, e.g. 一萬,二萬,三萬 *東,東,東 or 123m [111z]"),
+        )
+        .arg(
+            Arg::new("format")
+                .short('f')
+                .long("format")
+                .value_parser(["ascii", "chinese", "emoji", "english", "unicode"])
+                .default_value("chinese")
+                .help("The format used to print the tiles"),
+        )
+}
+
+pub fn get_analyze_hand_opts(matches: &clap::ArgMatches) -> AnalyzeHandOpts {
+    let hand: &String = matches.get_one("hand").unwrap();
+    let format: &String = matches.get_one("format").unwrap();
+
+    AnalyzeHandOpts {
+        format: TileFormat::from_str(format).unwrap(),
+        hand: hand.clone(),
+    }
+}
@@ -1,10 +1,14 @@
+use crate::analyze_hand::AnalyzeHandOpts;
 use crate::print_game::PrintGameOpts;
+use crate::review_game::ReviewGameOpts;
 use crate::simulate::SimulateOpts;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum AppCommand {
+    AnalyzeHand(AnalyzeHandOpts),
     Simulate(SimulateOpts),
     PrintGame(PrintGameOpts),
+    ReviewGame(ReviewGameOpts),
 }
 
 pub struct App {
@@ -1,6 +1,8 @@
 use crate::{
+    analyze_hand::{get_analyze_hand_command, get_analyze_hand_opts},
     base::{App, AppCommand},
     print_game::{get_print_game_command, get_print_game_opts},
+    review_game::{get_review_game_command, get_review_game_opts},
     simulate::{get_simulate_command, get_simulate_opts},
 };
 use clap::command;
@@ -8,10 +10,14 @@ use clap::command;
 pub async fn parse_args(app: &mut App) {
     let simulate_command = get_simulate_command();
     let print_game_command = get_print_game_command();
+    let analyze_hand_command = get_analyze_hand_command();
+    let review_game_command = get_review_game_command();
 
     let matches = command!()
         .subcommand(simulate_command)
         .subcommand(print_game_command)
+        .subcommand(analyze_hand_command)
+        .subcommand(review_game_command)
         .get_matches();
 
     match matches.subcommand() {
@@ -23,6 +29,14 @@ pub async fn parse_args(app: &mut App) {
             let opts = get_print_game_opts(args_matches);
             app.command = Some(AppCommand::PrintGame(opts));
         }
+        Some(("analyze-hand", args_matches)) => {
+            let opts = get_analyze_hand_opts(args_matches);
+            app.command = Some(AppCommand::AnalyzeHand(opts));
+        }
+        Some(("review-game", args_matches)) => {
+            let opts = get_review_game_opts(args_matches);
+            app.command = Some(AppCommand::ReviewGame(opts));
+        }
         _ => {
             println!("Error: no command specified");
             std::process::exit(1);
@@ -1,13 +1,17 @@
 #![deny(clippy::use_self)]
+use analyze_hand::analyze_hand;
 use base::{App, AppCommand};
 use cli::parse_args;
 use print_game::print_game;
+use review_game::review_game;
 use simulate::run_simulation;
 
+mod analyze_hand;
 mod base;
 mod cli;
 mod log;
 mod print_game;
+mod review_game;
 mod simulate;
 
 #[tokio::main]
@@ -22,10 +26,18 @@ async fn main() {
         AppCommand::Simulate(opts) => {
             run_simulation(opts).await;
         }
+        AppCommand::AnalyzeHand(opts) => {
+            analyze_hand(opts);
+        }
         AppCommand::PrintGame(opts) => {
             print_game(opts).await.unwrap_or_else(|e| {
                 println!("Error: {:?}", e);
             });
         }
+        AppCommand::ReviewGame(opts) => {
+            review_game(opts).await.unwrap_or_else(|e| {
+                println!("Error: {:?}", e);
+            });
+        }
     }
 }
@@ -0,0 +1,115 @@
+use clap::{Arg, Command};
+use mahjong_core::{
+    ai::{get_review_evaluator, review_game as review_decisions, GameDecision, HintAction},
+    deck::DEFAULT_DECK,
+    render::TileFormat,
+    TileId,
+};
+use mahjong_service::db_storage::DBStorage;
+use std::io::Error;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewGameOpts {
+    pub format: TileFormat,
+    pub game_id: String,
+    pub player_id: Option<String>,
+}
+
+fn format_tiles(tiles: &[TileId], format: TileFormat) -> String {
+    tiles
+        .iter()
+        .map(|tile| DEFAULT_DECK.get_sure(*tile).render(format))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn format_action(action: &HintAction, format: TileFormat) -> String {
+    match action {
+        HintAction::Claim { meld } => format!("claim {}", format_tiles(&meld.tiles, format)),
+        HintAction::Discard { tile } => format!("discard {}", format_tiles(&[*tile], format)),
+        HintAction::Kong { meld } => format!("kong {}", format_tiles(&meld.tiles, format)),
+        HintAction::Mahjong => "mahjong".to_string(),
+        HintAction::Meld { meld } => format!("declare {}", format_tiles(&meld.tiles, format)),
+        HintAction::Pass => "pass".to_string(),
+    }
+}
+
+pub async fn review_game(opts: ReviewGameOpts) -> Result<(), Error> {
+    let storage = DBStorage::new_dyn();
+
+    let decisions = storage
+        .get_game_decisions(&opts.game_id)
+        .await
+        .map_err(Error::other)?
+        .into_iter()
+        .filter(|decision| {
+            opts.player_id
+                .as_ref()
+                .is_none_or(|player_id| decision.game_summary.player_id == *player_id)
+        })
+        .collect::<Vec<GameDecision>>();
+
+    if decisions.is_empty() {
+        println!("No decisions recorded for the game {}", opts.game_id);
+        return Ok(());
+    }
+
+    let review = review_decisions(&decisions, &mut get_review_evaluator());
+
+    println!(
+        "Reviewed decisions: {}, mistakes: {}",
+        review.decisions,
+        review.mistakes.len()
+    );
+
+    for mistake in review.mistakes.iter() {
+        println!(
+            "- Round {}, turn {}, player {}: {} (recommended: {}), estimated loss: {:.2}",
+            mistake.round_wind,
+            mistake.turn,
+            mistake.player_id,
+            format_action(&mistake.action, opts.format),
+            format_action(&mistake.recommended, opts.format),
+            mistake.estimated_loss
+        );
+    }
+
+    Ok(())
+}
+
+pub fn get_review_game_command() -> Command {
+    Command::new("review-game")
+        .about("Review the recorded decisions of the players in a game")
+        .arg(
+            Arg::new("game-id")
+                .short('i')
+                .required(true)
+                .help("The ID of the game to review"),
+        )
+        .arg(
+            Arg::new("player-id")
+                .short('p')
+                .help("Only review the decisions of this player"),
+        )
+        .arg(
+            Arg::new("format")
+                .short('f')
+                .long("format")
+                .value_parser(["ascii", "chinese", "emoji", "english", "unicode"])
+                .default_value("chinese")
+                .help("The format used to print the tiles"),
+        )
+}
+
+pub fn get_review_game_opts(matches: &clap::ArgMatches) -> ReviewGameOpts {
+    let game_id: &String = matches.get_one("game-id").unwrap();
+    let player_id: Option<&String> = matches.get_one("player-id");
+    let format: &String = matches.get_one("format").unwrap();
+
+    ReviewGameOpts {
+        format: TileFormat::from_str(format).unwrap(),
+        game_id: game_id.clone(),
+        player_id: player_id.cloned(),
+    }
+}
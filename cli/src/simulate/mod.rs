@@ -1,7 +1,7 @@
 use std::process;
 
 use mahjong_core::{
-    ai::{PlayActionResult, StandardAI},
+    ai::{Metadata, PlayActionResult, StandardAI},
     Game, GamePhase,
 };
 use rustc_hash::FxHashSet;
@@ -18,9 +18,41 @@ struct HistoryItem {
     result: PlayActionResult,
 }
 
+fn print_metadata(metadata: &Option<Metadata>) {
+    let Some(metadata) = metadata else {
+        return;
+    };
+
+    for candidate in metadata.candidates.iter() {
+        let score = candidate
+            .score
+            .map(|score| score.to_string())
+            .unwrap_or("-".to_string());
+        println!(
+            "  candidate (player {}, score {score}): {:?}",
+            candidate.player_id, candidate.action
+        );
+    }
+    for rejected in metadata.rejected.iter() {
+        println!(
+            "  rejected (player {}, {:?}): {:?}",
+            rejected.player_id, rejected.error, rejected.action
+        );
+    }
+    if let Some(chosen) = &metadata.chosen {
+        let source = metadata
+            .chosen_source
+            .map(|source| format!(" ({source:?})"))
+            .unwrap_or_default();
+        println!("  chosen{source}: {chosen:?}");
+    }
+}
+
 pub async fn run_simulation(opts: SimulateOpts) {
     let mut stats = Stats::new();
 
+    println!("Agents: {:?}\n", opts.agents);
+
     loop {
         let mut game = Game::new(None);
         let mut history: Option<Vec<HistoryItem>> =
@@ -34,6 +66,11 @@ pub async fn run_simulation(opts: SimulateOpts) {
         let ai_players = FxHashSet::from_iter(game.players.0.clone());
         let mut game_ai = StandardAI::new(&mut game, ai_players, auto_stop_claim_meld);
 
+        for (index, player) in game_ai.game.players.0.clone().iter().enumerate() {
+            let agent_kind = opts.agents[index.min(opts.agents.len() - 1)];
+            game_ai.set_agent(player, agent_kind.create_agent());
+        }
+
         game_ai.dealer_order_deterministic = Some(false);
         game_ai.can_draw_round = true;
 
@@ -69,7 +106,11 @@ pub async fn run_simulation(opts: SimulateOpts) {
                                 break;
                             }
                         }
-                        println!("- {:?}", history_item.result);
+                        println!(
+                            "- {:?} {:?}",
+                            history_item.result.exit_location, history_item.result.changed
+                        );
+                        print_metadata(&history_item.result.metadata);
                         println!("{}", history_item.game.get_summary());
                         println!("\n\n\n");
                     }
@@ -77,8 +118,9 @@ pub async fn run_simulation(opts: SimulateOpts) {
                 println!(
                     "Current state:\n{}\n{:?}",
                     game_ai.game.get_summary(),
-                    result
+                    result.exit_location
                 );
+                print_metadata(&result.metadata);
                 process::exit(1);
             }
 
@@ -1,7 +1,10 @@
 use clap::{Arg, ArgAction, Command};
+use mahjong_core::ai::AgentKind;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct SimulateOpts {
+    pub agents: Vec<AgentKind>,
     pub once: bool,
     pub debug: bool,
 }
@@ -21,13 +24,30 @@ pub fn get_simulate_command() -> Command {
                 .help("Store debugging information to troubleshoot issues")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("agents")
+                .short('a')
+                .long("agents")
+                .help("The AI of each player separated by commas (greedy, random, search, shanten), the last one is used for the rest, e.g. shanten,random")
+                .default_value("shanten"),
+        )
 }
 
 pub fn get_simulate_opts(matches: &clap::ArgMatches) -> SimulateOpts {
     let once: Option<&bool> = matches.get_one("once");
     let debug: Option<&bool> = matches.get_one("debug");
+    let agents: &String = matches.get_one("agents").unwrap();
 
     SimulateOpts {
+        agents: agents
+            .split(',')
+            .map(|agent| {
+                AgentKind::from_str(agent.trim()).unwrap_or_else(|_| {
+                    println!("Error: unknown agent {agent}");
+                    std::process::exit(1);
+                })
+            })
+            .collect(),
         once: once == Some(&true),
         debug: debug == Some(&true),
     }
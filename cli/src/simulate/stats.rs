@@ -1,5 +1,5 @@
 use chrono::Utc;
-use mahjong_core::{Game, PlayerId};
+use mahjong_core::{Game, PlayerId, ScoreItem};
 use rustc_hash::FxHashMap;
 use std::ops::{Add, Div};
 
@@ -18,7 +18,7 @@ pub struct Stats {
     games_per_second: Vec<(f32, usize)>,
     rounds_num: Vec<u32>,
     start_time: chrono::NaiveTime,
-    top_scores: Vec<u32>,
+    top_scores: Vec<ScoreItem>,
     winners: FxHashMap<PlayerId, u32>,
 }
 
@@ -0,0 +1,76 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use mahjong_core::{
+    ai::StandardAI, meld::PlayerDiff, ruleset::HongKongRuleset, Game, GamePhase, Hand,
+};
+use rustc_hash::FxHashSet;
+use std::hint::black_box;
+
+const COMPLETE_HAND: &str = "一萬,一萬,一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,九萬,九萬,東";
+const READY_HAND: &str = "一萬,一萬,一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,九萬,九萬";
+const MIXED_HAND: &str = "二萬,三萬,四萬,四萬,五萬,三索,三索,四索,七索,八索,二筒,三筒,中,中";
+
+fn bench_hand(c: &mut Criterion) {
+    let complete_hand = Hand::from_summary(COMPLETE_HAND);
+    let ready_hand = Hand::from_summary(READY_HAND);
+    let mixed_hand = Hand::from_summary(MIXED_HAND);
+    let ruleset = HongKongRuleset::default();
+
+    c.bench_function("get_possible_melds", |b| {
+        b.iter(|| {
+            black_box(&mixed_hand).get_possible_melds(&ruleset, PlayerDiff::None, None, false)
+        })
+    });
+
+    c.bench_function("is_ready", |b| {
+        b.iter(|| black_box(&complete_hand).is_ready(&ruleset))
+    });
+
+    c.bench_function("get_waiting_tiles", |b| {
+        b.iter(|| black_box(&ready_hand).get_waiting_tiles(&ruleset))
+    });
+
+    c.bench_function("get_shanten", |b| {
+        b.iter(|| black_box(&mixed_hand).get_shanten(&ruleset))
+    });
+}
+
+fn bench_game(c: &mut Criterion) {
+    let mut game = Game::from_summary(
+        "- P1: 二萬,三萬,四萬,四萬,五萬,三索,三索,四索,七索,八索,二筒,三筒,中,中
+         - P2: 一萬,五萬,六萬,三索,四索,五索,九索,九索,一筒,二筒,四筒,東,東
+         - P3: 一萬,二萬,三萬,七萬,八萬,五索,六索,三筒,三筒,七筒,八筒,南,南
+         - P4: 六萬,七萬,八萬,一索,二索,四筒,五筒,六筒,九筒,九筒,西,西,中
+         Turn: P1, Phase: Playing",
+    );
+
+    c.bench_function("get_possible_melds_by_discard", |b| {
+        b.iter(|| black_box(&mut game).get_possible_melds_by_discard())
+    });
+}
+
+// Plays the first rounds of a game with the AI, which is what `cli simulate` does
+fn bench_simulation(c: &mut Criterion) {
+    c.bench_function("simulate_rounds", |b| {
+        b.iter(|| {
+            let mut game = Game::new(None);
+
+            for player in 0..Game::get_players_num(&game.style) {
+                game.players.push(player.to_string());
+            }
+
+            let ai_players = FxHashSet::from_iter(game.players.0.clone());
+            let mut game_ai = StandardAI::new(&mut game, ai_players, FxHashSet::default());
+            game_ai.dealer_order_deterministic = Some(false);
+            game_ai.can_draw_round = true;
+
+            while game_ai.game.round.round_index < 2 && game_ai.game.phase != GamePhase::End {
+                if !game_ai.play_action(false).changed {
+                    break;
+                }
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_hand, bench_game, bench_simulation);
+criterion_main!(benches);
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Animal } from "./Animal";
+
+export type AnimalTile = { id: number, value: Animal, };
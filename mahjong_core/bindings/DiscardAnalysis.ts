@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TileAcceptance } from "./TileAcceptance";
+
+export type DiscardAnalysis = { acceptance: Array<TileAcceptance>, shanten: number, tile: number, };
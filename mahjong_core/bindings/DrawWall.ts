@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WallSegment } from "./WallSegment";
+import type { Wind } from "./Wind";
+
+export type DrawWall = { segments: { [key: Wind]: WallSegment }, dead_wall: WallSegment, unordered: Array<number>, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GameSummary } from "./GameSummary";
+import type { HintAction } from "./HintAction";
+
+export type GameDecision = { action: HintAction, game_summary: GameSummary, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HandTile } from "./HandTile";
+import type { KongTile } from "./KongTile";
+import type { MeldSupplier } from "./MeldSupplier";
+
+export type Hand = { list: Array<HandTile>, kong_tiles: Array<KongTile>, meld_suppliers: Array<MeldSupplier>, is_dead: boolean, is_revealed: boolean, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PossibleMeld } from "./PossibleMeld";
+
+export type HintAction = { "type": "Claim", meld: PossibleMeld, } | { "type": "Discard", tile: number, } | { "type": "Kong", meld: PossibleMeld, } | { "type": "Mahjong" } | { "type": "Meld", meld: PossibleMeld, } | { "type": "Pass" };
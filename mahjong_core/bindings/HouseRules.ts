@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AbortiveDraw } from "./AbortiveDraw";
+import type { DiscardWinners } from "./DiscardWinners";
+
+export type HouseRules = { abortive_draws: Array<AbortiveDraw>, chombo_dead_hand: boolean, chombo_penalty: number | null, chow_only_from_left: boolean, concealed_kongs_shown: boolean, dealer_keeps_seat_if_ready: boolean, discard_winners: DiscardWinners, exhaustive_draw_passes_dealer: boolean, flowers_count: boolean, liability_payments: boolean, ready_hand_payment: number | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ActionCandidate } from "./ActionCandidate";
+import type { AgentAction } from "./AgentAction";
+import type { ChoiceSource } from "./ChoiceSource";
+import type { RejectedAction } from "./RejectedAction";
+
+export type Metadata = { candidates: Array<ActionCandidate>, chosen: AgentAction | null, chosen_source: ChoiceSource | null, rejected: Array<RejectedAction>, };
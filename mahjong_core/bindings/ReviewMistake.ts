@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HintAction } from "./HintAction";
+import type { Wind } from "./Wind";
+
+export type ReviewMistake = { action: HintAction, estimated_loss: number, player_id: string, recommended: HintAction, round_wind: Wind, turn: number, };
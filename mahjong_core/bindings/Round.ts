@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RoundDiscard } from "./RoundDiscard";
+import type { RoundOutcome } from "./RoundOutcome";
+import type { RoundTileClaimed } from "./RoundTileClaimed";
+import type { ScorePayment } from "./ScorePayment";
+import type { Wind } from "./Wind";
+
+export type Round = { consecutive_same_seats: number, dealer_player_index: number, player_index: number, east_player_index: number, round_index: number, tile_claimed: RoundTileClaimed | null, wall_tile_drawn: number | null, wind: Wind, initial_winds: number | null, payments: Array<ScorePayment>, last_outcome: RoundOutcome | null, discards: Array<RoundDiscard>, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AbortiveDraw } from "./AbortiveDraw";
+
+export type RoundOutcome = { "AbortiveDraw": AbortiveDraw } | { "ExhaustiveDraw": Array<string> } | { "Mahjong": Array<string> };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RoundDiscard } from "./RoundDiscard";
+import type { Wind } from "./Wind";
+
+export type RoundSummary = { consecutive_same_seats: number, dealer_player_index: number, east_player_index: number, discarded_tile: number | null, discards: Array<RoundDiscard>, player_index: number, wind: Wind, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AnimalTile } from "./AnimalTile";
+import type { DragonTile } from "./DragonTile";
+import type { FlowerTile } from "./FlowerTile";
+import type { SeasonTile } from "./SeasonTile";
+import type { SuitTile } from "./SuitTile";
+import type { WindTile } from "./WindTile";
+
+export type Tile = { "Dragon": DragonTile } | { "Suit": SuitTile } | { "Wind": WindTile } | { "Flower": FlowerTile } | { "Season": SeasonTile } | { "Animal": AnimalTile };
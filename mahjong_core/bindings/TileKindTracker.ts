@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TileKindTracker = { in_board: number, in_exposed: number, in_hand: number, tile: number, unseen: number, };
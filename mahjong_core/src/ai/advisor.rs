@@ -0,0 +1,200 @@
+use super::{best_drops::get_scored_drops, best_melds::get_scored_melds};
+use crate::{
+    game_summary::GameSummary,
+    meld::{MeldType, PossibleMeld},
+    shanten::get_standard_shanten,
+    TileId, TileKind,
+};
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+use ts_rs::TS;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+#[serde(tag = "type")]
+pub enum HintAction {
+    Claim { meld: PossibleMeld },
+    Discard { tile: TileId },
+    Kong { meld: PossibleMeld },
+    Mahjong,
+    // Declaring a concealed meld of a complete hand, before the mahjong
+    Meld { meld: PossibleMeld },
+    // Not claiming the discarded tile
+    Pass,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, EnumIter, TS)]
+#[ts(export)]
+pub enum HintReason {
+    // A safe discard, because an opponent is likely ready and the hand is far from it
+    Fold,
+    // The hand stays as close to being ready as possible. A claim gets a tile without waiting for a
+    // draw.
+    KeepsShanten,
+    // Declaring the kong gives a replacement tile
+    KongReplacementTile,
+    Mahjong,
+    // The hand gets further from being ready
+    MovesAwayFromReady,
+    // None of the claims improve the hand
+    NoGoodClaim,
+    ReducesShanten,
+}
+
+// A recommended action with the values that explain it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub struct Hint {
+    // The unseen tiles that improve the hand after the action
+    pub acceptance: usize,
+    pub action: HintAction,
+    // From 0 to 100, of the discarded tile
+    pub danger: u32,
+    pub reason: HintReason,
+    // The shanten of the hand after the action
+    pub shanten: i8,
+}
+
+// The actions that the player can do now, the recommended first. It uses the same values as the
+// shanten agent: the melds worth declaring go before the discards, and the melds that are not worth
+// it go last, after passing on the claims.
+pub fn get_hints(game_summary: &GameSummary) -> Vec<Hint> {
+    let mut hints = vec![];
+    let mut worse_melds = vec![];
+    let mut pass_shanten = None;
+    let mut is_claim_worth = false;
+    let mut can_say_mahjong = game_summary.get_can_say_mahjong();
+    let can_discard = game_summary.get_can_discard_tile();
+    // The hand has the winning shape, but the melds need to be declared before saying mahjong
+    let complete_hand = game_summary
+        .hand
+        .as_ref()
+        .filter(|_| can_discard)
+        .map(|hand| hand.get_kind_counts())
+        .filter(|(counts, melds_num)| get_standard_shanten(counts, *melds_num) == -1);
+
+    for scored_meld in get_scored_melds(game_summary, game_summary.get_possible_melds()) {
+        let is_kong = MeldType::from_tiles(&scored_meld.meld.tiles) == Some(MeldType::Kong);
+
+        // The mahjong with the tiles of the hand doesn't need to declare the melds before
+        if scored_meld.meld.is_mahjong && !scored_meld.needs_claim {
+            can_say_mahjong = true;
+            continue;
+        }
+
+        if !scored_meld.needs_claim && !is_kong {
+            if let Some((counts, melds_num)) = complete_hand.as_ref() {
+                let mut counts_after = *counts;
+                for tile in scored_meld.meld.tiles.iter() {
+                    counts_after[TileKind::from_id(*tile).index()] -= 1;
+                }
+
+                if get_standard_shanten(&counts_after, melds_num + 1) == -1 {
+                    hints.push(Hint {
+                        acceptance: 0,
+                        action: HintAction::Meld {
+                            meld: scored_meld.meld,
+                        },
+                        danger: 0,
+                        reason: HintReason::Mahjong,
+                        shanten: -1,
+                    });
+                }
+            }
+
+            // Otherwise the concealed pungs and chows don't change the hand
+            continue;
+        }
+
+        // The kongs of the hand are only possible in the turn of the player
+        if !scored_meld.needs_claim && !can_discard {
+            continue;
+        }
+
+        if scored_meld.needs_claim {
+            pass_shanten = Some(scored_meld.base_shanten);
+            is_claim_worth = is_claim_worth || scored_meld.is_worth;
+        }
+
+        let reason = if scored_meld.meld.is_mahjong {
+            HintReason::Mahjong
+        } else if scored_meld.shanten > scored_meld.base_shanten {
+            HintReason::MovesAwayFromReady
+        } else if is_kong {
+            HintReason::KongReplacementTile
+        } else if scored_meld.shanten < scored_meld.base_shanten {
+            HintReason::ReducesShanten
+        } else {
+            HintReason::KeepsShanten
+        };
+        let hint = Hint {
+            acceptance: scored_meld.acceptance,
+            danger: 0,
+            reason,
+            shanten: scored_meld.shanten,
+            action: if is_kong {
+                HintAction::Kong {
+                    meld: scored_meld.meld,
+                }
+            } else {
+                HintAction::Claim {
+                    meld: scored_meld.meld,
+                }
+            },
+        };
+
+        if scored_meld.is_worth {
+            hints.push(hint);
+        } else {
+            worse_melds.push(hint);
+        }
+    }
+
+    let drops = get_scored_drops(game_summary).unwrap_or_default();
+    let best_shanten = drops.iter().map(|drop| drop.shanten).min();
+
+    for drop in drops.iter() {
+        let reason = if drop.is_folding {
+            HintReason::Fold
+        } else if Some(drop.shanten) == best_shanten {
+            HintReason::KeepsShanten
+        } else {
+            HintReason::MovesAwayFromReady
+        };
+
+        hints.push(Hint {
+            acceptance: drop.acceptance,
+            action: HintAction::Discard { tile: drop.id },
+            danger: drop.danger,
+            reason,
+            shanten: drop.shanten,
+        });
+    }
+
+    if let (Some(shanten), false) = (pass_shanten, is_claim_worth) {
+        hints.push(Hint {
+            acceptance: 0,
+            action: HintAction::Pass,
+            danger: 0,
+            reason: HintReason::NoGoodClaim,
+            shanten,
+        });
+    }
+
+    hints.extend(worse_melds);
+
+    if can_say_mahjong {
+        hints.insert(
+            0,
+            Hint {
+                acceptance: 0,
+                action: HintAction::Mahjong,
+                danger: 0,
+                reason: HintReason::Mahjong,
+                shanten: -1,
+            },
+        );
+    }
+
+    hints
+}
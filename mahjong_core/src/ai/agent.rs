@@ -0,0 +1,86 @@
+use super::{
+    greedy_agent::GreedyAgent, random_agent::RandomAgent, search_agent::SearchAgent,
+    shanten_agent::ShantenAgent,
+};
+use crate::{game_summary::GameSummary, meld::PossibleMeld, TileId};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use strum_macros::EnumIter;
+use ts_rs::TS;
+
+// The decisions of one player, only from what the player can see: the observation doesn't have the
+// concealed tiles of the other players or the order of the wall. The driver (`StandardAI`) advances
+// the phases, validates each decision as an `AgentAction` against the real game and applies it, so
+// an agent can't cheat and different agents can play in the same game.
+pub trait MahjongAgent: Send {
+    // The melds that the player wants to declare from the possible ones, the preferred first. They
+    // can be a mahjong, or need to claim the discarded tile. An empty list skips the melds.
+    fn choose_melds(
+        &mut self,
+        observation: &GameSummary,
+        melds: Vec<PossibleMeld>,
+    ) -> Vec<PossibleMeld>;
+
+    // The tiles of the hand that the player wants to discard, the preferred first
+    fn choose_discards(&mut self, observation: &GameSummary) -> Vec<TileId>;
+
+    // The scores that the agent gave to the actions of its last choice, lower is better. The scale
+    // depends on the agent, and the actions that it didn't score are missing.
+    fn explain(&self) -> Vec<(AgentAction, i32)> {
+        vec![]
+    }
+}
+
+// A decision of an agent for a player
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum AgentAction {
+    Discard(TileId),
+    Meld(PossibleMeld),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, EnumIter, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum AgentActionError {
+    CanNotDiscard,
+    MeldNotPossible,
+    NotPlayerTurn,
+    TileNotInHand,
+    WrongPlayer,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, EnumIter, TS)]
+#[ts(export)]
+pub enum AgentKind {
+    Greedy,
+    Random,
+    // Slower than the others, it plays the rest of the round for the best discards and claims
+    Search,
+    #[default]
+    Shanten,
+}
+
+impl AgentKind {
+    pub fn create_agent(&self) -> Box<dyn MahjongAgent> {
+        match self {
+            Self::Greedy => Box::new(GreedyAgent),
+            Self::Random => Box::new(RandomAgent),
+            Self::Search => Box::new(SearchAgent::default()),
+            Self::Shanten => Box::new(ShantenAgent::default()),
+        }
+    }
+}
+
+impl FromStr for AgentKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "greedy" => Ok(Self::Greedy),
+            "random" => Ok(Self::Random),
+            "search" => Ok(Self::Search),
+            "shanten" => Ok(Self::Shanten),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,45 +1,201 @@
-use crate::{game_summary::GameSummary, PlayerId, TileId};
-
-use super::StandardAI;
-
-impl StandardAI<'_> {
-    // This can become complex if it takes into account different scoring rules.
-    // For now it should only take into account the possibility to create a meld.
-    // In future it should review which tiles have been claimed by other players.
-    // Should have some unit tests.
-    // TODO: finalise
-    pub fn get_best_drops(&self, player_id: &PlayerId) -> Option<Vec<TileId>> {
-        let game_clone = self.game.clone();
-        let game_summary = GameSummary::from_game(&game_clone, player_id)?;
-
-        if !game_summary.hand.clone().unwrap().can_drop_tile() {
-            return None;
-        }
+use super::{AIDifficulty, AIProfile, AIStyle};
+use crate::{
+    deck::DEFAULT_DECK,
+    game_summary::GameSummary,
+    ruleset::Ruleset,
+    score::ScoringRule,
+    tile_kind::{TileKind, TILE_KINDS_NUM},
+    Tile, TileId,
+};
+
+// One shanten is worth more than any acceptance, which is counted in unseen tiles
+pub(super) const SHANTEN_WEIGHT: i32 = 1000;
+// How much it is worth keeping a tile for the faan of the target hand, in unseen tiles
+const VALUABLE_HONOUR_VALUE: i32 = 6;
+// The hand folds (discards the safest tiles first) when it is far from being ready and an opponent
+// is likely ready. With a valuable hand, it needs to be further away to fold.
+const FOLD_SHANTEN: i8 = 2;
+const VALUABLE_FOLD_SHANTEN: i8 = 3;
+const FOLD_READINESS: u32 = 40;
+// When folding, one point of danger is worth more than any shanten
+const FOLD_DANGER_WEIGHT: i32 = SHANTEN_WEIGHT * 10;
+// When pushing, the danger costs one unseen tile for each this number of danger and readiness
+// points multiplied, and the double of it when the hand stays ready
+const DANGER_COST_DIVISOR: u32 = 300;
+
+// The values above are the ones of the defensive style, the other styles change them
+pub(super) struct StyleValues {
+    danger_cost_divisor: u32,
+    fold_readiness: u32,
+    fold_shanten: i8,
+    valuable_fold_shanten: i8,
+    pub(super) valuable_honour_value: i32,
+}
 
-        struct TileDrop {
-            id: TileId,
-            score: usize,
+impl StyleValues {
+    pub(super) fn from_style(style: &AIStyle) -> Self {
+        let defensive = Self {
+            danger_cost_divisor: DANGER_COST_DIVISOR,
+            fold_readiness: FOLD_READINESS,
+            fold_shanten: FOLD_SHANTEN,
+            valuable_fold_shanten: VALUABLE_FOLD_SHANTEN,
+            valuable_honour_value: VALUABLE_HONOUR_VALUE,
+        };
+
+        match style {
+            AIStyle::Aggressive => Self {
+                danger_cost_divisor: DANGER_COST_DIVISOR * 2,
+                fold_readiness: FOLD_READINESS + 20,
+                fold_shanten: FOLD_SHANTEN + 1,
+                valuable_fold_shanten: VALUABLE_FOLD_SHANTEN + 1,
+                ..defensive
+            },
+            AIStyle::Defensive => defensive,
+            AIStyle::ValueSeeking => Self {
+                valuable_fold_shanten: VALUABLE_FOLD_SHANTEN + 1,
+                valuable_honour_value: VALUABLE_HONOUR_VALUE * 2,
+                ..defensive
+            },
         }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TileDrop {
+    // The unseen tiles that improve the hand after the discard
+    pub acceptance: usize,
+    pub id: TileId,
+    // When an opponent is likely ready and the hand is far from it, the safest tiles go first
+    pub is_folding: bool,
+    // Lower is better
+    pub score: i32,
+    pub shanten: i8,
+    // From 0 to 100, see `GameSummary::get_tiles_danger`
+    pub danger: u32,
+}
+
+// The tiles that can be discarded, the best ones first. It prefers the discards that leave the hand
+// closer to be ready (lower shanten) and with more unseen tiles that improve it. When they are
+// similar, it keeps the tiles that can give faan in the scoring rules of the ruleset: the pairs of
+// dragons, for the great dragons. The danger of feeding the win of an opponent is weighed
+// against how close and valuable the hand is: it is a cost when pushing, and it decides the order
+// when folding.
+pub fn get_best_drops(game_summary: &GameSummary) -> Option<Vec<TileId>> {
+    let drops = get_scored_drops(game_summary)?;
+
+    Some(drops.iter().map(|drop| drop.id).collect())
+}
+
+// The same order as `get_best_drops`, with the values used to sort them
+pub fn get_scored_drops(game_summary: &GameSummary) -> Option<Vec<TileDrop>> {
+    get_scored_drops_for_profile(game_summary, &AIProfile::default())
+}
 
-        let mut drops: Vec<TileDrop> = vec![];
+// The beginners don't count the acceptance, and the style changes how the hand is valued and when
+// it folds
+pub fn get_scored_drops_for_profile(
+    game_summary: &GameSummary,
+    profile: &AIProfile,
+) -> Option<Vec<TileDrop>> {
+    let hand = game_summary.hand.as_ref()?;
+    let ruleset = game_summary.get_ruleset();
 
-        for tile in game_summary.hand.unwrap().list.iter() {
-            if tile.set_id.is_some() {
-                drops.push(TileDrop {
-                    id: tile.id,
-                    score: 0,
-                });
+    if !hand.can_drop_tile(&*ruleset) {
+        return None;
+    }
+
+    let analysis = game_summary.get_best_discards_analysis()?;
+    let style_values = StyleValues::from_style(&profile.style);
+    let keep_values = get_keep_values(game_summary, &*ruleset, &style_values);
+    let tiles_danger = game_summary.get_tiles_danger();
+    let max_readiness = game_summary
+        .get_opponents_readiness()
+        .iter()
+        .map(|opponent| opponent.readiness)
+        .max()
+        .unwrap_or_default();
+    let is_valuable = keep_values.iter().any(|value| *value > 0);
+
+    let mut drops = analysis
+        .discards
+        .iter()
+        .map(|discard| {
+            let acceptance = discard
+                .acceptance
+                .iter()
+                .map(|acceptance| acceptance.unseen)
+                .sum::<usize>();
+            let acceptance_value = if profile.difficulty == AIDifficulty::Beginner {
+                0
+            } else {
+                acceptance as i32
+            };
+            let kind = TileKind::from_id(discard.tile);
+            let danger = tiles_danger
+                .iter()
+                .find(|tile_danger| tile_danger.tile == discard.tile)
+                .map(|tile_danger| tile_danger.danger)
+                .unwrap_or_default();
+
+            TileDrop {
+                acceptance,
+                danger,
+                id: discard.tile,
+                is_folding: false,
+                score: discard.shanten as i32 * SHANTEN_WEIGHT - acceptance_value
+                    + keep_values[kind.index()],
+                shanten: discard.shanten,
             }
+        })
+        .collect::<Vec<TileDrop>>();
 
-            // - Check how possible is it to build a meld with this tile (score can also be one if
-            // one tile left)
-        }
+    let best_shanten = drops.iter().map(|drop| drop.shanten).min().unwrap_or(0);
+    let fold_shanten = if is_valuable {
+        style_values.valuable_fold_shanten
+    } else {
+        style_values.fold_shanten
+    };
+
+    let is_folding = best_shanten >= fold_shanten && max_readiness >= style_values.fold_readiness;
+
+    for drop in drops.iter_mut() {
+        drop.is_folding = is_folding;
+        drop.score += if is_folding {
+            drop.danger as i32 * FOLD_DANGER_WEIGHT
+        } else if drop.shanten == 0 {
+            (drop.danger * max_readiness / (style_values.danger_cost_divisor * 2)) as i32
+        } else {
+            (drop.danger * max_readiness / style_values.danger_cost_divisor) as i32
+        };
+    }
+
+    // Best drops sorted from left to right
+    drops.sort_by_key(|drop| drop.score);
 
-        // Best drops sorted from left to right
-        drops.sort_by(|a, b| a.score.cmp(&b.score));
+    Some(drops)
+}
 
-        let best_drops = drops.iter().map(|drop| drop.id).collect::<Vec<TileId>>();
+// The dragons, when the ruleset scores the great dragons. The winds and the flushes don't give faan
+// in the scoring rules.
+pub(super) fn is_valuable_honour(ruleset: &dyn Ruleset, kind: &TileKind) -> bool {
+    matches!(&DEFAULT_DECK.0[kind.get_tile_id()], Tile::Dragon(_))
+        && ruleset.get_scoring_rule_points(&ScoringRule::GreatDragons) > 0
+}
 
-        Some(best_drops)
+fn get_keep_values(
+    game_summary: &GameSummary,
+    ruleset: &dyn Ruleset,
+    style_values: &StyleValues,
+) -> [i32; TILE_KINDS_NUM] {
+    let mut keep_values = [0; TILE_KINDS_NUM];
+    let hand = game_summary.hand.as_ref().unwrap();
+    let (concealed_counts, _) = hand.get_kind_counts();
+
+    for kind in TileKind::iter_normal() {
+        if is_valuable_honour(ruleset, &kind) && concealed_counts[kind.index()] >= 2 {
+            keep_values[kind.index()] += style_values.valuable_honour_value;
+        }
     }
+
+    keep_values
 }
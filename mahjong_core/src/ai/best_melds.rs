@@ -0,0 +1,252 @@
+use super::{
+    best_drops::{is_valuable_honour, StyleValues, SHANTEN_WEIGHT},
+    AIStyle,
+};
+use crate::{
+    game_summary::GameSummary,
+    hand::SetIdContent,
+    meld::{MeldType, PossibleMeld},
+    shanten::get_standard_shanten,
+    tile_kind::{TileKind, TileKindCounts},
+    TileId,
+};
+use rustc_hash::FxHashMap;
+
+// Mixing chows and pungs in the exposed melds loses the common hand or the all in triplets
+const HAND_SHAPE_VALUE: i32 = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoredMeld {
+    // The unseen tiles that improve the hand after the meld (and the best discard)
+    pub acceptance: usize,
+    // The shanten of the hand without the meld
+    pub base_shanten: i8,
+    // If the meld is better for the hand than not declaring it
+    pub is_worth: bool,
+    pub meld: PossibleMeld,
+    // If it claims the discarded tile
+    pub needs_claim: bool,
+    // Lower is better, in the same units as the drops
+    pub score: i32,
+    pub shanten: i8,
+}
+
+struct HandValue {
+    acceptance: usize,
+    shanten: i8,
+}
+
+impl HandValue {
+    fn get_score(&self) -> i32 {
+        self.shanten as i32 * SHANTEN_WEIGHT - self.acceptance as i32
+    }
+
+    // The expected improvement of drawing one tile, like the replacement tile of a kong
+    fn get_draw_value(&self, unseen_total: usize) -> i32 {
+        (SHANTEN_WEIGHT as usize * self.acceptance / unseen_total.max(1)) as i32
+    }
+}
+
+// The melds only change the standard shape, which is the only one that can win, so the special
+// shapes are not considered
+fn get_waiting_value(
+    counts: &TileKindCounts,
+    melds_num: usize,
+    unseen: &TileKindCounts,
+) -> HandValue {
+    let shanten = get_standard_shanten(counts, melds_num);
+    let mut counts_with_draw = *counts;
+    let mut acceptance = 0;
+
+    for kind in TileKind::iter_normal() {
+        let index = kind.index();
+        if counts[index] >= 4 || unseen[index] == 0 {
+            continue;
+        }
+
+        counts_with_draw[index] += 1;
+        if get_standard_shanten(&counts_with_draw, melds_num) < shanten {
+            acceptance += unseen[index] as usize;
+        }
+        counts_with_draw[index] -= 1;
+    }
+
+    HandValue {
+        acceptance,
+        shanten,
+    }
+}
+
+// The value of the hand when it waits to draw, or after the best discard when it has the extra tile
+fn get_hand_value(counts: &TileKindCounts, melds_num: usize, unseen: &TileKindCounts) -> HandValue {
+    if counts.iter().map(|count| *count as usize).sum::<usize>() % 3 != 2 {
+        return get_waiting_value(counts, melds_num, unseen);
+    }
+
+    let discards = TileKind::iter_normal()
+        .filter(|kind| counts[kind.index()] > 0)
+        .map(|kind| {
+            let mut counts_after_discard = *counts;
+            counts_after_discard[kind.index()] -= 1;
+
+            (
+                get_standard_shanten(&counts_after_discard, melds_num),
+                counts_after_discard,
+            )
+        })
+        .collect::<Vec<_>>();
+    let best_shanten = discards.iter().map(|(shanten, _)| *shanten).min();
+
+    discards
+        .iter()
+        .filter(|(shanten, _)| Some(*shanten) == best_shanten)
+        .map(|(_, counts_after_discard)| get_waiting_value(counts_after_discard, melds_num, unseen))
+        .max_by_key(|value| value.acceptance)
+        .unwrap_or(HandValue {
+            acceptance: 0,
+            shanten: best_shanten.unwrap_or(8),
+        })
+}
+
+// The possible melds of the player, the best ones first. The claimed tile comes without waiting
+// for a draw, so a claim is worth it when it doesn't move the hand away from being ready, even if
+// fewer tiles improve it after. When there are several, the ones that keep the value of the hand
+// (the pungs of valuable honours, not mixing chows and pungs) go first. The replacement tile of a
+// kong usually makes up for the fourth tile, so a kong is only refused when the hand gets further
+// from being ready. The mahjong melds, the concealed pungs and chows, and the melds of a tile that
+// the player already claimed are always worth it.
+pub fn get_scored_melds(game_summary: &GameSummary, melds: Vec<PossibleMeld>) -> Vec<ScoredMeld> {
+    get_scored_melds_for_style(game_summary, melds, &AIStyle::default())
+}
+
+// The value-seeking style refuses the claims that mix chows and pungs
+pub fn get_scored_melds_for_style(
+    game_summary: &GameSummary,
+    melds: Vec<PossibleMeld>,
+    style: &AIStyle,
+) -> Vec<ScoredMeld> {
+    let Some(hand) = game_summary.hand.as_ref() else {
+        return vec![];
+    };
+    let unseen = game_summary.get_unseen_counts();
+    let unseen_total = unseen.iter().map(|count| *count as usize).sum::<usize>();
+    let (counts, melds_num) = hand.get_kind_counts();
+    let base_value = get_hand_value(&counts, melds_num, &unseen);
+    let style_values = StyleValues::from_style(style);
+    let ruleset = game_summary.get_ruleset();
+    let claimed_tile = game_summary
+        .round
+        .discards
+        .last()
+        .filter(|discard| discard.claimed_by.as_ref() == Some(&game_summary.player_id))
+        .map(|discard| discard.tile)
+        .filter(|tile| hand.list.iter().any(|hand_tile| hand_tile.id == *tile));
+
+    let mut exposed_sets: FxHashMap<SetIdContent, Vec<TileId>> = FxHashMap::default();
+    for tile in hand.list.iter().filter(|tile| !tile.concealed) {
+        if let Some(set_id) = &tile.set_id {
+            exposed_sets
+                .entry(set_id.clone())
+                .or_default()
+                .push(tile.id);
+        }
+    }
+    let exposed_types = exposed_sets
+        .values()
+        .filter_map(|tiles| MeldType::from_tiles(tiles))
+        .collect::<Vec<_>>();
+
+    let mut scored_melds = melds
+        .into_iter()
+        .map(|meld| {
+            if meld.is_mahjong {
+                return ScoredMeld {
+                    acceptance: 0,
+                    base_shanten: base_value.shanten,
+                    is_worth: true,
+                    needs_claim: meld
+                        .tiles
+                        .iter()
+                        .any(|tile| !hand.list.iter().any(|hand_tile| hand_tile.id == *tile)),
+                    meld,
+                    score: i32::MIN,
+                    shanten: -1,
+                };
+            }
+
+            let mut counts_after = counts;
+            let mut needs_claim = false;
+            for tile in meld.tiles.iter() {
+                match hand.list.iter().find(|hand_tile| hand_tile.id == *tile) {
+                    Some(hand_tile) if hand_tile.set_id.is_none() => {
+                        counts_after[TileKind::from_id(*tile).index()] -= 1;
+                    }
+                    Some(_) => {}
+                    None => needs_claim = true,
+                }
+            }
+            let melds_after = melds_num + usize::from(!meld.is_upgrade);
+            let value = get_hand_value(&counts_after, melds_after, &unseen);
+            let mut score = value.get_score();
+
+            let meld_type = MeldType::from_tiles(&meld.tiles);
+            let is_chow = meld_type == Some(MeldType::Chow);
+            let breaks_shape = !meld.is_concealed
+                && !meld.is_upgrade
+                && !exposed_types.is_empty()
+                && exposed_types
+                    .iter()
+                    .all(|exposed_type| (*exposed_type == MeldType::Chow) != is_chow);
+
+            if !is_chow && is_valuable_honour(&*ruleset, &TileKind::from_id(meld.tiles[0])) {
+                score -= style_values.valuable_honour_value;
+            }
+            if breaks_shape {
+                score += HAND_SHAPE_VALUE;
+            }
+
+            let is_worth = if meld_type == Some(MeldType::Kong) {
+                score -= value.get_draw_value(unseen_total);
+
+                value.shanten <= base_value.shanten
+            } else if needs_claim {
+                value.shanten <= base_value.shanten
+                    && !(breaks_shape && *style == AIStyle::ValueSeeking)
+            } else {
+                // Setting aside a concealed pung or chow doesn't claim anything or draw a tile
+                meld.is_concealed
+            };
+
+            ScoredMeld {
+                acceptance: value.acceptance,
+                base_shanten: base_value.shanten,
+                is_worth: is_worth || claimed_tile.is_some_and(|tile| meld.tiles.contains(&tile)),
+                meld,
+                needs_claim,
+                score,
+                shanten: value.shanten,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    scored_melds.sort_by_key(|scored_meld| scored_meld.score);
+
+    scored_melds
+}
+
+// The melds that are worth declaring, the best ones first
+pub fn get_best_melds(game_summary: &GameSummary, melds: Vec<PossibleMeld>) -> Vec<PossibleMeld> {
+    get_best_melds_for_style(game_summary, melds, &AIStyle::default())
+}
+
+pub fn get_best_melds_for_style(
+    game_summary: &GameSummary,
+    melds: Vec<PossibleMeld>,
+    style: &AIStyle,
+) -> Vec<PossibleMeld> {
+    get_scored_melds_for_style(game_summary, melds, style)
+        .into_iter()
+        .filter(|scored_meld| scored_meld.is_worth)
+        .map(|scored_meld| scored_meld.meld)
+        .collect()
+}
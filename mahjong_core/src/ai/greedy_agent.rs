@@ -0,0 +1,51 @@
+use super::{sort_by_is_mahjong, MahjongAgent};
+use crate::{
+    game_summary::GameSummary,
+    meld::PossibleMeld,
+    tile_kind::{get_kind_counts, TileKind},
+    TileId,
+};
+
+// Declares every meld it can and discards the tile with fewer close tiles in the hand. It is much
+// faster than the shanten agent, so it is used for the playouts of the search.
+pub struct GreedyAgent;
+
+impl MahjongAgent for GreedyAgent {
+    fn choose_melds(
+        &mut self,
+        _observation: &GameSummary,
+        mut melds: Vec<PossibleMeld>,
+    ) -> Vec<PossibleMeld> {
+        melds.sort_by(sort_by_is_mahjong);
+
+        melds
+    }
+
+    fn choose_discards(&mut self, observation: &GameSummary) -> Vec<TileId> {
+        let Some(hand) = observation.hand.as_ref() else {
+            return vec![];
+        };
+        let mut discards = hand
+            .list
+            .iter()
+            .filter(|tile| tile.set_id.is_none())
+            .map(|tile| tile.id)
+            .collect::<Vec<TileId>>();
+        let counts = get_kind_counts(discards.iter().cloned());
+
+        discards.sort_by_cached_key(|tile| {
+            let kind = TileKind::from_id(*tile);
+            let index = kind.index();
+            let same_kind = counts[index] as usize - 1;
+            let close_tiles = (index.saturating_sub(2)..=(index + 2).min(counts.len() - 1))
+                .filter(|other| *other != index)
+                .filter(|other| TileKind::from_index(*other).is_same_suit(&kind))
+                .map(|other| counts[other] as usize)
+                .sum::<usize>();
+
+            same_kind * 3 + close_tiles
+        });
+
+        discards
+    }
+}
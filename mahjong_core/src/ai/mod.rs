@@ -1,15 +1,46 @@
+pub use self::advisor::{get_hints, Hint, HintAction, HintReason};
+pub use self::agent::{AgentAction, AgentActionError, AgentKind, MahjongAgent};
+pub use self::best_drops::{
+    get_best_drops, get_scored_drops, get_scored_drops_for_profile, TileDrop,
+};
+pub use self::best_melds::{
+    get_best_melds, get_best_melds_for_style, get_scored_melds, get_scored_melds_for_style,
+    ScoredMeld,
+};
+pub use self::greedy_agent::GreedyAgent;
+pub use self::profile::{AIDifficulty, AIProfile, AIStyle};
+pub use self::random_agent::RandomAgent;
+pub use self::review::{
+    get_review_evaluator, review_decision, review_game, GameDecision, GameReview, ReviewMistake,
+};
+pub use self::search_agent::{SearchAgent, SearchBudget};
+pub use self::shanten_agent::ShantenAgent;
 use crate::game::{DrawError, DrawTileResult};
+use crate::game_summary::GameSummary;
 use crate::meld::PossibleMeld;
 use crate::{Game, GamePhase, PlayerId, TileId, Wind, WINDS_ROUND_ORDER};
 use rand::seq::SliceRandom;
 use rand::thread_rng;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
 use strum_macros::EnumIter;
+use ts_rs::TS;
 
+mod advisor;
+mod agent;
 mod best_drops;
-
-// Naive AI as a placeholder which can be extended later
+mod best_melds;
+mod greedy_agent;
+mod profile;
+mod random_agent;
+mod review;
+mod search_agent;
+mod shanten_agent;
+
+// Drives the game through the phases, and asks the agent of each AI player for its decisions
 pub struct StandardAI<'a> {
+    agents: FxHashMap<PlayerId, Box<dyn MahjongAgent>>,
     ai_players: FxHashSet<PlayerId>,
     pub auto_stop_claim_meld: FxHashSet<PlayerId>,
     pub can_draw_round: bool,
@@ -52,9 +83,70 @@ pub enum PlayExitLocation {
     WaitingPlayers,
 }
 
-// This is used for debugging unexpected "NoAction" results
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub struct Metadata {}
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ActionCandidate {
+    pub action: AgentAction,
+    pub player_id: PlayerId,
+    // The score that the agent gave to the action (see `MahjongAgent::explain`). It is missing when
+    // the agent didn't score it.
+    pub score: Option<i32>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct RejectedAction {
+    pub action: AgentAction,
+    pub error: AgentActionError,
+    pub player_id: PlayerId,
+}
+
+// Why the driver applied the chosen action
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum ChoiceSource {
+    Agent,
+    // The claimed tile had to be discarded, because it didn't end in a meld
+    ClaimedTile,
+    // None of the discards of the agent were valid, so a random tile was discarded
+    RandomFallback,
+}
+
+// This is used for debugging unexpected "NoAction" results: the actions that the agents proposed,
+// in their order of preference, and the ones that the driver didn't accept
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct Metadata {
+    pub candidates: Vec<ActionCandidate>,
+    pub chosen: Option<AgentAction>,
+    pub chosen_source: Option<ChoiceSource>,
+    pub rejected: Vec<RejectedAction>,
+}
+
+impl Metadata {
+    fn add_candidates(
+        &mut self,
+        player_id: &PlayerId,
+        actions: Vec<AgentAction>,
+        scores: &[(AgentAction, i32)],
+    ) {
+        self.candidates.extend(actions.into_iter().map(|action| {
+            ActionCandidate {
+                score: scores
+                    .iter()
+                    .find(|(scored_action, _)| *scored_action == action)
+                    .map(|(_, score)| *score),
+                action,
+                player_id: player_id.clone(),
+            }
+        }));
+    }
+
+    fn set_chosen(&mut self, action: AgentAction, source: ChoiceSource) {
+        self.chosen = Some(action);
+        self.chosen_source = Some(source);
+    }
+}
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct PlayActionResult {
@@ -81,7 +173,7 @@ impl StandardAI<'_> {
         }
         let current_hand = self.game.table.hands.get(&current_player.unwrap());
 
-        current_hand.unwrap().len() < self.game.style.tiles_after_claim()
+        current_hand.unwrap().len() < self.game.get_ruleset().tiles_after_claim()
             && self.game.round.tile_claimed.is_some()
     }
 }
@@ -93,6 +185,7 @@ impl<'a> StandardAI<'a> {
         auto_stop_claim_meld: FxHashSet<PlayerId>,
     ) -> Self {
         Self {
+            agents: FxHashMap::default(),
             ai_players,
             auto_stop_claim_meld,
             can_draw_round: false,
@@ -107,11 +200,66 @@ impl<'a> StandardAI<'a> {
         }
     }
 
+    // The AI players without an agent use the default one
+    pub fn set_agent(&mut self, player_id: &PlayerId, agent: Box<dyn MahjongAgent>) {
+        self.agents.insert(player_id.clone(), agent);
+    }
+
+    fn get_agent(&mut self, player_id: &PlayerId) -> &mut Box<dyn MahjongAgent> {
+        self.agents
+            .entry(player_id.clone())
+            .or_insert_with(|| AgentKind::default().create_agent())
+    }
+
+    // The melds have to be one of the possible ones of the player, which the driver gets from the
+    // real game, and the discards have to be a free tile of the player in their turn
+    pub fn validate_action(
+        &self,
+        player_id: &PlayerId,
+        action: &AgentAction,
+        possible_melds: &[PossibleMeld],
+    ) -> Result<(), AgentActionError> {
+        match action {
+            AgentAction::Meld(meld) => {
+                if meld.player_id != *player_id {
+                    return Err(AgentActionError::WrongPlayer);
+                }
+
+                if !possible_melds.contains(meld) {
+                    return Err(AgentActionError::MeldNotPossible);
+                }
+            }
+            AgentAction::Discard(tile) => {
+                if self.game.get_current_player().as_ref() != Some(player_id) {
+                    return Err(AgentActionError::NotPlayerTurn);
+                }
+
+                let Some(hand) = self.game.table.hands.0.get(player_id) else {
+                    return Err(AgentActionError::WrongPlayer);
+                };
+
+                if hand.len() != self.game.get_ruleset().tiles_after_claim() {
+                    return Err(AgentActionError::CanNotDiscard);
+                }
+
+                if !hand
+                    .list
+                    .iter()
+                    .any(|hand_tile| hand_tile.id == *tile && hand_tile.set_id.is_none())
+                {
+                    return Err(AgentActionError::TileNotInHand);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn play_action(&mut self, with_metadata: bool) -> PlayActionResult {
         let mut metadata: Option<Metadata> = None;
 
         if with_metadata {
-            metadata = Some(Metadata {});
+            metadata = Some(Metadata::default());
         }
 
         match self.game.phase {
@@ -226,20 +374,69 @@ impl<'a> StandardAI<'a> {
             GamePhase::Playing => {}
         }
 
-        // Check if any meld can be created with existing cards
-        let mut melds = self.game.get_possible_melds(true);
+        // Check if any meld can be created with existing cards. All the players are checked,
+        // because the agents can decline their melds.
+        let mut melds = self.game.get_possible_melds(false);
 
         // Suffle melds
         let mut rng = thread_rng();
         melds.shuffle(&mut rng);
-        melds.sort_by(sort_by_is_mahjong);
+        melds.sort_by_key(|meld| Reverse(self.game.get_ruleset().get_claim_priority(meld)));
+
+        // The players with a mahjong go first, and then the ones with a pung or a kong
+        let mut melds_players: Vec<PlayerId> = vec![];
+        for meld in melds.iter() {
+            if self.ai_players.contains(&meld.player_id) && !melds_players.contains(&meld.player_id)
+            {
+                melds_players.push(meld.player_id.clone());
+            }
+        }
+
+        for player_id in melds_players {
+            let Some(observation) = GameSummary::from_game(self.game, &player_id) else {
+                continue;
+            };
+            let player_melds = melds
+                .iter()
+                .filter(|meld| meld.player_id == player_id)
+                .cloned()
+                .collect::<Vec<PossibleMeld>>();
+            let agent = self.get_agent(&player_id);
+            let chosen_melds = agent.choose_melds(&observation, player_melds.clone());
+
+            if let Some(metadata) = metadata.as_mut() {
+                let scores = agent.explain();
+                let actions = chosen_melds
+                    .iter()
+                    .cloned()
+                    .map(AgentAction::Meld)
+                    .collect();
+
+                metadata.add_candidates(&player_id, actions, &scores);
+            }
+
+            for meld in chosen_melds {
+                let action = AgentAction::Meld(meld.clone());
+
+                if let Err(error) = self.validate_action(&player_id, &action, &player_melds) {
+                    if let Some(metadata) = metadata.as_mut() {
+                        metadata.rejected.push(RejectedAction {
+                            action,
+                            error,
+                            player_id: player_id.clone(),
+                        });
+                    }
+                    continue;
+                }
 
-        for meld in melds {
-            if self.ai_players.contains(&meld.player_id) {
                 if meld.is_mahjong {
                     let mahjong_success = self.game.say_mahjong(&meld.player_id);
 
                     if mahjong_success.is_ok() {
+                        if let Some(metadata) = metadata.as_mut() {
+                            metadata.set_chosen(action, ChoiceSource::Agent);
+                        }
+
                         return PlayActionResult {
                             changed: true,
                             exit_location: PlayExitLocation::SuccessMahjong,
@@ -262,6 +459,10 @@ impl<'a> StandardAI<'a> {
                             let was_tile_claimed = self.game.claim_tile(&meld.player_id);
 
                             if was_tile_claimed {
+                                if let Some(metadata) = metadata.as_mut() {
+                                    metadata.set_chosen(action, ChoiceSource::Agent);
+                                }
+
                                 return PlayActionResult {
                                     changed: true,
                                     exit_location: PlayExitLocation::ClaimedTile,
@@ -292,6 +493,12 @@ impl<'a> StandardAI<'a> {
                     meld.is_concealed,
                 );
 
+                if meld_created.is_ok() {
+                    if let Some(metadata) = metadata.as_mut() {
+                        metadata.set_chosen(action, ChoiceSource::Agent);
+                    }
+                }
+
                 if phase_before == GamePhase::Playing && self.game.phase != GamePhase::Playing {
                     return PlayActionResult {
                         changed: true,
@@ -337,7 +544,7 @@ impl<'a> StandardAI<'a> {
             }
 
             let player_hand = self.game.table.hands.0.get(&current_player).unwrap();
-            if player_hand.len() == self.game.style.tiles_after_claim() {
+            if player_hand.len() == self.game.get_ruleset().tiles_after_claim() {
                 let mut tiles_without_meld = player_hand
                     .list
                     .iter()
@@ -346,22 +553,58 @@ impl<'a> StandardAI<'a> {
                     .collect::<Vec<TileId>>();
 
                 if !tiles_without_meld.is_empty() {
-                    let tile_to_discard = 'a: {
+                    let (tile_to_discard, source) = 'a: {
                         if let Some(tile_claimed) = self.game.round.tile_claimed.clone() {
                             for tile in tiles_without_meld.iter() {
                                 if tile_claimed.id == *tile {
-                                    break 'a tile_claimed.id;
+                                    break 'a (tile_claimed.id, ChoiceSource::ClaimedTile);
+                                }
+                            }
+                        }
+
+                        if let Some(observation) =
+                            GameSummary::from_game(self.game, &current_player)
+                        {
+                            let agent = self.get_agent(&current_player);
+                            let discards = agent.choose_discards(&observation);
+
+                            if let Some(metadata) = metadata.as_mut() {
+                                let scores = agent.explain();
+                                let actions =
+                                    discards.iter().copied().map(AgentAction::Discard).collect();
+
+                                metadata.add_candidates(&current_player, actions, &scores);
+                            }
+
+                            for tile in discards {
+                                let action = AgentAction::Discard(tile);
+
+                                match self.validate_action(&current_player, &action, &[]) {
+                                    Ok(()) => break 'a (tile, ChoiceSource::Agent),
+                                    Err(error) => {
+                                        if let Some(metadata) = metadata.as_mut() {
+                                            metadata.rejected.push(RejectedAction {
+                                                action,
+                                                error,
+                                                player_id: current_player.clone(),
+                                            });
+                                        }
+                                    }
                                 }
                             }
                         }
 
                         tiles_without_meld.shuffle(&mut thread_rng());
-                        tiles_without_meld[0]
+                        (tiles_without_meld[0], ChoiceSource::RandomFallback)
                     };
 
                     let discarded = self.game.discard_tile_to_board(&tile_to_discard);
 
                     if discarded.is_ok() {
+                        if let Some(metadata) = metadata.as_mut() {
+                            metadata.set_chosen(AgentAction::Discard(tile_to_discard), source);
+                        }
+
                         return PlayActionResult {
                             changed: true,
                             exit_location: PlayExitLocation::TileDiscarded,
@@ -414,7 +657,10 @@ impl<'a> StandardAI<'a> {
                         }
                     }
                 }
-                let success = self.game.round.next_turn(&self.game.table.hands);
+                let success = self.game.round.next_turn(
+                    &self.game.table.hands,
+                    &*self.game.style.get_ruleset(&self.game.house_rules),
+                );
 
                 if success.is_ok() {
                     return PlayActionResult {
@@ -456,8 +702,11 @@ impl<'a> StandardAI<'a> {
                 };
             } else if self.can_pass_turn {
                 let player_hand = self.game.table.hands.0.get(&current_player).unwrap();
-                if player_hand.len() < self.game.style.tiles_after_claim() {
-                    let success = self.game.round.next_turn(&self.game.table.hands);
+                if player_hand.len() < self.game.get_ruleset().tiles_after_claim() {
+                    let success = self.game.round.next_turn(
+                        &self.game.table.hands,
+                        &*self.game.style.get_ruleset(&self.game.house_rules),
+                    );
 
                     if success.is_ok() {
                         return PlayActionResult {
@@ -0,0 +1,73 @@
+use super::{search_agent::SearchAgent, shanten_agent::ShantenAgent, MahjongAgent};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use strum_macros::EnumIter;
+use ts_rs::TS;
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, EnumIter, TS)]
+#[ts(export)]
+pub enum AIDifficulty {
+    // Only looks at how far the hand is from being ready, not at how many tiles improve it
+    Beginner,
+    #[default]
+    Intermediate,
+    // Slower, it plays the rest of the round for the best discards and claims (not the own kongs)
+    Strong,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, EnumIter, TS)]
+#[ts(export)]
+pub enum AIStyle {
+    // Rarely folds, and cares less about the danger of the discards
+    Aggressive,
+    // Folds when an opponent is likely ready and the hand is far from it
+    #[default]
+    Defensive,
+    // Keeps the tiles that give faan, and doesn't mix chows and pungs in the exposed melds
+    ValueSeeking,
+}
+
+// The difficulty and the style of an AI player
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub struct AIProfile {
+    pub difficulty: AIDifficulty,
+    pub style: AIStyle,
+}
+
+impl AIProfile {
+    pub fn create_agent(&self) -> Box<dyn MahjongAgent> {
+        match self.difficulty {
+            AIDifficulty::Beginner | AIDifficulty::Intermediate => {
+                Box::new(ShantenAgent::new(*self))
+            }
+            AIDifficulty::Strong => Box::new(SearchAgent::default().with_style(self.style)),
+        }
+    }
+}
+
+impl Display for AIDifficulty {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Beginner => write!(f, "Beginner"),
+            Self::Intermediate => write!(f, "Intermediate"),
+            Self::Strong => write!(f, "Strong"),
+        }
+    }
+}
+
+impl Display for AIStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Aggressive => write!(f, "Aggressive"),
+            Self::Defensive => write!(f, "Defensive"),
+            Self::ValueSeeking => write!(f, "Value-seeking"),
+        }
+    }
+}
+
+impl Display for AIProfile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.difficulty, self.style)
+    }
+}
@@ -0,0 +1,33 @@
+use super::{sort_by_is_mahjong, MahjongAgent};
+use crate::{game_summary::GameSummary, meld::PossibleMeld, TileId};
+use rand::{seq::SliceRandom, thread_rng};
+
+// Declares every meld it can and discards a random tile, it is useful as a baseline
+pub struct RandomAgent;
+
+impl MahjongAgent for RandomAgent {
+    fn choose_melds(
+        &mut self,
+        _observation: &GameSummary,
+        mut melds: Vec<PossibleMeld>,
+    ) -> Vec<PossibleMeld> {
+        melds.shuffle(&mut thread_rng());
+        melds.sort_by(sort_by_is_mahjong);
+
+        melds
+    }
+
+    fn choose_discards(&mut self, observation: &GameSummary) -> Vec<TileId> {
+        let mut discards = observation
+            .hand
+            .iter()
+            .flat_map(|hand| hand.list.iter())
+            .filter(|tile| tile.set_id.is_none())
+            .map(|tile| tile.id)
+            .collect::<Vec<TileId>>();
+
+        discards.shuffle(&mut thread_rng());
+
+        discards
+    }
+}
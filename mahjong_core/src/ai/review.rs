@@ -0,0 +1,181 @@
+use super::{get_hints, HintAction, SearchAgent, SearchBudget};
+use crate::{
+    game_summary::GameSummary, meld::PossibleMeld, round::RoundTileClaimed, Game, PlayerId, TileId,
+    Wind,
+};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+// The playouts are evaluated in batches, to know how much the loss changes between them
+const REVIEW_BATCHES: usize = 8;
+// The smaller losses are not worth reporting even if they are measured
+const MISTAKE_MIN_LOSS: f64 = 0.25;
+const REVIEW_BUDGET_MULTIPLIER: usize = 4;
+
+// An action of a player, with what the player could see when it was taken
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct GameDecision {
+    pub action: HintAction,
+    pub game_summary: GameSummary,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
+#[ts(export)]
+pub struct ReviewMistake {
+    pub action: HintAction,
+    // The mean score that the action lost in the playouts, compared with the recommended action
+    pub estimated_loss: f64,
+    pub player_id: PlayerId,
+    pub recommended: HintAction,
+    pub round_wind: Wind,
+    // The number of discards in the round before the decision, plus one
+    pub turn: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, TS)]
+#[ts(export)]
+pub struct GameReview {
+    pub decisions: usize,
+    pub mistakes: Vec<ReviewMistake>,
+}
+
+impl GameDecision {
+    pub fn from_game(game: &Game, player_id: &PlayerId, action: HintAction) -> Option<Self> {
+        Some(Self {
+            action,
+            game_summary: GameSummary::from_game(game, player_id)?,
+        })
+    }
+
+    // The claims are recorded when the player declares the meld with the claimed tile (or the
+    // mahjong), with what the player could see before claiming it. It is `None` if the tiles don't
+    // include the claimed tile, for example in a later meld of the hand.
+    pub fn from_claimed_meld(game: &Game, player_id: &PlayerId, tiles: &[TileId]) -> Option<Self> {
+        let tile_claimed = game.round.tile_claimed.as_ref()?;
+
+        if tile_claimed.by.as_ref() != Some(player_id) || !tiles.contains(&tile_claimed.id) {
+            return None;
+        }
+
+        let mut game_before_claim = game.clone();
+        let hand = game_before_claim.table.hands.0.get_mut(player_id)?;
+        let tile_position = hand
+            .list
+            .iter()
+            .position(|tile| tile.id == tile_claimed.id && tile.set_id.is_none())?;
+        hand.list.remove(tile_position);
+        game_before_claim.table.board.0.push(tile_claimed.id);
+        game_before_claim.round.player_index = game
+            .players
+            .iter()
+            .position(|player| *player == tile_claimed.from)?;
+        game_before_claim.round.tile_claimed = Some(RoundTileClaimed {
+            by: None,
+            ..tile_claimed.clone()
+        });
+        if let Some(discard) = game_before_claim
+            .round
+            .discards
+            .last_mut()
+            .filter(|discard| discard.tile == tile_claimed.id)
+        {
+            discard.claimed_by = None;
+        }
+
+        let game_summary = GameSummary::from_game(&game_before_claim, player_id)?;
+        // The same meld as in the hints when it is there, so the review can compare them
+        let mut sorted_tiles = tiles.to_vec();
+        sorted_tiles.sort();
+        let meld = game_summary
+            .get_possible_melds()
+            .into_iter()
+            .find(|meld| {
+                let mut meld_tiles = meld.tiles.clone();
+                meld_tiles.sort();
+
+                meld_tiles == sorted_tiles
+            })
+            .unwrap_or_else(|| PossibleMeld {
+                discard_tile: None,
+                is_concealed: false,
+                is_mahjong: false,
+                is_upgrade: false,
+                player_id: player_id.clone(),
+                tiles: tiles.to_vec(),
+            });
+
+        Some(Self {
+            action: HintAction::Claim { meld },
+            game_summary,
+        })
+    }
+}
+
+// The evaluator of the review. It plays the same greedy playouts as the search agent of the games,
+// but four times as many in each batch, because the review runs once after the game and doesn't
+// need to be fast.
+pub fn get_review_evaluator() -> SearchAgent {
+    SearchAgent::new(SearchBudget {
+        iterations: SearchBudget::default().iterations * REVIEW_BUDGET_MULTIPLIER,
+        time_ms: None,
+    })
+}
+
+// Compares the action with the first hint in the playouts of the evaluator. It is a mistake if the
+// action lost a meaningful score, clearly above the noise of the playouts.
+pub fn review_decision(
+    decision: &GameDecision,
+    evaluator: &mut SearchAgent,
+) -> Option<ReviewMistake> {
+    let game_summary = &decision.game_summary;
+    let recommended = get_hints(game_summary).into_iter().next()?.action;
+
+    if recommended == decision.action {
+        return None;
+    }
+
+    let actions = [recommended.clone(), decision.action.clone()];
+    let losses = (0..REVIEW_BATCHES)
+        .map(|_| {
+            let values = evaluator.evaluate_actions(game_summary, &actions);
+
+            values[0] - values[1]
+        })
+        .collect::<Vec<f64>>();
+    let batches = losses.len() as f64;
+    let estimated_loss = losses.iter().sum::<f64>() / batches;
+    let variance = losses
+        .iter()
+        .map(|loss| (loss - estimated_loss).powi(2))
+        .sum::<f64>()
+        / (batches - 1.0);
+    let std_error = (variance / batches).sqrt();
+
+    // Not finite when one of the actions couldn't be applied in the playouts
+    if !estimated_loss.is_finite()
+        || estimated_loss < MISTAKE_MIN_LOSS
+        || estimated_loss < 2.0 * std_error
+    {
+        return None;
+    }
+
+    Some(ReviewMistake {
+        action: decision.action.clone(),
+        estimated_loss,
+        player_id: game_summary.player_id.clone(),
+        recommended,
+        round_wind: game_summary.round.wind.clone(),
+        turn: game_summary.round.discards.len() + 1,
+    })
+}
+
+pub fn review_game(decisions: &[GameDecision], evaluator: &mut SearchAgent) -> GameReview {
+    GameReview {
+        decisions: decisions.len(),
+        mistakes: decisions
+            .iter()
+            .filter_map(|decision| review_decision(decision, evaluator))
+            .collect(),
+    }
+}
@@ -0,0 +1,354 @@
+use super::{
+    best_drops::get_scored_drops_for_profile, best_melds::get_scored_melds_for_style,
+    greedy_agent::GreedyAgent, AIDifficulty, AIProfile, AIStyle, AgentAction, HintAction,
+    MahjongAgent, StandardAI,
+};
+use crate::{game_summary::GameSummary, meld::PossibleMeld, Game, GamePhase, PlayerId, TileId};
+use rand::{rngs::StdRng, SeedableRng};
+use rustc_hash::FxHashSet;
+
+// The actions of a playout before giving up, a round rarely needs more than half of them
+const MAX_PLAYOUT_ACTIONS: usize = 600;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchBudget {
+    // Each iteration samples one game, and plays it once per candidate discard
+    pub iterations: usize,
+    // It is only checked when it is set, because the clock is not available in all the targets
+    // (e.g. wasm)
+    pub time_ms: Option<u64>,
+}
+
+impl Default for SearchBudget {
+    fn default() -> Self {
+        Self {
+            iterations: 16,
+            time_ms: None,
+        }
+    }
+}
+
+// Information-set Monte Carlo search of the discards and the claims. The concealed tiles of the
+// other players and the wall are sampled from what the player can't see, and the round is played
+// until the end with the greedy agent for everyone. The best ranked discards of the shanten agent,
+// or the claims and passing, are compared in the same samples by the score that they win over the
+// other players.
+pub struct SearchAgent {
+    pub budget: SearchBudget,
+    // The number of best ranked discards (or claims) that are compared
+    pub candidates: usize,
+    // It ranks the candidates and chooses the melds
+    pub style: AIStyle,
+    rng: StdRng,
+    scores: Vec<(AgentAction, i32)>,
+}
+
+impl Default for SearchAgent {
+    fn default() -> Self {
+        Self::new(SearchBudget::default())
+    }
+}
+
+impl SearchAgent {
+    pub fn new(budget: SearchBudget) -> Self {
+        Self {
+            budget,
+            candidates: 3,
+            style: AIStyle::default(),
+            rng: StdRng::from_entropy(),
+            scores: vec![],
+        }
+    }
+
+    // For reproducible decisions
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    pub fn with_style(mut self, style: AIStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn has_budget(&self, iteration: usize, start: Option<&std::time::Instant>) -> bool {
+        if iteration >= self.budget.iterations {
+            return false;
+        }
+
+        match (start, self.budget.time_ms) {
+            (Some(start), Some(time_ms)) => start.elapsed().as_millis() < time_ms as u128,
+            _ => true,
+        }
+    }
+
+    // The mean score of the player minus the mean score of the other players, for each candidate
+    pub fn evaluate_discards(&mut self, observation: &GameSummary, tiles: &[TileId]) -> Vec<f64> {
+        let actions = tiles
+            .iter()
+            .map(|tile| HintAction::Discard { tile: *tile })
+            .collect::<Vec<HintAction>>();
+
+        self.evaluate_actions(observation, &actions)
+    }
+
+    // The same as the discards, but with any action that the player can do now. The mean only
+    // counts the samples where the action could be applied, and it is negative infinity if it
+    // couldn't be applied in any of them.
+    pub fn evaluate_actions(
+        &mut self,
+        observation: &GameSummary,
+        actions: &[HintAction],
+    ) -> Vec<f64> {
+        let mut totals = vec![(0.0, 0); actions.len()];
+        let start = self.budget.time_ms.map(|_| std::time::Instant::now());
+        let mut iteration = 0;
+        let passed_tile = observation.round.discarded_tile;
+
+        while self.has_budget(iteration, start.as_ref()) {
+            let sampled_game = observation.sample_game(&mut self.rng);
+
+            for (action, (total, count)) in actions.iter().zip(totals.iter_mut()) {
+                let mut game = sampled_game.clone();
+
+                if !apply_action(&mut game, &observation.player_id, action) {
+                    continue;
+                }
+
+                let passed_tile = passed_tile.filter(|_| *action == HintAction::Pass);
+
+                *total += play_round(&mut game, &sampled_game, observation, passed_tile);
+                *count += 1;
+            }
+
+            iteration += 1;
+        }
+
+        totals
+            .into_iter()
+            .map(|(total, count)| {
+                if count == 0 {
+                    f64::NEG_INFINITY
+                } else {
+                    total / count as f64
+                }
+            })
+            .collect()
+    }
+}
+
+fn apply_action(game: &mut Game, player_id: &PlayerId, action: &HintAction) -> bool {
+    match action {
+        HintAction::Discard { tile } => game.discard_tile_to_board(tile).is_ok(),
+        HintAction::Claim { meld } => {
+            if !game.claim_tile(player_id) {
+                return false;
+            }
+
+            // The melds of the mahjong are declared in the playout
+            meld.is_mahjong
+                || game
+                    .create_meld(player_id, &meld.tiles, meld.is_upgrade, meld.is_concealed)
+                    .is_ok()
+        }
+        HintAction::Kong { meld } | HintAction::Meld { meld } => game
+            .create_meld(player_id, &meld.tiles, meld.is_upgrade, meld.is_concealed)
+            .is_ok(),
+        HintAction::Mahjong => game.say_mahjong(player_id).is_ok(),
+        HintAction::Pass => true,
+    }
+}
+
+// Declines the melds with a discarded tile, for the playouts after passing on it
+struct PassingAgent {
+    tile: TileId,
+}
+
+impl MahjongAgent for PassingAgent {
+    fn choose_melds(
+        &mut self,
+        observation: &GameSummary,
+        melds: Vec<PossibleMeld>,
+    ) -> Vec<PossibleMeld> {
+        let melds = melds
+            .into_iter()
+            .filter(|meld| !meld.tiles.contains(&self.tile))
+            .collect();
+
+        GreedyAgent.choose_melds(observation, melds)
+    }
+
+    fn choose_discards(&mut self, observation: &GameSummary) -> Vec<TileId> {
+        GreedyAgent.choose_discards(observation)
+    }
+}
+
+fn play_round(
+    game: &mut Game,
+    initial_game: &Game,
+    observation: &GameSummary,
+    passed_tile: Option<TileId>,
+) -> f64 {
+    let round_index = game.round.round_index;
+    let players = game.players.0.clone();
+    let mut game_ai = StandardAI::new(
+        game,
+        FxHashSet::from_iter(players.clone()),
+        FxHashSet::default(),
+    );
+    game_ai.can_draw_round = true;
+
+    for player_id in players.iter() {
+        game_ai.set_agent(player_id, Box::new(GreedyAgent));
+    }
+
+    if let Some(tile) = passed_tile {
+        game_ai.set_agent(&observation.player_id, Box::new(PassingAgent { tile }));
+    }
+
+    for _ in 0..MAX_PLAYOUT_ACTIONS {
+        if game_ai.game.phase != GamePhase::Playing || game_ai.game.round.round_index != round_index
+        {
+            break;
+        }
+
+        if !game_ai.play_action(false).changed {
+            break;
+        }
+    }
+
+    let get_delta = |player_id: &String| {
+        let initial_score = initial_game
+            .score
+            .get(player_id)
+            .cloned()
+            .unwrap_or_default();
+        let final_score = game.score.get(player_id).cloned().unwrap_or_default();
+
+        (final_score - initial_score) as f64
+    };
+    let others_delta = players
+        .iter()
+        .filter(|player_id| **player_id != observation.player_id)
+        .map(get_delta)
+        .sum::<f64>()
+        / (players.len().max(2) - 1) as f64;
+
+    get_delta(&observation.player_id) - others_delta
+}
+
+impl MahjongAgent for SearchAgent {
+    fn choose_melds(
+        &mut self,
+        observation: &GameSummary,
+        melds: Vec<PossibleMeld>,
+    ) -> Vec<PossibleMeld> {
+        let scored_melds = get_scored_melds_for_style(observation, melds, &self.style);
+
+        self.scores = scored_melds
+            .iter()
+            .map(|scored| (AgentAction::Meld(scored.meld.clone()), scored.score))
+            .collect();
+        // The mahjong is always declared, so there is nothing to compare
+        let has_mahjong = scored_melds.iter().any(|scored| scored.meld.is_mahjong);
+        // The claims of the discarded tile are compared with passing on it. The melds of the own
+        // hand (e.g. the concealed kongs) keep the ranking, because the greedy playouts always
+        // declare them.
+        let claims = scored_melds
+            .iter()
+            .filter(|scored| scored.needs_claim && !has_mahjong)
+            .take(self.candidates)
+            .map(|scored| scored.meld.clone())
+            .collect::<Vec<PossibleMeld>>();
+        let other_melds = scored_melds
+            .into_iter()
+            .filter(|scored| scored.is_worth && !claims.contains(&scored.meld))
+            .map(|scored| scored.meld)
+            .collect::<Vec<PossibleMeld>>();
+
+        if claims.is_empty() {
+            return other_melds;
+        }
+
+        let actions = claims
+            .iter()
+            .map(|meld| HintAction::Claim { meld: meld.clone() })
+            .chain([HintAction::Pass])
+            .collect::<Vec<HintAction>>();
+        let mut values = self.evaluate_actions(observation, &actions);
+        let pass_value = values.pop().unwrap_or_default();
+        // The searched claims are scored by the points that they win over passing
+        self.scores = claims
+            .iter()
+            .zip(values.iter())
+            .map(|(meld, value)| {
+                let score = -(value - pass_value).round() as i32;
+
+                (AgentAction::Meld(meld.clone()), score)
+            })
+            .collect();
+        let mut searched = claims
+            .into_iter()
+            .zip(values)
+            .filter(|(_, value)| *value > pass_value)
+            .collect::<Vec<_>>();
+        // Stable, so the ties keep the order of the ranking
+        searched.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        searched
+            .into_iter()
+            .map(|(meld, _)| meld)
+            .chain(other_melds)
+            .collect()
+    }
+
+    fn choose_discards(&mut self, observation: &GameSummary) -> Vec<TileId> {
+        let profile = AIProfile {
+            difficulty: AIDifficulty::Strong,
+            style: self.style,
+        };
+        let drops = get_scored_drops_for_profile(observation, &profile).unwrap_or_default();
+        self.scores = drops
+            .iter()
+            .map(|drop| (AgentAction::Discard(drop.id), drop.score))
+            .collect();
+        let Some(best_shanten) = drops.first().map(|drop| drop.shanten) else {
+            return vec![];
+        };
+        // Only the discards that don't move the hand away from being ready are compared
+        let candidates = drops
+            .iter()
+            .take(self.candidates)
+            .take_while(|drop| drop.shanten == best_shanten)
+            .map(|drop| drop.id)
+            .collect::<Vec<TileId>>();
+        let mut discards = drops.iter().map(|drop| drop.id).collect::<Vec<TileId>>();
+
+        if candidates.len() < 2 {
+            return discards;
+        }
+
+        let values = self.evaluate_discards(observation, &candidates);
+        // The searched discards are only comparable between them, by the points that they win
+        self.scores = candidates
+            .iter()
+            .zip(values.iter())
+            .map(|(tile, value)| (AgentAction::Discard(*tile), -value.round() as i32))
+            .collect();
+        let mut searched = candidates.iter().zip(values).collect::<Vec<_>>();
+        // Stable, so the ties keep the order of the ranking
+        searched.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        discards.retain(|tile| !candidates.contains(tile));
+
+        searched
+            .into_iter()
+            .map(|(tile, _)| *tile)
+            .chain(discards)
+            .collect()
+    }
+
+    fn explain(&self) -> Vec<(AgentAction, i32)> {
+        self.scores.clone()
+    }
+}
@@ -0,0 +1,58 @@
+use super::{
+    best_drops::get_scored_drops_for_profile, best_melds::get_scored_melds_for_style, AIProfile,
+    AgentAction, MahjongAgent,
+};
+use crate::{game_summary::GameSummary, meld::PossibleMeld, TileId};
+
+// Declares the melds that improve the hand, and discards the tiles that keep the hand closest to be
+// ready, as the profile values them
+#[derive(Default)]
+pub struct ShantenAgent {
+    pub profile: AIProfile,
+    scores: Vec<(AgentAction, i32)>,
+}
+
+impl ShantenAgent {
+    pub fn new(profile: AIProfile) -> Self {
+        Self {
+            profile,
+            scores: vec![],
+        }
+    }
+}
+
+impl MahjongAgent for ShantenAgent {
+    fn choose_melds(
+        &mut self,
+        observation: &GameSummary,
+        melds: Vec<PossibleMeld>,
+    ) -> Vec<PossibleMeld> {
+        let scored_melds = get_scored_melds_for_style(observation, melds, &self.profile.style);
+
+        self.scores = scored_melds
+            .iter()
+            .map(|scored| (AgentAction::Meld(scored.meld.clone()), scored.score))
+            .collect();
+
+        scored_melds
+            .into_iter()
+            .filter(|scored| scored.is_worth)
+            .map(|scored| scored.meld)
+            .collect()
+    }
+
+    fn choose_discards(&mut self, observation: &GameSummary) -> Vec<TileId> {
+        let drops = get_scored_drops_for_profile(observation, &self.profile).unwrap_or_default();
+
+        self.scores = drops
+            .iter()
+            .map(|drop| (AgentAction::Discard(drop.id), drop.score))
+            .collect();
+
+        drops.iter().map(|drop| drop.id).collect()
+    }
+
+    fn explain(&self) -> Vec<(AgentAction, i32)> {
+        self.scores.clone()
+    }
+}
@@ -0,0 +1,236 @@
+// Estimation of how likely a discard is to complete the hand of another player. It only uses what
+// the player can see: the discards of each opponent and the tiles that they let pass, their
+// exposed melds, when they claimed tiles, and the unseen copies that can form each wait.
+
+use crate::{
+    game_summary::GameSummary,
+    tile_kind::{TileKind, TileKindCounts, TILE_KINDS_NUM},
+    PlayerId, TileId,
+};
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+// The readiness of a hand without melds grows with the discards, until it is likely ready
+const BASE_READINESS: f64 = 0.02;
+const READINESS_PER_DISCARD: f64 = 0.025;
+const READINESS_PER_MELD: f64 = 0.12;
+// A claim in the last go-around is often the last step to be ready
+const RECENT_CLAIM_READINESS: f64 = 0.1;
+const MAX_READINESS: f64 = 0.9;
+// Relative weights of the waits, the two-sided waits are the most common
+const SIDE_WAIT_WEIGHT: f64 = 1.0;
+const CLOSED_WAIT_WEIGHT: f64 = 0.5;
+const PAIR_WAIT_WEIGHT: f64 = 0.5;
+// No rule forbids winning with a tile that the player discarded, but it is rarely the case
+const OWN_DISCARD_FACTOR: f64 = 0.2;
+// The side waits that would have won with a discarded tile (e.g. a 3-6 wait after discarding 6)
+const SUJI_FACTOR: f64 = 0.3;
+// The hand didn't change after letting the tile pass, so it was not waiting for it
+const PASSED_FACTOR: f64 = 0.1;
+// A ready hand waits on less than two kinds on average
+const WAITED_KINDS: f64 = 2.0;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub struct OpponentReadiness {
+    pub player_id: PlayerId,
+    // From 0 to 100, the chance that the hand is ready
+    pub readiness: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub struct TileDanger {
+    // From 0 to 100, the chance that the tile completes the hand of an opponent
+    pub danger: u32,
+    // The opponent that is most likely to win with the tile
+    pub player_id: Option<PlayerId>,
+    pub tile: TileId,
+}
+
+fn to_percent(value: f64) -> u32 {
+    (value * 100.0).round() as u32
+}
+
+fn get_side_waits(kind: &TileKind) -> Vec<(usize, usize, f64, Option<usize>)> {
+    let Some(value) = kind.get_suit_value() else {
+        return vec![];
+    };
+    let index = kind.index();
+    let mut waits = vec![];
+
+    // The other tile that the two-sided wait accepts, for the suji
+    if value >= 3 {
+        let suji = (value >= 4).then(|| index - 3);
+        waits.push((index - 2, index - 1, SIDE_WAIT_WEIGHT, suji));
+    }
+    if value <= 7 {
+        let suji = (value <= 6).then(|| index + 3);
+        waits.push((index + 1, index + 2, SIDE_WAIT_WEIGHT, suji));
+    }
+    if (2..=8).contains(&value) {
+        waits.push((index - 1, index + 1, CLOSED_WAIT_WEIGHT, None));
+    }
+
+    waits
+}
+
+impl GameSummary {
+    fn get_opponent_readiness(&self, player_id: &PlayerId) -> f64 {
+        let Some(other_hand) = self.other_hands.0.get(player_id) else {
+            return 0.0;
+        };
+        let melds = other_hand
+            .visible
+            .list
+            .iter()
+            .filter_map(|tile| tile.set_id.clone())
+            .collect::<FxHashSet<_>>()
+            .len();
+        let discards = &self.round.discards;
+        let own_discards = discards
+            .iter()
+            .filter(|discard| &discard.player_id == player_id)
+            .count();
+        let has_recent_claim = discards
+            .iter()
+            .rev()
+            .take(self.players.len())
+            .any(|discard| discard.claimed_by.as_ref() == Some(player_id));
+
+        let mut readiness = BASE_READINESS
+            + READINESS_PER_DISCARD * own_discards as f64
+            + READINESS_PER_MELD * melds as f64;
+        if has_recent_claim {
+            readiness += RECENT_CLAIM_READINESS;
+        }
+
+        readiness.min(MAX_READINESS)
+    }
+
+    pub fn get_opponents_readiness(&self) -> Vec<OpponentReadiness> {
+        self.players
+            .iter()
+            .filter(|player_id| **player_id != self.player_id)
+            .map(|player_id| OpponentReadiness {
+                player_id: player_id.clone(),
+                readiness: to_percent(self.get_opponent_readiness(player_id)),
+            })
+            .collect()
+    }
+
+    // The chance that each tile kind completes the hand of the opponent, if it is ready
+    fn get_opponent_waits(
+        &self,
+        player_id: &PlayerId,
+        unseen: &TileKindCounts,
+    ) -> [f64; TILE_KINDS_NUM] {
+        let discards = &self.round.discards;
+        let mut own_discards = [false; TILE_KINDS_NUM];
+        let mut passed = [false; TILE_KINDS_NUM];
+        let last_own_discard = discards
+            .iter()
+            .rposition(|discard| &discard.player_id == player_id);
+
+        for (index, discard) in discards.iter().enumerate() {
+            let kind_index = TileKind::from_id(discard.tile).index();
+
+            if &discard.player_id == player_id {
+                own_discards[kind_index] = true;
+            } else if last_own_discard.is_some_and(|last| index > last) {
+                passed[kind_index] = true;
+            }
+        }
+
+        let mut weights = [0.0; TILE_KINDS_NUM];
+
+        for kind in TileKind::iter_normal() {
+            let index = kind.index();
+            let copies = unseen[index] as f64;
+            let mut weight = PAIR_WAIT_WEIGHT * (copies + copies * (copies - 1.0).max(0.0) / 2.0);
+
+            for (first, second, wait_weight, suji) in get_side_waits(&kind) {
+                let mut shapes = wait_weight * (unseen[first] as f64) * (unseen[second] as f64);
+                if suji.is_some_and(|suji| own_discards[suji]) {
+                    shapes *= SUJI_FACTOR;
+                }
+                weight += shapes;
+            }
+
+            if own_discards[index] {
+                weight *= OWN_DISCARD_FACTOR;
+            }
+            if passed[index] {
+                weight *= PASSED_FACTOR;
+            }
+
+            weights[index] = weight;
+        }
+
+        let total = weights.iter().sum::<f64>();
+        if total > 0.0 {
+            for weight in weights.iter_mut() {
+                *weight = (*weight * WAITED_KINDS / total).min(1.0);
+            }
+        }
+
+        weights
+    }
+
+    // For each tile kind, the chance that it completes the hand of any opponent, and the opponent
+    // that is most likely to win with it
+    pub(crate) fn get_kinds_danger(&self) -> [(f64, Option<PlayerId>); TILE_KINDS_NUM] {
+        let unseen = self.get_unseen_counts();
+        let mut safe = [1.0; TILE_KINDS_NUM];
+        let mut riskiest: [(f64, Option<PlayerId>); TILE_KINDS_NUM] =
+            std::array::from_fn(|_| (0.0, None));
+
+        for player_id in self.players.iter() {
+            if *player_id == self.player_id {
+                continue;
+            }
+
+            let readiness = self.get_opponent_readiness(player_id);
+            let waits = self.get_opponent_waits(player_id, &unseen);
+
+            for index in 0..TILE_KINDS_NUM {
+                let danger = readiness * waits[index];
+                safe[index] *= 1.0 - danger;
+
+                if danger > riskiest[index].0 {
+                    riskiest[index] = (danger, Some(player_id.clone()));
+                }
+            }
+        }
+
+        std::array::from_fn(|index| (1.0 - safe[index], riskiest[index].1.clone()))
+    }
+
+    // The danger of each tile of the hand that can be discarded
+    pub fn get_tiles_danger(&self) -> Vec<TileDanger> {
+        let Some(hand) = self.hand.as_ref() else {
+            return vec![];
+        };
+        let kinds_danger = self.get_kinds_danger();
+
+        hand.list
+            .iter()
+            .filter(|tile| tile.set_id.is_none())
+            .map(|tile| {
+                let kind = TileKind::from_id(tile.id);
+                let (danger, player_id) = if kind.is_bonus() {
+                    (0.0, None)
+                } else {
+                    kinds_danger[kind.index()].clone()
+                };
+
+                TileDanger {
+                    danger: to_percent(danger),
+                    player_id,
+                    tile: tile.id,
+                }
+            })
+            .collect()
+    }
+}
@@ -3,8 +3,9 @@ use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
 use crate::{
-    game::Players, table::BonusTiles, Board, Dragon, DragonTile, DrawWall, Flower, FlowerTile,
-    Hand, Hands, HandsMap, Season, SeasonTile, Suit, SuitTile, Table, Tile, TileId, Wind, WindTile,
+    game::Players, ruleset::Ruleset, table::BonusTiles, AnimalTile, Board, Dragon, DragonTile,
+    DrawWall, Flower, FlowerTile, Hand, Hands, HandsMap, Season, SeasonTile, Suit, SuitTile, Table,
+    Tile, TileId, Wind, WindTile, ANIMALS_ORDER,
 };
 
 pub type DeckContent = Vec<Tile>;
@@ -70,6 +71,14 @@ fn create_deck_content() -> DeckContent {
         }
     }
 
+    // The animals are added at the end so the ids of the rest of tiles don't depend on the style
+    for animal in ANIMALS_ORDER {
+        deck_list.push(Tile::Animal(AnimalTile {
+            id: 0,
+            value: animal.clone(),
+        }));
+    }
+
     let mut deck: DeckContent = vec![];
 
     deck_list.iter().enumerate().for_each(|(index, tile)| {
@@ -100,12 +109,13 @@ impl Deck {
             .clone()
     }
 
-    pub fn create_table(&self, players: &Players) -> Table {
+    pub fn create_table(&self, players: &Players, ruleset: &dyn Ruleset) -> Table {
         let Self(deck_content) = self;
-        let mut ids: Vec<usize> = vec![];
-        for id in 0..deck_content.len() {
-            ids.push(id);
-        }
+        let ids: Vec<TileId> = deck_content
+            .iter()
+            .filter(|tile| ruleset.has_tile(tile))
+            .map(|tile| tile.get_id())
+            .collect();
         let draw_wall = DrawWall::new(ids);
 
         let hands_map = players
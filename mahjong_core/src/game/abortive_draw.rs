@@ -0,0 +1,148 @@
+use super::{AbortiveDrawError, Game, GamePhase, PlayerId};
+use crate::{
+    deck::DEFAULT_DECK,
+    round::{AbortiveDraw, RoundOutcome},
+    Tile,
+};
+
+impl Game {
+    pub fn is_abortive_draw(&self, abortive_draw: &AbortiveDraw, player_id: &PlayerId) -> bool {
+        match abortive_draw {
+            AbortiveDraw::FourKongs => self.has_four_kongs(),
+            AbortiveDraw::FourWinds => self.has_four_winds(),
+            AbortiveDraw::NineTerminals => self.has_nine_terminals(player_id),
+        }
+    }
+
+    pub fn declare_abortive_draw(
+        &mut self,
+        player_id: &PlayerId,
+        abortive_draw: &AbortiveDraw,
+    ) -> Result<(), AbortiveDrawError> {
+        if self.phase != GamePhase::Playing
+            || !self
+                .get_ruleset()
+                .get_abortive_draws()
+                .contains(abortive_draw)
+        {
+            return Err(AbortiveDrawError::NotAllowed);
+        }
+
+        if !self.is_abortive_draw(abortive_draw, player_id) {
+            return Err(AbortiveDrawError::ConditionNotMet);
+        }
+
+        self.abort_round(abortive_draw);
+
+        Ok(())
+    }
+
+    // The nine terminals draw is optional for the player, so only the others are detected
+    pub fn get_detected_abortive_draw(&self) -> Option<AbortiveDraw> {
+        if self.phase != GamePhase::Playing {
+            return None;
+        }
+
+        let current_player = self.get_current_player()?;
+
+        self.get_ruleset()
+            .get_abortive_draws()
+            .into_iter()
+            .filter(|abortive_draw| abortive_draw != &AbortiveDraw::NineTerminals)
+            .find(|abortive_draw| self.is_abortive_draw(abortive_draw, &current_player))
+    }
+
+    pub fn check_abortive_draws(&mut self) -> Option<AbortiveDraw> {
+        let abortive_draw = self.get_detected_abortive_draw()?;
+
+        self.abort_round(&abortive_draw);
+
+        Some(abortive_draw)
+    }
+
+    fn abort_round(&mut self, abortive_draw: &AbortiveDraw) {
+        let ruleset = self.style.get_ruleset(&self.house_rules);
+        let dealer_keeps_seat = ruleset.dealer_keeps_seat_after_draw();
+        self.round
+            .move_after_draw(&mut self.phase, dealer_keeps_seat, &*ruleset);
+        self.round.last_outcome = Some(RoundOutcome::AbortiveDraw(abortive_draw.clone()));
+
+        if self.phase != GamePhase::End {
+            self.phase = GamePhase::InitialShuffle;
+        }
+    }
+
+    // No melds were exposed yet in the round. The concealed kongs don't interrupt it, because
+    // they don't claim a discard.
+    fn is_first_go_around_uninterrupted(&self) -> bool {
+        self.table.hands.0.values().all(|hand| {
+            hand.list
+                .iter()
+                .all(|tile| tile.set_id.is_none() || tile.concealed)
+        })
+    }
+
+    fn has_four_kongs(&self) -> bool {
+        let kongs_per_player = self
+            .table
+            .hands
+            .0
+            .values()
+            .map(|hand| hand.kong_tiles.len())
+            .filter(|kongs| *kongs > 0)
+            .collect::<Vec<_>>();
+
+        kongs_per_player.len() > 1 && kongs_per_player.iter().sum::<usize>() == 4
+    }
+
+    fn has_four_winds(&self) -> bool {
+        let board = &self.table.board.0;
+
+        if board.len() != self.get_ruleset().get_players_num()
+            || !self.is_first_go_around_uninterrupted()
+        {
+            return false;
+        }
+
+        let first_tile = &DEFAULT_DECK.0[board[0]];
+
+        matches!(first_tile, Tile::Wind(_))
+            && board
+                .iter()
+                .all(|tile_id| DEFAULT_DECK.0[*tile_id].is_same_content(first_tile))
+    }
+
+    fn has_nine_terminals(&self, player_id: &PlayerId) -> bool {
+        let hand = match self.table.hands.0.get(player_id) {
+            Some(hand) => hand,
+            None => return false,
+        };
+        let player_index = match self.players.iter().position(|p| p == player_id) {
+            Some(player_index) => player_index,
+            None => return false,
+        };
+        let players_num = self.players.len();
+        let turns_since_dealer =
+            (player_index + players_num - self.round.dealer_player_index) % players_num;
+
+        if self.round.player_index != player_index
+            || hand.len() != self.get_ruleset().tiles_after_claim()
+            || self.table.board.0.len() != turns_since_dealer
+            || !self.is_first_go_around_uninterrupted()
+        {
+            return false;
+        }
+
+        let mut terminals: Vec<&Tile> = vec![];
+
+        for hand_tile in hand.list.iter() {
+            let tile = &DEFAULT_DECK.0[hand_tile.id];
+
+            if tile.is_terminal_or_honour() && !terminals.iter().any(|t| t.is_same_content(tile)) {
+                terminals.push(tile);
+            }
+        }
+
+        terminals.len() >= 9
+    }
+}
@@ -2,12 +2,14 @@ use super::{
     definition::{Game, GamePhase, GameStyle},
     Players,
 };
-use crate::{deck::DEFAULT_DECK, round::Round, Score};
+use crate::{deck::DEFAULT_DECK, round::Round, ruleset::HouseRules, Score};
 use uuid::Uuid;
 
 #[derive(Default, Clone)]
 pub struct GameNewOpts {
     pub players: Option<Players>,
+    pub style: Option<GameStyle>,
+    pub house_rules: Option<HouseRules>,
 }
 
 impl Game {
@@ -15,9 +17,10 @@ impl Game {
         let parsed_opts = opts.unwrap_or_default();
         let version = Uuid::new_v4().to_string();
         let players = parsed_opts.players.clone().unwrap_or_default();
-        let game_style = GameStyle::HongKong;
+        let game_style = parsed_opts.style.unwrap_or_default();
+        let house_rules = parsed_opts.house_rules.unwrap_or_default();
 
-        let table = DEFAULT_DECK.create_table(&players);
+        let table = DEFAULT_DECK.create_table(&players, &*game_style.get_ruleset(&house_rules));
         let score = Score::new(&players.0);
 
         Self {
@@ -27,7 +30,8 @@ impl Game {
             players,
             round: Round::new(&game_style),
             score,
-            style: GameStyle::HongKong,
+            style: game_style,
+            house_rules,
             table,
             version,
         }
@@ -1,5 +1,10 @@
-use super::Players;
-use crate::{macros::derive_game_common, round::Round, Score, Table, TileId};
+use super::{PlayerId, Players};
+use crate::{
+    macros::derive_game_common,
+    round::{Round, TileClaimed},
+    ruleset::HouseRules,
+    HandTile, Score, Table, TileId,
+};
 use std::{
     fmt::{Display, Formatter},
     str::FromStr,
@@ -24,6 +29,7 @@ derive_game_common! {
 #[ts(export)]
 pub enum GameStyle {
     HongKong,
+    Singapore,
 }}
 
 pub type GameId = String;
@@ -42,6 +48,8 @@ pub struct Game {
     pub table: Table,
     pub version: GameVersion,
     pub style: GameStyle,
+    #[serde(default)]
+    pub house_rules: HouseRules,
 }}
 
 derive_game_common! {
@@ -53,11 +61,18 @@ pub enum DrawTileResult {
     WallExhausted,
 }}
 
+// What `Game::apply_discard` changed, so it can be reverted
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiscardUndo {
+    pub player_id: PlayerId,
+    pub previous_tile_claimed: TileClaimed,
+    pub tile: HandTile,
+    pub tile_index: usize,
+}
+
 impl Game {
     pub fn get_players_num(style: &GameStyle) -> usize {
-        match style {
-            GameStyle::HongKong => 4,
-        }
+        style.get_ruleset(&HouseRules::default()).get_players_num()
     }
 }
 
@@ -77,11 +92,13 @@ impl Display for GamePhase {
 }
 
 const STYLE_HONG_KONG: &str = "Hong Kong";
+const STYLE_SINGAPORE: &str = "Singapore";
 
 impl Display for GameStyle {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::HongKong => f.write_str(STYLE_HONG_KONG),
+            Self::Singapore => f.write_str(STYLE_SINGAPORE),
         }
     }
 }
@@ -92,6 +109,7 @@ impl FromStr for GameStyle {
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         match input {
             STYLE_HONG_KONG => Ok(Self::HongKong),
+            STYLE_SINGAPORE => Ok(Self::Singapore),
             _ => Err(()),
         }
     }
@@ -102,16 +120,3 @@ impl Default for GameStyle {
         Self::HongKong
     }
 }
-
-impl GameStyle {
-    pub fn tiles_after_claim(&self) -> usize {
-        match self {
-            Self::HongKong => 14,
-        }
-    }
-    pub fn max_consecutive_same_seats(&self) -> usize {
-        match self {
-            Self::HongKong => 3,
-        }
-    }
-}
@@ -11,6 +11,7 @@ pub enum DiscardTileError {
 
 #[derive(Debug, PartialEq, Eq, Clone, EnumIter)]
 pub enum CreateMeldError {
+    DeadHand,
     EndRound,
     NotMeld,
     TileIsPartOfMeld,
@@ -45,3 +46,9 @@ impl Default for DrawError {
         Self::NotEnoughTiles
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, EnumIter)]
+pub enum AbortiveDrawError {
+    ConditionNotMet,
+    NotAllowed,
+}
@@ -1,26 +1,29 @@
 pub use self::creation::GameNewOpts;
-pub use self::definition::{DrawTileResult, Game, GameId, GamePhase, GameStyle, GameVersion};
+pub use self::definition::{
+    DiscardUndo, DrawTileResult, Game, GameId, GamePhase, GameStyle, GameVersion,
+};
 use self::errors::DecideDealerError;
 pub use self::errors::{
-    BreakMeldError, CreateMeldError, DiscardTileError, DrawError, PassNullRoundError,
+    AbortiveDrawError, BreakMeldError, CreateMeldError, DiscardTileError, DrawError,
+    PassNullRoundError,
 };
 pub use self::players::{PlayerId, Players, PlayersVec};
-use crate::hand::KongTile;
+use crate::hand::{KongTile, MeldSupplier};
 use crate::table::PositionTilesOpts;
 use crate::{
     deck::DEFAULT_DECK,
     hand::CanSayMahjongError,
-    meld::{
-        get_is_chow, get_is_kong, get_is_pung, get_tile_claimed_id_for_user, PlayerDiff,
-        PossibleMeld, SetCheckOpts,
-    },
-    round::{Round, RoundTileClaimed},
-    Hand, HandTile, TileId,
+    meld::{get_tile_claimed_id_for_user, PlayerDiff, PossibleMeld, SetCheckOpts},
+    round::{Round, RoundDiscard, RoundOutcome, RoundTileClaimed},
+    ruleset::{DiscardWinners, Ruleset},
+    Hand, HandTile, TileId, TileKind,
 };
 use crate::{Tile, Wind, WINDS_ROUND_ORDER};
 use rustc_hash::FxHashSet;
+use std::cmp::Reverse;
 use uuid::Uuid;
 
+mod abortive_draw;
 mod charleston;
 mod creation;
 mod definition;
@@ -34,10 +37,24 @@ impl Game {
         &self,
         player: &PlayerId,
         check_for_mahjong: bool,
+    ) -> Vec<PossibleMeld> {
+        self.get_player_possible_melds(&*self.get_ruleset(), player, check_for_mahjong)
+    }
+
+    fn get_player_possible_melds(
+        &self,
+        ruleset: &dyn Ruleset,
+        player: &PlayerId,
+        check_for_mahjong: bool,
     ) -> Vec<PossibleMeld> {
         let mut melds: Vec<PossibleMeld> = vec![];
 
-        let (can_claim_tile, tile_claimed, player_hand) = self.get_can_claim_tile(player);
+        let (can_claim_tile, tile_claimed, player_hand) =
+            self.get_player_can_claim_tile(ruleset, player);
+
+        if player_hand.is_some_and(|hand| hand.is_dead) {
+            return melds;
+        }
 
         let hand = if can_claim_tile {
             let mut hand = player_hand.unwrap().clone();
@@ -64,8 +81,12 @@ impl Game {
             self.get_board_tile_player_diff(Some(&round), Some(&hand), player);
         let claimed_tile = get_tile_claimed_id_for_user(player, &round.tile_claimed);
 
-        let possible_melds =
-            hand.get_possible_melds(board_tile_player_diff, claimed_tile, check_for_mahjong);
+        let possible_melds = hand.get_possible_melds(
+            ruleset,
+            board_tile_player_diff,
+            claimed_tile,
+            check_for_mahjong,
+        );
 
         for meld in possible_melds {
             melds.push(PossibleMeld {
@@ -82,6 +103,14 @@ impl Game {
     }
 
     pub fn get_can_claim_tile(&self, player: &PlayerId) -> (bool, Option<TileId>, Option<&Hand>) {
+        self.get_player_can_claim_tile(&*self.get_ruleset(), player)
+    }
+
+    fn get_player_can_claim_tile(
+        &self,
+        ruleset: &dyn Ruleset,
+        player: &PlayerId,
+    ) -> (bool, Option<TileId>, Option<&Hand>) {
         let tile_claimed = self.round.get_claimable_tile(player);
         let player_hand = self.table.hands.0.get(player);
 
@@ -90,22 +119,33 @@ impl Game {
         }
 
         let player_hand = player_hand.unwrap();
-        let can_claim_tile =
-            tile_claimed.is_some() && player_hand.len() < self.style.tiles_after_claim();
+        let can_claim_tile = tile_claimed.is_some()
+            && !player_hand.is_dead
+            && player_hand.len() < ruleset.tiles_after_claim();
 
         (can_claim_tile, tile_claimed, Some(player_hand))
     }
 
     pub fn get_possible_melds(&self, early_return: bool) -> Vec<PossibleMeld> {
-        let mut melds: Vec<PossibleMeld> = vec![];
         let mut players = self.players.clone();
 
         if early_return {
             players.shuffle();
         }
 
-        for player in &players.0 {
-            let mut player_melds = self.get_possible_melds_for_player(player, true);
+        self.get_possible_melds_for_players(&players.0, early_return)
+    }
+
+    fn get_possible_melds_for_players(
+        &self,
+        players: &[PlayerId],
+        early_return: bool,
+    ) -> Vec<PossibleMeld> {
+        let mut melds: Vec<PossibleMeld> = vec![];
+        let ruleset = self.get_ruleset();
+
+        for player in players {
+            let mut player_melds = self.get_player_possible_melds(&*ruleset, player, true);
 
             if early_return && !player_melds.is_empty() {
                 return player_melds;
@@ -114,8 +154,8 @@ impl Game {
             melds.append(&mut player_melds);
         }
 
-        for player in &players.0 {
-            let mut player_melds = self.get_possible_melds_for_player(player, false);
+        for player in players {
+            let mut player_melds = self.get_player_possible_melds(&*ruleset, player, false);
 
             if early_return && !player_melds.is_empty() {
                 return player_melds;
@@ -124,62 +164,63 @@ impl Game {
             melds.append(&mut player_melds);
         }
 
+        melds.sort_by_key(|meld| Reverse(ruleset.get_claim_priority(meld)));
+
         melds
     }
 
-    pub fn get_possible_melds_by_discard(&self) -> Vec<PossibleMeld> {
+    // The melds that other players could claim after each possible discard. The discards are
+    // applied and reverted in place, so the game is the same after calling it.
+    pub fn get_possible_melds_by_discard(&mut self) -> Vec<PossibleMeld> {
         let mut melds = self.get_possible_melds(false);
+        let tiles_after_claim = self.get_ruleset().tiles_after_claim();
 
-        let player_index = self
+        let player_id = match self
             .players
             .iter()
-            .position(|p| self.table.hands.get(p).unwrap().len() == self.style.tiles_after_claim());
-
-        if player_index.is_none() {
-            return melds;
-        }
-
-        let player_index = player_index.unwrap();
-        let player_id = self.players.get(player_index).unwrap().clone();
+            .find(|p| self.table.hands.0.get(*p).unwrap().len() == tiles_after_claim)
+        {
+            Some(player_id) => player_id.clone(),
+            None => return melds,
+        };
 
-        let player_hand: Vec<HandTile> = self
-            .table
-            .hands
-            .get(&player_id)
-            .clone()
-            .unwrap()
+        // Only the other players can claim the discard
+        let other_players: Vec<PlayerId> = self
+            .players
+            .iter()
+            .filter(|p| **p != player_id)
+            .cloned()
+            .collect();
+        let discard_tiles: Vec<TileId> = self.table.hands.0[&player_id]
             .list
-            .into_iter()
+            .iter()
             .filter(|t| t.set_id.is_none())
+            .map(|t| t.id)
             .collect();
 
-        player_hand.iter().for_each(|hand_tile| {
-            let mut game_copy = self.clone();
-
-            game_copy
-                .discard_tile_to_board(&hand_tile.id)
-                .unwrap_or_default();
-
-            let new_melds = game_copy.get_possible_melds(false);
+        for discard_tile in discard_tiles {
+            let undo = match self.apply_discard(&discard_tile) {
+                Ok(undo) => undo,
+                Err(_) => continue,
+            };
 
-            new_melds
-                .iter()
-                .filter(|m| m.player_id != player_id)
-                .for_each(|meld| {
-                    if !meld.tiles.iter().any(|t| t == &hand_tile.id) {
-                        return;
-                    }
+            // If the discard ends the round, the tile can't be claimed
+            if self.get_detected_abortive_draw().is_none() {
+                let new_melds = self.get_possible_melds_for_players(&other_players, false);
+
+                melds.extend(
+                    new_melds
+                        .into_iter()
+                        .filter(|meld| meld.tiles.contains(&discard_tile))
+                        .map(|meld| PossibleMeld {
+                            discard_tile: Some(discard_tile),
+                            ..meld
+                        }),
+                );
+            }
 
-                    melds.push(PossibleMeld {
-                        discard_tile: Some(hand_tile.id),
-                        is_concealed: meld.is_concealed,
-                        is_mahjong: meld.is_mahjong,
-                        is_upgrade: meld.is_upgrade,
-                        player_id: meld.player_id.clone(),
-                        tiles: meld.tiles.clone(),
-                    });
-                });
-        });
+            self.rollback_discard(undo);
+        }
 
         melds
     }
@@ -258,13 +299,85 @@ impl Game {
 
     pub fn say_mahjong(&mut self, player_id: &PlayerId) -> Result<(), CanSayMahjongError> {
         let hand = self.table.hands.get(player_id).unwrap();
+        let ruleset = self.get_ruleset();
 
-        hand.can_say_mahjong()?;
+        ruleset.can_say_mahjong(&hand)?;
 
-        self.calculate_hand_score(player_id);
-        let player_index = self.players.iter().position(|p| p == player_id).unwrap();
+        let discard_winners = ruleset.get_discard_winners();
+        let other_winners = if discard_winners == DiscardWinners::FirstCaller {
+            vec![]
+        } else {
+            self.find_other_discard_winners(&*ruleset, player_id)
+        };
+
+        let winners = match discard_winners {
+            DiscardWinners::HeadBump => {
+                let caller_turns = self.get_turns_after_discarder(player_id);
 
-        self.round.move_after_win(&mut self.phase, player_index);
+                match other_winners.first() {
+                    Some(other_winner)
+                        if self.get_turns_after_discarder(other_winner) < caller_turns =>
+                    {
+                        let other_winner = other_winner.clone();
+                        self.move_claimed_tile(player_id, &other_winner);
+                        self.settle_win(&other_winner, None);
+
+                        vec![other_winner]
+                    }
+                    _ => {
+                        self.settle_win(player_id, None);
+
+                        vec![player_id.clone()]
+                    }
+                }
+            }
+            DiscardWinners::Multiple if !other_winners.is_empty() => {
+                let tile_claimed = self.round.tile_claimed.clone().unwrap();
+
+                self.settle_win(player_id, Some(&tile_claimed.from));
+
+                for other_winner in other_winners.iter() {
+                    let original_hand = self.table.hands.get(other_winner).unwrap();
+                    let mut winning_hand = original_hand.clone();
+                    winning_hand.push(HandTile {
+                        concealed: true,
+                        id: tile_claimed.id,
+                        set_id: None,
+                    });
+                    winning_hand.set_winning_melds();
+
+                    self.table.hands.insert(other_winner, winning_hand);
+                    self.settle_win(other_winner, Some(&tile_claimed.from));
+                    self.table.hands.insert(other_winner, original_hand);
+                }
+
+                [vec![player_id.clone()], other_winners].concat()
+            }
+            DiscardWinners::FirstCaller | DiscardWinners::Multiple => {
+                self.settle_win(player_id, None);
+
+                vec![player_id.clone()]
+            }
+        };
+
+        let winners_indexes = winners
+            .iter()
+            .map(|winner| self.players.iter().position(|p| p == winner).unwrap())
+            .collect::<Vec<_>>();
+        let dealer_index = self.round.dealer_player_index;
+
+        // The dealer keeps the seat if it is one of the winners
+        let winner_index = if winners_indexes.contains(&dealer_index) {
+            dealer_index
+        } else {
+            winners_indexes[0]
+        };
+
+        // The ruleset is borrowed again, because the scores were settled
+        let round_ruleset = self.style.get_ruleset(&self.house_rules);
+        self.round
+            .move_after_win(&mut self.phase, winner_index, &*round_ruleset);
+        self.round.last_outcome = Some(RoundOutcome::Mahjong(winners));
 
         if self.phase != GamePhase::End {
             self.phase = GamePhase::InitialShuffle;
@@ -273,24 +386,162 @@ impl Game {
         Ok(())
     }
 
+    // The players (in turn order after the discarder) that could also win with the discard that
+    // the winner claimed
+    pub fn get_other_discard_winners(&self, winner_player: &PlayerId) -> Vec<PlayerId> {
+        self.find_other_discard_winners(&*self.get_ruleset(), winner_player)
+    }
+
+    // The player that discarded the winning tile, when the winner claimed it
+    fn get_winning_discarder(&self, winner_player: &PlayerId) -> Option<PlayerId> {
+        let tile_claimed = match &self.round.tile_claimed {
+            Some(tile_claimed) if tile_claimed.by.as_ref() == Some(winner_player) => tile_claimed,
+            _ => return None,
+        };
+        let winner_hand = self.table.hands.0.get(winner_player)?;
+        let is_claimed_tile_in_kong = winner_hand.list.iter().any(|t| {
+            t.id == tile_claimed.id
+                && t.set_id.is_some()
+                && winner_hand
+                    .kong_tiles
+                    .iter()
+                    .any(|k| Some(&k.set_id) == t.set_id.as_ref())
+        });
+
+        // After a kong the winning tile is the replacement one, not the discard
+        if is_claimed_tile_in_kong || !winner_hand.get_has_tile(&tile_claimed.id) {
+            return None;
+        }
+
+        Some(tile_claimed.from.clone())
+    }
+
+    fn find_other_discard_winners(
+        &self,
+        ruleset: &dyn Ruleset,
+        winner_player: &PlayerId,
+    ) -> Vec<PlayerId> {
+        if self.get_winning_discarder(winner_player).is_none() {
+            return vec![];
+        }
+
+        let tile_claimed = self.round.tile_claimed.as_ref().unwrap();
+
+        let mut other_winners = self
+            .players
+            .iter()
+            .filter(|player_id| *player_id != winner_player && *player_id != &tile_claimed.from)
+            .filter(|player_id| {
+                let hand = self.table.hands.0.get(*player_id).unwrap();
+
+                if hand.is_dead || hand.len() != ruleset.tiles_after_claim() - 1 {
+                    return false;
+                }
+
+                let (mut counts, _) = hand.get_kind_counts();
+                let claimed_kind = TileKind::from_id(tile_claimed.id);
+
+                if claimed_kind.is_bonus() {
+                    return false;
+                }
+
+                counts[claimed_kind.index()] += 1;
+
+                ruleset.is_winning_shape(&counts)
+            })
+            .cloned()
+            .collect::<Vec<PlayerId>>();
+
+        other_winners.sort_by_key(|player_id| self.get_turns_after_discarder(player_id));
+
+        other_winners
+    }
+
+    fn get_turns_after_discarder(&self, player_id: &PlayerId) -> usize {
+        let players_num = self.players.len();
+        let discarder_index = self
+            .round
+            .tile_claimed
+            .as_ref()
+            .and_then(|tile_claimed| self.players.iter().position(|p| p == &tile_claimed.from))
+            .unwrap_or(0);
+        let player_index = self.players.iter().position(|p| p == player_id).unwrap();
+
+        (player_index + players_num - discarder_index) % players_num
+    }
+
+    // Used by the head-bump rule, when a player with more priority can also win with the discard
+    fn move_claimed_tile(&mut self, from_player: &PlayerId, to_player: &PlayerId) {
+        let mut tile_claimed = self.round.tile_claimed.clone().unwrap();
+        let from_hand = self.table.hands.0.get_mut(from_player).unwrap();
+        let position = from_hand
+            .list
+            .iter()
+            .position(|t| t.id == tile_claimed.id)
+            .unwrap();
+        let removed_tile = from_hand.list.remove(position);
+
+        if removed_tile.set_id.is_some() {
+            from_hand
+                .meld_suppliers
+                .retain(|s| Some(&s.set_id) != removed_tile.set_id.as_ref());
+            from_hand
+                .list
+                .iter_mut()
+                .filter(|t| t.set_id == removed_tile.set_id)
+                .for_each(|t| {
+                    t.concealed = true;
+                    t.set_id = None;
+                });
+        }
+
+        let to_hand = self.table.hands.0.get_mut(to_player).unwrap();
+        to_hand.push(HandTile {
+            concealed: true,
+            id: tile_claimed.id,
+            set_id: None,
+        });
+        to_hand.set_winning_melds();
+
+        tile_claimed.by = Some(to_player.clone());
+        self.round.tile_claimed = Some(tile_claimed);
+        self.round.player_index = self.players.iter().position(|p| p == to_player).unwrap();
+    }
+
     pub fn pass_null_round(&mut self) -> Result<(), PassNullRoundError> {
         if self.table.draw_wall.can_draw() {
             return Err(PassNullRoundError::WallNotEmpty);
         }
 
+        let ruleset = self.get_ruleset();
+
         if self.round.tile_claimed.is_some() {
             for hand in self.table.hands.0.values() {
-                if hand.can_drop_tile() {
+                if hand.can_drop_tile(&*ruleset) {
                     return Err(PassNullRoundError::HandCanDropTile);
                 }
 
-                if hand.can_say_mahjong().is_ok() {
+                if hand.can_say_mahjong(&*ruleset).is_ok() {
                     return Err(PassNullRoundError::HandCanSayMahjong);
                 }
             }
         }
 
-        self.round.move_after_draw(&mut self.phase);
+        let ready_players = self.get_ready_players();
+        let dealer_keeps_seat = if ruleset.dealer_keeps_seat_if_ready() {
+            self.players
+                .0
+                .get(self.round.dealer_player_index)
+                .is_some_and(|dealer| ready_players.contains(dealer))
+        } else {
+            ruleset.dealer_keeps_seat_after_draw()
+        };
+
+        self.settle_exhaustive_draw(&ready_players);
+        let round_ruleset = self.style.get_ruleset(&self.house_rules);
+        self.round
+            .move_after_draw(&mut self.phase, dealer_keeps_seat, &*round_ruleset);
+        self.round.last_outcome = Some(RoundOutcome::ExhaustiveDraw(ready_players));
 
         if self.phase != GamePhase::End {
             self.phase = GamePhase::InitialShuffle;
@@ -333,7 +584,8 @@ impl Game {
     }
 
     pub fn prepare_table(&mut self, with_dead_wall: bool) {
-        self.table = DEFAULT_DECK.create_table(&self.players);
+        self.round.payments.clear();
+        self.table = DEFAULT_DECK.create_table(&self.players, &*self.get_ruleset());
         self.table.draw_wall.position_tiles(Some(PositionTilesOpts {
             shuffle: Some(true),
             dead_wall: Some(with_dead_wall),
@@ -358,6 +610,7 @@ impl Game {
                 let bonus_tiles = self.table.bonus_tiles.get_or_create(player_id);
 
                 bonus_tiles.push(tile_id);
+                self.pay_bonus_bites(player_id, tile_id);
                 continue;
             }
 
@@ -368,7 +621,7 @@ impl Game {
     }
 
     pub fn initial_draw(&mut self) -> Result<(), DrawError> {
-        let tiles_after_claim = self.style.tiles_after_claim();
+        let tiles_after_claim = self.get_ruleset().tiles_after_claim();
 
         for player_id in self.players.0.clone() {
             'loop_label: loop {
@@ -407,12 +660,11 @@ impl Game {
         let tile = &DEFAULT_DECK.0[tile_id];
 
         if tile.is_bonus() {
-            let bonus_tiles = self
-                .table
-                .bonus_tiles
-                .get_or_create(&self.get_current_player().unwrap());
+            let player_id = self.get_current_player().unwrap();
+            let bonus_tiles = self.table.bonus_tiles.get_or_create(&player_id);
 
             bonus_tiles.push(tile_id);
+            self.pay_bonus_bites(&player_id, tile_id);
 
             return DrawTileResult::Bonus(tile_id);
         }
@@ -428,10 +680,21 @@ impl Game {
     }
 
     pub fn discard_tile_to_board(&mut self, tile_id: &TileId) -> Result<(), DiscardTileError> {
+        self.apply_discard(tile_id)?;
+
+        self.check_abortive_draws();
+
+        Ok(())
+    }
+
+    // Discards the tile without the effects that end the round, so it can be reverted with
+    // `rollback_discard`. It is useful to explore the discards without cloning the game.
+    pub fn apply_discard(&mut self, tile_id: &TileId) -> Result<DiscardUndo, DiscardTileError> {
+        let tiles_after_claim = self.get_ruleset().tiles_after_claim();
         let player_with_max_tiles = self
             .players
             .iter()
-            .find(|p| self.table.hands.get(p).unwrap().len() == self.style.tiles_after_claim());
+            .find(|p| self.table.hands.0.get(*p).unwrap().len() == tiles_after_claim);
 
         if player_with_max_tiles.is_none() {
             return Err(DiscardTileError::NoPlayerCanDiscard);
@@ -484,14 +747,33 @@ impl Game {
         player_hand.list.remove(tile_index);
 
         self.table.board.0.push(tile.id);
+        self.round.discards.push(RoundDiscard {
+            claimed_by: None,
+            player_id: player_id.clone(),
+            tile: tile.id,
+        });
 
-        self.round.tile_claimed = Some(RoundTileClaimed {
+        let previous_tile_claimed = self.round.tile_claimed.replace(RoundTileClaimed {
             from: player_id.clone(),
             id: tile.id,
             by: None,
         });
 
-        Ok(())
+        Ok(DiscardUndo {
+            player_id,
+            previous_tile_claimed,
+            tile,
+            tile_index,
+        })
+    }
+
+    pub fn rollback_discard(&mut self, undo: DiscardUndo) {
+        let player_hand = self.table.hands.0.get_mut(&undo.player_id).unwrap();
+
+        player_hand.list.insert(undo.tile_index, undo.tile);
+        self.table.board.0.pop();
+        self.round.discards.pop();
+        self.round.tile_claimed = undo.previous_tile_claimed;
     }
 
     pub fn create_meld(
@@ -503,6 +785,10 @@ impl Game {
     ) -> Result<(), CreateMeldError> {
         let tiles_set = tiles.iter().cloned().collect::<FxHashSet<TileId>>();
         let hand = self.table.hands.get(player_id);
+
+        if hand.as_ref().is_some_and(|hand| hand.is_dead) {
+            return Err(CreateMeldError::DeadHand);
+        }
         let sub_hand_tiles = hand
             .unwrap()
             .list
@@ -533,10 +819,11 @@ impl Game {
             sub_hand: &tiles_full,
         };
 
+        let ruleset = self.get_ruleset();
         let mut is_kong = false;
 
-        if get_is_pung(&opts) || get_is_chow(&opts) || {
-            is_kong = get_is_kong(&opts);
+        if ruleset.is_pung(&opts) || ruleset.is_chow(&opts) || {
+            is_kong = ruleset.is_kong(&opts);
             is_kong
         } {
             if (is_upgrade && !is_kong) || (is_concealed && opts_claimed_tile.is_some()) {
@@ -544,6 +831,10 @@ impl Game {
             }
 
             let set_id = Uuid::new_v4().to_string();
+            let supplier = opts_claimed_tile
+                .filter(|claimed_tile| tiles.contains(claimed_tile))
+                .and(self.round.tile_claimed.as_ref())
+                .map(|tile_claimed| tile_claimed.from.clone());
             let player_hand = self.table.hands.0.get_mut(player_id).unwrap();
 
             for tile in tiles.iter() {
@@ -554,6 +845,27 @@ impl Game {
                 }
             }
 
+            if is_upgrade {
+                let previous_set_id = player_hand
+                    .list
+                    .iter()
+                    .find(|t| tiles.contains(&t.id) && t.set_id.is_some())
+                    .and_then(|t| t.set_id.clone());
+
+                for meld_supplier in player_hand.meld_suppliers.iter_mut() {
+                    if Some(&meld_supplier.set_id) == previous_set_id.as_ref() {
+                        meld_supplier.set_id = set_id.clone();
+                    }
+                }
+            }
+
+            if let Some(supplier) = supplier {
+                player_hand.meld_suppliers.push(MeldSupplier {
+                    player_id: supplier,
+                    set_id: set_id.clone(),
+                });
+            }
+
             player_hand
                 .list
                 .iter_mut()
@@ -570,26 +882,27 @@ impl Game {
                     .find(|t| t.set_id == Some(set_id.clone()))
                     .unwrap()
                     .clone();
-
-                self.draw_tile_for_player(player_id)
-                    .map_err(|_| match self.pass_null_round() {
-                        Ok(_) => CreateMeldError::EndRound,
-                        Err(_) => CreateMeldError::NotMeld,
-                    })?;
-
-                let next_player_hand = self.table.hands.0.get_mut(player_id).unwrap();
-
-                let position = next_player_hand
+                let position = player_hand
                     .list
                     .iter()
                     .position(|t| t.id == moved_tile.id)
                     .unwrap();
-                next_player_hand.list.remove(position);
-                next_player_hand.kong_tiles.insert(KongTile {
+                player_hand.list.remove(position);
+                player_hand.kong_tiles.insert(KongTile {
                     set_id: set_id.clone(),
                     concealed: is_concealed,
                     id: moved_tile.id,
                 });
+
+                if self.check_abortive_draws().is_some() {
+                    return Err(CreateMeldError::EndRound);
+                }
+
+                self.draw_tile_for_player(player_id)
+                    .map_err(|_| match self.pass_null_round() {
+                        Ok(_) => CreateMeldError::EndRound,
+                        Err(_) => CreateMeldError::NotMeld,
+                    })?;
             }
 
             return Ok(());
@@ -625,19 +938,23 @@ impl Game {
             }
         }
 
+        hand.meld_suppliers.retain(|s| &s.set_id != set_id);
+
         self.table.hands.0.insert(player_id.clone(), hand);
 
         Ok(())
     }
 
     pub fn claim_tile(&mut self, player_id: &PlayerId) -> bool {
+        let tiles_after_claim = self.get_ruleset().tiles_after_claim();
         let player_hand = self.table.hands.0.get_mut(player_id);
         if player_hand.is_none() {
             return false;
         }
         let player_hand = player_hand.unwrap();
 
-        if player_hand.len() != self.style.tiles_after_claim() - 1
+        if player_hand.is_dead
+            || player_hand.len() != tiles_after_claim - 1
             || self.round.tile_claimed.is_none()
             || self.table.board.0.is_empty()
         {
@@ -649,6 +966,15 @@ impl Game {
         let mut tile_claimed = self.round.tile_claimed.clone().unwrap();
         tile_claimed.by = Some(player_id.clone());
 
+        if let Some(discard) = self
+            .round
+            .discards
+            .last_mut()
+            .filter(|discard| discard.tile == tile)
+        {
+            discard.claimed_by = Some(player_id.clone());
+        }
+
         self.round.tile_claimed = Some(tile_claimed);
         self.round.player_index = self.players.iter().position(|p| p == player_id).unwrap();
 
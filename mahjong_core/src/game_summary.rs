@@ -1,24 +1,34 @@
 use crate::{
     deck::DEFAULT_DECK,
-    game::{GameStyle, GameVersion, Players},
+    game::{GameNewOpts, GameStyle, GameVersion, Players},
+    hand::{HandWait, KongTile},
     meld::{PlayerDiff, PossibleMeld},
+    round::{RoundDiscard, RoundTileClaimed},
+    ruleset::{HouseRules, Ruleset, StyleRuleset},
+    score::ScoringContext,
+    shanten::{get_acceptance, get_shanten, DiscardAnalysis, ShantenAnalysis, TileAcceptance},
     table::BonusTiles,
-    Board, Game, GameId, GamePhase, Hand, HandTile, Hands, PlayerId, Score, TileId, Wind,
-    WINDS_ROUND_ORDER,
+    tile_kind::{TileKind, TileKindCounts, BONUS_TILE_KINDS_NUM, TILE_KINDS_NUM},
+    Board, DrawWall, DrawWallPlace, Game, GameId, GamePhase, Hand, HandTile, Hands, PlayerId,
+    Score, TileId, Wind, WINDS_ROUND_ORDER,
 };
+use rand::{seq::SliceRandom, Rng};
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
 use ts_rs::TS;
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export)]
 pub struct RoundSummary {
-    consecutive_same_seats: usize,
+    pub consecutive_same_seats: usize,
     pub dealer_player_index: usize,
-    east_player_index: usize,
+    pub east_player_index: usize,
     pub discarded_tile: Option<TileId>,
+    #[serde(default)]
+    pub discards: Vec<RoundDiscard>,
     pub player_index: usize,
-    wind: Wind,
+    pub wind: Wind,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -41,22 +51,60 @@ pub struct HandTileStat {
     in_board: usize,
 }
 
+// The copies of a tile kind from the perspective of the player
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub struct TileKindTracker {
+    pub in_board: usize,
+    // In the exposed melds of the other players and in the bonus tiles of all players
+    pub in_exposed: usize,
+    // Including the melds and kongs of the player
+    pub in_hand: usize,
+    // The first tile of the deck with this kind
+    pub tile: TileId,
+    pub unseen: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct OtherPlayerHands(pub FxHashMap<PlayerId, OtherPlayerHand>);
 
 impl OtherPlayerHands {
-    pub fn from_hands(hands: &Hands, player_id: &PlayerId) -> Self {
+    pub fn from_hands(hands: &Hands, player_id: &PlayerId, ruleset: &dyn Ruleset) -> Self {
         let mut other_hands = FxHashMap::default();
+        let concealed_kongs_shown = ruleset.are_concealed_kongs_shown();
 
         for (id, hand) in hands.0.iter() {
             if id != player_id {
-                let visible_tiles: Vec<HandTile> =
-                    hand.list.iter().filter(|t| !t.concealed).cloned().collect();
+                let shown_kongs = hand
+                    .kong_tiles
+                    .iter()
+                    .filter(|t| !t.concealed || concealed_kongs_shown)
+                    .cloned()
+                    .collect::<FxHashSet<KongTile>>();
+                let visible_tiles: Vec<HandTile> = hand
+                    .list
+                    .iter()
+                    .filter(|t| {
+                        !t.concealed
+                            || shown_kongs
+                                .iter()
+                                .any(|k| t.set_id.as_ref() == Some(&k.set_id))
+                    })
+                    .cloned()
+                    .collect();
+                let visible = if hand.is_revealed {
+                    hand.clone()
+                } else {
+                    let mut visible = Hand::new(visible_tiles);
+                    visible.kong_tiles = shown_kongs;
+                    visible
+                };
+
                 other_hands.insert(
                     id.clone(),
                     OtherPlayerHand {
                         tiles: hand.len(),
-                        visible: Hand::new(visible_tiles),
+                        visible,
                     },
                 );
             }
@@ -81,6 +129,8 @@ pub struct GameSummary {
     pub round: RoundSummary,
     pub score: Score,
     pub style: GameStyle,
+    #[serde(default)]
+    pub house_rules: HouseRules,
     pub version: GameVersion,
 }
 
@@ -96,13 +146,15 @@ impl GameSummary {
             dealer_player_index: game.round.dealer_player_index,
             east_player_index: game.round.east_player_index,
             discarded_tile,
+            discards: game.round.discards.clone(),
             consecutive_same_seats: game.round.consecutive_same_seats,
             player_index: game.round.player_index,
             wind: game.round.wind.clone(),
         };
 
         let draw_wall_count = game.table.draw_wall.len();
-        let other_hands = OtherPlayerHands::from_hands(&game.table.hands, player_id);
+        let other_hands =
+            OtherPlayerHands::from_hands(&game.table.hands, player_id, &*game.get_ruleset());
 
         Some(Self {
             board: game.table.board.clone(),
@@ -117,10 +169,15 @@ impl GameSummary {
             round,
             score: game.score.clone(),
             style: game.style.clone(),
+            house_rules: game.house_rules.clone(),
             version: game.version.clone(),
         })
     }
 
+    pub fn get_ruleset(&self) -> StyleRuleset<'_> {
+        self.style.get_ruleset(&self.house_rules)
+    }
+
     pub fn get_current_player(&self) -> &PlayerId {
         &self.players.0[self.round.player_index]
     }
@@ -130,7 +187,7 @@ impl GameSummary {
             return false;
         }
 
-        let tiles_after_claim = self.style.tiles_after_claim();
+        let tiles_after_claim = self.get_ruleset().tiles_after_claim();
         self.hand.clone().unwrap().len() < tiles_after_claim
             && self
                 .other_hands
@@ -143,12 +200,13 @@ impl GameSummary {
     pub fn get_can_pass_turn(&self) -> bool {
         self.phase == GamePhase::Playing
             && self.hand.is_some()
-            && self.hand.as_ref().unwrap().len() == self.style.tiles_after_claim() - 1
+            && self.hand.as_ref().unwrap().len() == self.get_ruleset().tiles_after_claim() - 1
             && self.get_current_player() == &self.player_id
     }
 
     pub fn get_can_discard_tile(&self) -> bool {
-        self.hand.is_some() && self.hand.clone().unwrap().len() == self.style.tiles_after_claim()
+        self.hand.is_some()
+            && self.hand.clone().unwrap().len() == self.get_ruleset().tiles_after_claim()
     }
 
     pub fn get_possible_melds(&self) -> Vec<PossibleMeld> {
@@ -185,10 +243,12 @@ impl GameSummary {
             });
         }
 
-        let mut raw_melds = tested_hand.get_possible_melds(player_diff, claimed_tile, true);
+        let ruleset = self.get_ruleset();
+        let mut raw_melds =
+            tested_hand.get_possible_melds(&*ruleset, player_diff, claimed_tile, true);
 
         tested_hand
-            .get_possible_melds(player_diff, claimed_tile, false)
+            .get_possible_melds(&*ruleset, player_diff, claimed_tile, false)
             .iter()
             .for_each(|m| {
                 raw_melds.push(m.clone());
@@ -227,15 +287,33 @@ impl GameSummary {
         winds
     }
 
+    pub fn get_player_wind(&self) -> Wind {
+        let player_index = self
+            .players
+            .iter()
+            .position(|player| *player == self.player_id)
+            .unwrap();
+        let wind_index = (player_index + 4 - self.round.east_player_index) % 4;
+
+        WINDS_ROUND_ORDER[wind_index].clone()
+    }
+
     pub fn get_players_visible_melds(&self) -> FxHashMap<PlayerId, Vec<VisibleMeld>> {
         let mut visible_melds_set = FxHashMap::default();
 
-        fn get_visible_melds(player_hand: &Hand) -> Vec<VisibleMeld> {
+        fn get_visible_melds(player_hand: &Hand, concealed_kongs_shown: bool) -> Vec<VisibleMeld> {
             let mut visible_melds = vec![];
             let player_melds = player_hand
                 .list
                 .iter()
-                .filter(|t| !t.concealed)
+                .filter(|t| {
+                    !t.concealed
+                        || (concealed_kongs_shown
+                            && player_hand
+                                .kong_tiles
+                                .iter()
+                                .any(|k| t.set_id.as_ref() == Some(&k.set_id)))
+                })
                 .filter_map(|t| t.set_id.clone())
                 .collect::<FxHashSet<_>>();
 
@@ -268,22 +346,27 @@ impl GameSummary {
             return visible_melds_set;
         }
 
+        let concealed_kongs_shown = self.get_ruleset().are_concealed_kongs_shown();
+
         visible_melds_set.insert(
             self.player_id.clone(),
-            get_visible_melds(&self.hand.clone().unwrap()),
+            get_visible_melds(&self.hand.clone().unwrap(), concealed_kongs_shown),
         );
 
         for (player_id, other_player_hand) in self.other_hands.0.iter() {
             let hand = &other_player_hand.visible;
 
-            visible_melds_set.insert(player_id.clone(), get_visible_melds(hand));
+            visible_melds_set.insert(
+                player_id.clone(),
+                get_visible_melds(hand, concealed_kongs_shown),
+            );
         }
 
         visible_melds_set
     }
 
     pub fn get_can_pass_round(&self) -> bool {
-        let tiles_after_claim = self.style.tiles_after_claim();
+        let tiles_after_claim = self.get_ruleset().tiles_after_claim();
 
         self.phase == GamePhase::Playing
             && self.hand.is_some()
@@ -299,7 +382,7 @@ impl GameSummary {
     pub fn get_can_draw_tile(&self) -> bool {
         self.phase == GamePhase::Playing
             && self.hand.is_some()
-            && self.hand.as_ref().unwrap().len() < self.style.tiles_after_claim()
+            && self.hand.as_ref().unwrap().len() < self.get_ruleset().tiles_after_claim()
             && self.draw_wall_count > 0
             && self.get_current_player() == &self.player_id
     }
@@ -307,7 +390,10 @@ impl GameSummary {
     pub fn get_can_say_mahjong(&self) -> bool {
         self.phase == GamePhase::Playing
             && self.hand.is_some()
-            && self.hand.as_ref().unwrap().can_say_mahjong().is_ok()
+            && self
+                .get_ruleset()
+                .can_say_mahjong(self.hand.as_ref().unwrap())
+                .is_ok()
     }
 
     pub fn get_hand_stats(&self) -> FxHashMap<TileId, HandTileStat> {
@@ -373,4 +459,355 @@ impl GameSummary {
 
         hand_stats
     }
+
+    // The tiles that would complete the hand, with the copies that the player can't see and the
+    // faan of a win with a discard of that tile
+    pub fn get_hand_waits(&self) -> Vec<HandWait> {
+        let hand = match &self.hand {
+            Some(hand) => hand,
+            None => return vec![],
+        };
+        let unseen_counts = self.get_unseen_counts();
+
+        hand.get_waiting_tiles(&*self.get_ruleset())
+            .into_iter()
+            .map(|tile_id| HandWait {
+                faan: self.get_wait_faan(hand, tile_id),
+                tile: tile_id,
+                unseen: Self::get_unseen(&unseen_counts, tile_id),
+            })
+            .collect()
+    }
+
+    pub fn get_shanten_analysis(&self) -> Option<ShantenAnalysis> {
+        self.get_discards_analysis(false)
+    }
+
+    // The same analysis, but the acceptance is only calculated for the discards with the lowest
+    // shanten, which is much faster when there are many discards
+    pub fn get_best_discards_analysis(&self) -> Option<ShantenAnalysis> {
+        self.get_discards_analysis(true)
+    }
+
+    fn get_discards_analysis(&self, only_best_acceptance: bool) -> Option<ShantenAnalysis> {
+        let hand = self.hand.as_ref()?;
+        let unseen_counts = self.get_unseen_counts();
+        let (counts, melds_num) = hand.get_kind_counts();
+        let special_shapes = self.get_ruleset().has_special_shapes();
+        let get_acceptance_list = |acceptance_counts: &TileKindCounts| {
+            get_acceptance(acceptance_counts, melds_num, special_shapes)
+                .into_iter()
+                .map(|kind| {
+                    let tile = kind.get_tile_id();
+
+                    TileAcceptance {
+                        tile,
+                        unseen: Self::get_unseen(&unseen_counts, tile),
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        if counts.iter().map(|count| *count as usize).sum::<usize>() % 3 != 2 {
+            return Some(ShantenAnalysis {
+                acceptance: get_acceptance_list(&counts),
+                discards: vec![],
+                shanten: get_shanten(&counts, melds_num, special_shapes),
+            });
+        }
+
+        let mut discards = TileKind::iter_normal()
+            .filter(|kind| counts[kind.index()] > 0)
+            .map(|kind| {
+                let mut counts_after_discard = counts;
+                counts_after_discard[kind.index()] -= 1;
+                let tile = hand
+                    .list
+                    .iter()
+                    .find(|t| t.set_id.is_none() && TileKind::from_id(t.id) == kind)
+                    .unwrap()
+                    .id;
+
+                DiscardAnalysis {
+                    acceptance: vec![],
+                    shanten: get_shanten(&counts_after_discard, melds_num, special_shapes),
+                    tile,
+                }
+            })
+            .collect::<Vec<_>>();
+        let best_shanten = discards.iter().map(|discard| discard.shanten).min();
+
+        for discard in discards.iter_mut() {
+            if only_best_acceptance && Some(discard.shanten) != best_shanten {
+                continue;
+            }
+
+            let mut counts_after_discard = counts;
+            counts_after_discard[TileKind::from_id(discard.tile).index()] -= 1;
+            discard.acceptance = get_acceptance_list(&counts_after_discard);
+        }
+
+        discards.sort_by_key(|discard| {
+            let acceptance_total = discard
+                .acceptance
+                .iter()
+                .map(|acceptance| acceptance.unseen)
+                .sum::<usize>();
+
+            (discard.shanten, Reverse(acceptance_total))
+        });
+
+        Some(ShantenAnalysis {
+            acceptance: vec![],
+            discards,
+            shanten: get_shanten(&counts, melds_num, special_shapes),
+        })
+    }
+
+    // All the tile kinds of the style, only with what the player can see
+    pub fn get_tiles_tracker(&self) -> Vec<TileKindTracker> {
+        let hand_counts = Self::count_all_kinds(self.hand.iter().flat_map(|hand| {
+            hand.list
+                .iter()
+                .map(|t| t.id)
+                .chain(hand.kong_tiles.iter().map(|t| t.id))
+        }));
+        let board_counts = Self::count_all_kinds(self.board.0.iter().cloned());
+        let exposed_counts = Self::count_all_kinds(
+            self.other_hands
+                .0
+                .values()
+                .flat_map(|other_hand| {
+                    other_hand
+                        .visible
+                        .list
+                        .iter()
+                        .map(|t| t.id)
+                        .chain(other_hand.visible.kong_tiles.iter().map(|t| t.id))
+                })
+                .chain(self.bonus_tiles.0.values().flatten().cloned()),
+        );
+        let ruleset = self.get_ruleset();
+
+        (0..TILE_KINDS_NUM + BONUS_TILE_KINDS_NUM)
+            .map(TileKind::from_index)
+            .filter(|kind| ruleset.has_tile(&DEFAULT_DECK.0[kind.get_tile_id()]))
+            .map(|kind| {
+                let index = kind.index();
+                let seen = hand_counts[index] + board_counts[index] + exposed_counts[index];
+
+                TileKindTracker {
+                    in_board: board_counts[index],
+                    in_exposed: exposed_counts[index],
+                    in_hand: hand_counts[index],
+                    tile: kind.get_tile_id(),
+                    unseen: kind.get_deck_copies().saturating_sub(seen),
+                }
+            })
+            .collect()
+    }
+
+    // Unlike `get_kind_counts`, it includes the bonus tiles
+    fn count_all_kinds(
+        ids: impl Iterator<Item = TileId>,
+    ) -> [usize; TILE_KINDS_NUM + BONUS_TILE_KINDS_NUM] {
+        let mut counts = [0; TILE_KINDS_NUM + BONUS_TILE_KINDS_NUM];
+
+        ids.for_each(|id| counts[TileKind::from_id(id).index()] += 1);
+
+        counts
+    }
+
+    pub(crate) fn get_unseen_counts(&self) -> TileKindCounts {
+        let mut unseen_counts = [0; TILE_KINDS_NUM];
+
+        for tracker in self.get_tiles_tracker() {
+            let kind = TileKind::from_id(tracker.tile);
+
+            if !kind.is_bonus() {
+                unseen_counts[kind.index()] = tracker.unseen as u8;
+            }
+        }
+
+        unseen_counts
+    }
+
+    fn get_unseen(unseen_counts: &TileKindCounts, tile_id: TileId) -> usize {
+        let kind = TileKind::from_id(tile_id);
+
+        if kind.is_bonus() {
+            return 0;
+        }
+
+        unseen_counts[kind.index()] as usize
+    }
+
+    // Scores a win with a discard of the tile, only with what the player can see
+    fn get_wait_faan(&self, hand: &Hand, tile_id: TileId) -> u32 {
+        let mut winning_hand = hand.clone();
+        winning_hand.push(HandTile::from_id(tile_id));
+        winning_hand.set_winning_melds();
+
+        let context = ScoringContext {
+            bonus_tiles: self
+                .bonus_tiles
+                .0
+                .get(&self.player_id)
+                .map(|tiles| tiles.as_slice())
+                .unwrap_or_default(),
+            hand: &winning_hand,
+            is_self_draw: false,
+            is_wall_empty: self.draw_wall_count == 0,
+            seat_wind: self.get_player_wind(),
+        };
+
+        self.get_ruleset().get_context_score(&context).1
+    }
+
+    // A full game that is consistent with what the player can see. The concealed tiles of the
+    // other players and the wall are dealt at random from the unseen tiles, so the draw order is
+    // not known. The dead wall is not part of it.
+    pub fn sample_game(&self, rng: &mut impl Rng) -> Game {
+        let mut game = Game::new(Some(GameNewOpts {
+            house_rules: Some(self.house_rules.clone()),
+            players: Some(self.players.clone()),
+            style: Some(self.style.clone()),
+        }));
+        let ruleset = self.get_ruleset();
+        let hand = self.hand.clone().unwrap_or_default();
+
+        let seen = hand
+            .list
+            .iter()
+            .map(|t| t.id)
+            .chain(hand.kong_tiles.iter().map(|t| t.id))
+            .chain(self.board.0.iter().cloned())
+            .chain(self.bonus_tiles.0.values().flatten().cloned())
+            .chain(self.other_hands.0.values().flat_map(|other_hand| {
+                other_hand
+                    .visible
+                    .list
+                    .iter()
+                    .map(|t| t.id)
+                    .chain(other_hand.visible.kong_tiles.iter().map(|t| t.id))
+            }))
+            .collect::<FxHashSet<TileId>>();
+        let mut unseen = DEFAULT_DECK
+            .0
+            .iter()
+            .filter(|tile| ruleset.has_tile(tile) && !seen.contains(&tile.get_id()))
+            .map(|tile| tile.get_id())
+            .collect::<Vec<TileId>>();
+        unseen.shuffle(rng);
+
+        // Sorted to use the same random tiles for the same players in every sample
+        let mut other_players = self
+            .other_hands
+            .0
+            .keys()
+            .cloned()
+            .collect::<Vec<PlayerId>>();
+        other_players.sort();
+
+        for player_id in other_players {
+            let other_hand = &self.other_hands.0[&player_id];
+            let mut sampled_hand = other_hand.visible.clone();
+            let concealed_num = other_hand.tiles.saturating_sub(sampled_hand.list.len());
+
+            for _ in 0..concealed_num {
+                // The bonus tiles are never kept in the hand, so they stay in the wall
+                let Some(position) = unseen
+                    .iter()
+                    .rposition(|tile| !TileKind::from_id(*tile).is_bonus())
+                else {
+                    break;
+                };
+                sampled_hand.push(HandTile::from_id(unseen.swap_remove(position)));
+            }
+
+            game.table.hands.insert(&player_id, sampled_hand);
+        }
+
+        game.table.hands.insert(&self.player_id, hand.clone());
+        game.table.board = self.board.clone();
+        game.table.bonus_tiles = self.bonus_tiles.clone();
+        // Every wind needs a segment, because a player can't draw from the wall of a missing one
+        game.table.draw_wall = DrawWall::new_full(
+            unseen
+                .into_iter()
+                .take(self.draw_wall_count)
+                .enumerate()
+                .map(|(index, tile)| {
+                    let wind = WINDS_ROUND_ORDER[index % WINDS_ROUND_ORDER.len()].clone();
+
+                    (tile, DrawWallPlace::Segment(wind))
+                })
+                .collect(),
+        );
+
+        game.phase = self.phase;
+        game.score = self.score.clone();
+        game.round.consecutive_same_seats = self.round.consecutive_same_seats;
+        game.round.dealer_player_index = self.round.dealer_player_index;
+        game.round.east_player_index = self.round.east_player_index;
+        game.round.player_index = self.round.player_index;
+        game.round.wind = self.round.wind.clone();
+        game.round.discards = self.round.discards.clone();
+
+        let current_player = self.get_current_player().clone();
+        let players_num = self.players.len();
+
+        game.round.tile_claimed = self.round.discarded_tile.map(|tile_id| {
+            // The tile is in the hand of the player after claiming it, so only this player knows
+            // who claimed it
+            if hand.get_has_tile(&tile_id) {
+                let player_index = self
+                    .players
+                    .iter()
+                    .position(|p| p == &self.player_id)
+                    .unwrap_or_default();
+                // The discards are missing in the summaries from before they were recorded
+                let discarder = self
+                    .round
+                    .discards
+                    .iter()
+                    .rev()
+                    .find(|discard| discard.tile == tile_id)
+                    .map(|discard| discard.player_id.clone())
+                    .unwrap_or_else(|| {
+                        self.players.0[(player_index + players_num - 1) % players_num].clone()
+                    });
+
+                RoundTileClaimed {
+                    by: Some(self.player_id.clone()),
+                    from: discarder,
+                    id: tile_id,
+                }
+            } else {
+                RoundTileClaimed {
+                    by: None,
+                    from: current_player.clone(),
+                    id: tile_id,
+                }
+            }
+        });
+
+        // The value of the drawn tile is only used to know if the player already drew
+        let current_hand_len = game
+            .table
+            .hands
+            .get(&current_player)
+            .map(|current_hand| current_hand.len())
+            .unwrap_or_default();
+        if self.round.discarded_tile.is_some() || current_hand_len == ruleset.tiles_after_claim() {
+            game.round.wall_tile_drawn = game
+                .table
+                .hands
+                .get(&current_player)
+                .and_then(|current_hand| current_hand.list.first().map(|tile| tile.id))
+                .or(self.round.discarded_tile);
+        }
+
+        game
+    }
 }
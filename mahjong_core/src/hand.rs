@@ -2,15 +2,18 @@ use crate::{
     deck::DEFAULT_DECK,
     game::GameStyle,
     meld::{
-        get_is_chow, get_is_kong, get_is_pair, get_is_pung, MeldType, PlayerDiff, PossibleMeld,
-        SetCheckOpts,
+        get_is_pung, get_winning_shape_groups, MeldType, PlayerDiff, PossibleMeld, SetCheckOpts,
     },
+    ruleset::Ruleset,
+    shanten::get_shanten,
+    tile_kind::{get_kind_counts, TileKind, TileKindCounts, TILE_KINDS_NUM},
     PlayerId, Tile, TileId,
 };
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 use ts_rs::TS;
+use uuid::Uuid;
 
 pub type SetIdContent = String;
 pub type SetId = Option<SetIdContent>;
@@ -67,14 +70,41 @@ impl HandTile {
     }
 }
 
+// The player that discarded the tile claimed for an exposed meld
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub struct MeldSupplier {
+    pub player_id: PlayerId,
+    pub set_id: SetIdContent,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize, TS)]
 pub struct Hand {
     pub list: Vec<HandTile>,
     pub kong_tiles: FxHashSet<KongTile>,
+    // In the order that the melds were claimed
+    #[serde(default)]
+    pub meld_suppliers: Vec<MeldSupplier>,
+    // A dead hand can only draw and discard until the end of the round
+    #[serde(default)]
+    pub is_dead: bool,
+    // The tiles are visible to the other players, e.g. after a false mahjong
+    #[serde(default)]
+    pub is_revealed: bool,
     #[serde(skip)]
     pub style: Option<GameStyle>,
 }
 
+// A tile kind that would complete a ready hand
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub struct HandWait {
+    pub faan: u32,
+    pub tile: TileId,
+    // The copies that the player can't see in their hand, the visible melds or the board
+    pub unseen: usize,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, TS)]
 #[ts(export)]
 pub struct HandMeld {
@@ -116,6 +146,7 @@ pub enum SortHandError {
 #[derive(Debug, EnumIter, Eq, PartialEq, Clone)]
 pub enum CanSayMahjongError {
     CantDrop,
+    DeadHand,
     NotPair,
 }
 
@@ -125,12 +156,18 @@ impl Hand {
             list,
             style: None,
             kong_tiles: FxHashSet::default(),
+            meld_suppliers: Vec::new(),
+            is_dead: false,
+            is_revealed: false,
         }
     }
 
     pub fn from_ref_vec(tiles: &[&HandTile]) -> Self {
         Self {
             kong_tiles: FxHashSet::default(),
+            meld_suppliers: Vec::new(),
+            is_dead: false,
+            is_revealed: false,
             list: tiles.iter().cloned().cloned().collect(),
             style: None,
         }
@@ -140,6 +177,9 @@ impl Hand {
         Self {
             list: tiles.iter().cloned().map(HandTile::from_id).collect(),
             kong_tiles: FxHashSet::default(),
+            meld_suppliers: Vec::new(),
+            is_dead: false,
+            is_revealed: false,
             style: None,
         }
     }
@@ -222,31 +262,146 @@ impl Hand {
         melds
     }
 
-    pub fn can_say_mahjong(&self) -> Result<(), CanSayMahjongError> {
-        if !self.can_drop_tile() {
-            return Err(CanSayMahjongError::CantDrop);
+    pub fn can_say_mahjong(&self, ruleset: &dyn Ruleset) -> Result<(), CanSayMahjongError> {
+        ruleset.can_say_mahjong(self)
+    }
+
+    // The hand is one tile away from a winning shape (tenpai). When the hand has the extra tile,
+    // it is ready if some discard leaves it one tile away.
+    pub fn is_ready(&self, ruleset: &dyn Ruleset) -> bool {
+        if self.is_dead {
+            return false;
+        }
+
+        let (counts, _) = self.get_kind_counts();
+
+        if counts.iter().map(|count| *count as usize).sum::<usize>() % 3 == 2 {
+            return TileKind::iter_normal()
+                .filter(|kind| counts[kind.index()] > 0)
+                .any(|kind| {
+                    let mut counts_after_discard = counts;
+                    counts_after_discard[kind.index()] -= 1;
+                    !self
+                        .get_waiting_kinds(&counts_after_discard, ruleset)
+                        .is_empty()
+                });
+        }
+
+        !self.get_waiting_kinds(&counts, ruleset).is_empty()
+    }
+
+    pub fn get_shanten(&self, ruleset: &dyn Ruleset) -> i8 {
+        let (counts, melds_num) = self.get_kind_counts();
+
+        get_shanten(&counts, melds_num, ruleset.has_special_shapes())
+    }
+
+    // The counts of the tiles without meld, and the number of melds
+    pub fn get_kind_counts(&self) -> (TileKindCounts, usize) {
+        let counts = get_kind_counts(
+            self.list
+                .iter()
+                .filter(|t| t.set_id.is_none())
+                .map(|t| t.id),
+        );
+        let melds_num = self
+            .list
+            .iter()
+            .filter_map(|t| t.set_id.clone())
+            .collect::<FxHashSet<SetIdContent>>()
+            .len();
+
+        (counts, melds_num)
+    }
+
+    // The tile kinds that would complete the hand, each one represented by the first tile of the
+    // deck with that content. It is empty if the hand is not ready or if it has the extra tile.
+    pub fn get_waiting_tiles(&self, ruleset: &dyn Ruleset) -> Vec<TileId> {
+        let (counts, _) = self.get_kind_counts();
+        let mut tiles = self
+            .get_waiting_kinds(&counts, ruleset)
+            .iter()
+            .map(|kind| kind.get_tile_id())
+            .collect::<Vec<_>>();
+
+        tiles.sort();
+
+        tiles
+    }
+
+    fn get_waiting_kinds(&self, counts: &TileKindCounts, ruleset: &dyn Ruleset) -> Vec<TileKind> {
+        if counts.iter().map(|count| *count as usize).sum::<usize>() % 3 != 1 {
+            return vec![];
         }
 
-        let tiles_without_meld: Vec<&Tile> = self
+        // A tile can't complete the hand when the player already has all the copies
+        let held_counts = get_kind_counts(
+            self.list
+                .iter()
+                .map(|t| t.id)
+                .chain(self.kong_tiles.iter().map(|t| t.id)),
+        );
+        let mut counts_with_candidate = *counts;
+
+        TileKind::iter_normal()
+            .filter(|candidate| {
+                let index = candidate.index();
+
+                if held_counts[index] >= 4 {
+                    return false;
+                }
+
+                counts_with_candidate[index] += 1;
+                let is_winning_shape = ruleset.is_winning_shape(&counts_with_candidate);
+                counts_with_candidate[index] -= 1;
+
+                is_winning_shape
+            })
+            .collect()
+    }
+
+    // Groups the tiles without meld into melds, leaving only the pair, so the hand can be scored
+    pub fn set_winning_melds(&mut self) -> bool {
+        let tiles_without_meld = self
             .list
             .iter()
             .filter(|t| t.set_id.is_none())
-            .map(|t| &DEFAULT_DECK.0[t.id])
-            .collect();
+            .map(|t| t.id)
+            .collect::<Vec<TileId>>();
+
+        let groups = match get_winning_shape_groups(&tiles_without_meld) {
+            Some(groups) => groups,
+            None => return false,
+        };
 
-        let is_pair = get_is_pair(&tiles_without_meld);
+        let mut pair_set_id = None;
 
-        if !is_pair {
-            return Err(CanSayMahjongError::NotPair);
+        for group in groups {
+            let set_id = Uuid::new_v4().to_string();
+            pair_set_id.get_or_insert(set_id.clone());
+
+            for tile_id in group {
+                let tile = self
+                    .list
+                    .iter_mut()
+                    .find(|t| t.set_id.is_none() && t.id == tile_id)
+                    .unwrap();
+                tile.set_id = Some(set_id.clone());
+            }
         }
 
-        Ok(())
+        self.list
+            .iter_mut()
+            .filter(|t| t.set_id == pair_set_id)
+            .for_each(|t| t.set_id = None);
+
+        true
     }
 
-    fn get_pungs_tiles(&self) -> Vec<(Tile, SetId)> {
+    fn get_pungs_kinds(&self) -> Vec<(TileKind, SetId)> {
         let sets_ids: FxHashSet<SetIdContent> =
             self.list.iter().filter_map(|t| t.set_id.clone()).collect();
-        let mut pungs: Vec<(Tile, SetId)> = vec![];
+        let mut pungs: Vec<(TileKind, SetId)> = vec![];
         let existing_kongs = self
             .kong_tiles
             .iter()
@@ -268,7 +423,7 @@ impl Hand {
             });
 
             if is_pung && !existing_kongs.contains(&set_id) {
-                pungs.push((tiles[0].clone(), Some(set_id)));
+                pungs.push((tiles[0].get_kind(), Some(set_id)));
             }
         }
 
@@ -277,17 +432,15 @@ impl Hand {
 
     pub fn get_possible_melds(
         &self,
+        ruleset: &dyn Ruleset,
         board_tile_player_diff: PlayerDiff,
         claimed_tile: Option<TileId>,
         check_for_mahjong: bool,
     ) -> Vec<HandPossibleMeld> {
-        let hand_filtered: Vec<&HandTile> =
-            self.list.iter().filter(|h| h.set_id.is_none()).collect();
         let mut melds: Vec<HandPossibleMeld> = vec![];
-        let existing_pungs = self.get_pungs_tiles();
 
         if check_for_mahjong {
-            if self.can_say_mahjong().is_ok() {
+            if ruleset.can_say_mahjong(self).is_ok() {
                 let tiles = self
                     .list
                     .iter()
@@ -307,82 +460,108 @@ impl Hand {
             return melds;
         }
 
-        for first_tile_index in 0..hand_filtered.len() {
-            let first_tile = hand_filtered[first_tile_index].id;
-            let first_tile_full = &DEFAULT_DECK.0[first_tile];
-
-            for second_tile_index in (first_tile_index + 1)..hand_filtered.len() {
-                let second_tile = hand_filtered[second_tile_index].id;
-                let second_tile_full = &DEFAULT_DECK.0[second_tile];
-
-                if !first_tile_full.is_same_type(second_tile_full) {
-                    continue;
-                }
-
-                for third_tile_index in (second_tile_index + 1)..hand_filtered.len() {
-                    let third_tile = hand_filtered[third_tile_index].id;
-                    let third_tile_full = &DEFAULT_DECK.0[third_tile];
-                    if !first_tile_full.is_same_type(third_tile_full) {
-                        continue;
-                    }
+        let free_tiles: Vec<TileId> = self
+            .list
+            .iter()
+            .filter(|h| h.set_id.is_none())
+            .map(|h| h.id)
+            .collect();
+        // The positions in the free tiles of each kind, so the candidates only combine the kinds
+        // that can form a meld instead of every group of tiles
+        let mut counts: TileKindCounts = [0; TILE_KINDS_NUM];
+        let mut kinds_slots = [[0; 4]; TILE_KINDS_NUM];
+        for (position, tile) in free_tiles.iter().enumerate() {
+            let kind = TileKind::from_id(*tile);
+
+            if !kind.is_bonus() {
+                kinds_slots[kind.index()][counts[kind.index()] as usize] = position;
+                counts[kind.index()] += 1;
+            }
+        }
 
-                    let sub_hand = [first_tile_full, second_tile_full, third_tile_full];
+        let is_concealed = claimed_tile.is_none();
+        // Sorted by the positions in the hand, with the upgrades after the melds of the same tile
+        type MeldPosition = (usize, bool, [usize; 4]);
+        let mut positioned_melds: Vec<(MeldPosition, HandPossibleMeld)> = vec![];
+        let mut check_candidate = |positions: &[usize]| {
+            let mut sub_hand = [&DEFAULT_DECK.0[0]; 4];
+            let mut sorted_positions = [0; 4];
+            for (index, position) in positions.iter().enumerate() {
+                sub_hand[index] = &DEFAULT_DECK.0[free_tiles[*position]];
+                sorted_positions[index] = *position;
+            }
+            sorted_positions[..positions.len()].sort_unstable();
+
+            let opts = SetCheckOpts {
+                board_tile_player_diff,
+                claimed_tile,
+                sub_hand: &sub_hand[..positions.len()],
+            };
+            let is_meld = if positions.len() == 4 {
+                ruleset.is_kong(&opts)
+            } else {
+                ruleset.is_pung(&opts) || ruleset.is_chow(&opts)
+            };
+
+            if is_meld {
+                let meld = HandPossibleMeld {
+                    is_concealed,
+                    is_mahjong: false,
+                    is_upgrade: false,
+                    tiles: sorted_positions[..positions.len()]
+                        .iter()
+                        .map(|position| free_tiles[*position])
+                        .collect(),
+                };
+                positioned_melds.push(((sorted_positions[0], false, sorted_positions), meld));
+            }
+        };
 
-                    let opts = SetCheckOpts {
-                        board_tile_player_diff,
-                        claimed_tile,
-                        sub_hand: &sub_hand,
-                    };
+        for kind in TileKind::iter_normal() {
+            let slot = &kinds_slots[kind.index()][..counts[kind.index()] as usize];
 
-                    if get_is_pung(&opts) || get_is_chow(&opts) {
-                        let is_concealed = claimed_tile.is_none();
+            for (first_index, first) in slot.iter().enumerate() {
+                for (second_index, second) in slot.iter().enumerate().skip(first_index + 1) {
+                    for (third_index, third) in slot.iter().enumerate().skip(second_index + 1) {
+                        check_candidate(&[*first, *second, *third]);
 
-                        let meld = HandPossibleMeld {
-                            is_concealed,
-                            is_mahjong: false,
-                            is_upgrade: false,
-                            tiles: vec![first_tile, second_tile, third_tile],
-                        };
-                        melds.push(meld);
+                        for forth in slot.iter().skip(third_index + 1) {
+                            check_candidate(&[*first, *second, *third, *forth]);
+                        }
                     }
+                }
+            }
 
-                    for forth_tile in hand_filtered.iter().skip(third_tile_index + 1) {
-                        let forth_tile_full = &DEFAULT_DECK.0[forth_tile.id];
-
-                        let mut opts = opts.clone();
-                        let sub_hand_inner = [
-                            first_tile_full,
-                            second_tile_full,
-                            third_tile_full,
-                            forth_tile_full,
-                        ];
-                        opts.sub_hand = &sub_hand_inner;
-
-                        if get_is_kong(&opts) {
-                            let is_concealed = claimed_tile.is_none();
-
-                            let meld = HandPossibleMeld {
-                                is_concealed,
-                                is_mahjong: false,
-                                is_upgrade: false,
-                                tiles: vec![first_tile, second_tile, third_tile, forth_tile.id],
-                            };
-                            melds.push(meld);
-                        }
+            let next_kind = TileKind::from_index(kind.index() + 1);
+            let last_kind = TileKind::from_index(kind.index() + 2);
+            if !kind.is_same_suit(&next_kind) || !kind.is_same_suit(&last_kind) {
+                continue;
+            }
+
+            let next_slot = &kinds_slots[next_kind.index()][..counts[next_kind.index()] as usize];
+            let last_slot = &kinds_slots[last_kind.index()][..counts[last_kind.index()] as usize];
+            for first in slot {
+                for second in next_slot {
+                    for third in last_slot {
+                        check_candidate(&[*first, *second, *third]);
                     }
                 }
             }
+        }
+
+        let existing_pungs = self.get_pungs_kinds();
+        for (position, tile) in free_tiles.iter().enumerate() {
+            let kind = TileKind::from_id(*tile);
 
-            for (concealed_pung_tile, set_id) in existing_pungs.iter() {
-                if first_tile_full.is_same_content(concealed_pung_tile) {
-                    let is_concealed = claimed_tile.is_none();
+            for (pung_kind, set_id) in existing_pungs.iter() {
+                if kind == *pung_kind {
                     let mut tiles: Vec<TileId> = self
                         .list
                         .iter()
                         .filter(|t| t.set_id == *set_id)
                         .map(|t| t.id)
                         .collect();
-                    tiles.push(first_tile);
+                    tiles.push(*tile);
 
                     let meld = HandPossibleMeld {
                         is_mahjong: false,
@@ -390,11 +569,14 @@ impl Hand {
                         is_concealed,
                         tiles,
                     };
-                    melds.push(meld);
+                    positioned_melds.push(((position, true, [0; 4]), meld));
                 }
             }
         }
 
+        positioned_melds.sort_by_key(|(position, _)| *position);
+        melds.extend(positioned_melds.into_iter().map(|(_, meld)| meld));
+
         melds
     }
 
@@ -420,13 +602,8 @@ impl Hand {
         sets
     }
 
-    pub fn can_drop_tile(&self) -> bool {
-        self.list.len()
-            == self
-                .style
-                .as_ref()
-                .unwrap_or(&GameStyle::HongKong)
-                .tiles_after_claim()
+    pub fn can_drop_tile(&self, ruleset: &dyn Ruleset) -> bool {
+        self.list.len() == ruleset.tiles_after_claim()
     }
 }
 
@@ -9,9 +9,11 @@ use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 pub use table::{Board, BonusTiles, DrawWall, DrawWallPlace, Table};
 pub use tile::{Tile, TileId};
+pub use tile_kind::TileKind;
 use ts_rs::TS;
 
 pub mod ai;
+pub mod danger;
 pub mod deck;
 pub mod game;
 pub mod game_summary;
@@ -19,15 +21,19 @@ pub mod hand;
 mod log;
 mod macros;
 pub mod meld;
+pub mod notation;
+pub mod render;
 pub mod round;
+pub mod ruleset;
 pub mod score;
+pub mod shanten;
 #[cfg(feature = "summary")]
 mod summary_view;
 mod table;
 #[cfg(test)]
 mod tests;
 pub mod tile;
-pub mod ui;
+pub mod tile_kind;
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, TS)]
 #[ts(export)]
@@ -68,6 +74,12 @@ pub const SEASONS_ORDER: &[Season] = &[
     Season::Autumn,
     Season::Winter,
 ];
+pub const ANIMALS_ORDER: &[Animal] = &[
+    Animal::Cat,
+    Animal::Mouse,
+    Animal::Rooster,
+    Animal::Centipede,
+];
 
 #[derive(Clone, Debug, Serialize, Deserialize, TS)]
 #[ts(export)]
@@ -123,6 +135,23 @@ pub struct SeasonTile {
     pub value: Season,
 }
 
+// The animal tiles are only part of the deck in some styles (e.g. Singapore)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, TS)]
+#[ts(export)]
+pub enum Animal {
+    Cat,
+    Centipede,
+    Mouse,
+    Rooster,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AnimalTile {
+    pub id: TileId,
+    pub value: Animal,
+}
+
 impl FromStr for Wind {
     type Err = ();
 
@@ -1,5 +1,8 @@
 use crate::{
-    deck::DEFAULT_DECK, round::TileClaimed, HandTile, PlayerId, SetId, Suit, SuitTile, Tile, TileId,
+    deck::DEFAULT_DECK,
+    round::TileClaimed,
+    tile_kind::{get_kind_counts, TileKind, TileKindCounts},
+    HandTile, PlayerId, SetId, Tile, TileId,
 };
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
@@ -82,13 +85,6 @@ pub fn get_is_pung(opts: &SetCheckOpts) -> bool {
     true
 }
 
-// This approach is used for performance
-const DUMMY_SUIT: SuitTile = SuitTile {
-    id: 0,
-    value: 0,
-    suit: crate::Suit::Dots,
-};
-
 pub fn get_is_chow(opts: &SetCheckOpts) -> bool {
     if opts.sub_hand.len() != 3 {
         return false;
@@ -107,40 +103,16 @@ pub fn get_is_chow(opts: &SetCheckOpts) -> bool {
         }
     }
 
-    let mut suit_tiles: [&SuitTile; 3] = [&DUMMY_SUIT, &DUMMY_SUIT, &DUMMY_SUIT];
-    let mut suit: Option<Suit> = None;
-
-    for (idx, tile) in opts.sub_hand.iter().enumerate() {
-        match tile {
-            Tile::Suit(suit_tile) => {
-                if suit.is_some() {
-                    if Some(suit_tile.suit) != suit {
-                        return false;
-                    }
-                } else {
-                    suit = Some(suit_tile.suit);
-                }
-                suit_tiles[idx] = suit_tile
-            }
-            _ => {
-                return false;
-            }
-        }
-    }
-
-    suit_tiles.sort_by(|a, b| a.value.cmp(&b.value));
-
-    let mut last_tile = suit_tiles[0];
-
-    for tile in suit_tiles.iter().skip(1).take(2) {
-        if last_tile.value + 1 != tile.value {
-            return false;
-        }
-
-        last_tile = tile;
-    }
+    let mut kinds = [
+        opts.sub_hand[0].get_kind(),
+        opts.sub_hand[1].get_kind(),
+        opts.sub_hand[2].get_kind(),
+    ];
+    kinds.sort();
 
-    true
+    kinds[0].get_suit_value().unwrap_or(9) <= 7
+        && kinds[1].index() == kinds[0].index() + 1
+        && kinds[2].index() == kinds[0].index() + 2
 }
 
 pub fn get_is_kong(opts: &SetCheckOpts) -> bool {
@@ -217,6 +189,106 @@ pub fn get_is_pair(hand: &[&Tile]) -> bool {
     hand[0].is_same_content(hand[1])
 }
 
+// Checks if all the tiles can be split into pungs or chows and exactly one pair
+pub fn get_is_winning_shape(counts: &TileKindCounts) -> bool {
+    get_winning_shape_kinds(counts).is_some()
+}
+
+// Splits the tiles into pungs or chows and exactly one pair, which is the first group
+pub fn get_winning_shape_groups(tiles: &[TileId]) -> Option<Vec<Vec<TileId>>> {
+    if tiles.iter().any(|id| TileKind::from_id(*id).is_bonus()) {
+        return None;
+    }
+
+    let kinds_groups = get_winning_shape_kinds(&get_kind_counts(tiles.iter().cloned()))?;
+    let mut remaining_tiles = tiles.to_vec();
+
+    let groups = kinds_groups
+        .iter()
+        .map(|group| {
+            group
+                .iter()
+                .map(|kind| {
+                    let index = remaining_tiles
+                        .iter()
+                        .position(|id| TileKind::from_id(*id) == *kind)
+                        .unwrap();
+
+                    remaining_tiles.swap_remove(index)
+                })
+                .collect()
+        })
+        .collect();
+
+    Some(groups)
+}
+
+pub fn get_winning_shape_kinds(counts: &TileKindCounts) -> Option<Vec<Vec<TileKind>>> {
+    let tiles_num = counts.iter().map(|count| *count as usize).sum::<usize>();
+
+    if tiles_num % 3 != 2 {
+        return None;
+    }
+
+    TileKind::iter_normal()
+        .filter(|kind| counts[kind.index()] >= 2)
+        .find_map(|pair_kind| {
+            let mut counts_without_pair = *counts;
+            counts_without_pair[pair_kind.index()] -= 2;
+
+            get_melds_kinds(counts_without_pair).map(|melds| {
+                let mut groups = vec![vec![pair_kind; 2]];
+                groups.extend(melds);
+                groups
+            })
+        })
+}
+
+// Starting with the lowest kind, a pung is always as good as three chows, so the tiles can be
+// grouped without backtracking
+fn get_melds_kinds(mut counts: TileKindCounts) -> Option<Vec<Vec<TileKind>>> {
+    let mut groups = vec![];
+
+    for kind in TileKind::iter_normal() {
+        let index = kind.index();
+
+        if counts[index] >= 3 {
+            counts[index] -= 3;
+            groups.push(vec![kind; 3]);
+        }
+
+        let chows_num = counts[index];
+
+        if chows_num == 0 {
+            continue;
+        }
+
+        if kind.get_suit_value().unwrap_or(9) > 7 {
+            return None;
+        }
+
+        for next_index in [index + 1, index + 2] {
+            if counts[next_index] < chows_num {
+                return None;
+            }
+
+            counts[next_index] -= chows_num;
+        }
+
+        counts[index] = 0;
+
+        for _ in 0..chows_num {
+            groups.push(vec![
+                kind,
+                TileKind::from_index(index + 1),
+                TileKind::from_index(index + 2),
+            ]);
+        }
+    }
+
+    Some(groups)
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, TS)]
 #[ts(export)]
 pub struct PossibleMeld {
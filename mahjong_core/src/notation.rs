@@ -0,0 +1,276 @@
+// Compact text notation for tiles and hands, similar to the one used in riichi tools, e.g.
+// `123m456p789s11z`. The number goes before the suffix of its group:
+// - `m`: characters, `p`: dots, `s`: bamboo (from 1 to 9)
+// - `z`: east, south, west, north, white, green and red (from 1 to 7)
+// - `f`: flowers (from 1 to 4) and seasons (from 5 to 8), `a`: animals (from 1 to 4)
+// In a hand, the melds are wrapped with `[...]` when exposed and with `(...)` when concealed, and
+// the melds with four tiles are kongs, e.g. `123m456p11z 1f [789s] (1111z)`.
+
+use crate::{
+    deck::DEFAULT_DECK,
+    hand::KongTile,
+    tile_kind::{TileKind, TILE_KINDS_NUM},
+    Hand, HandTile, TileId,
+};
+use rustc_hash::FxHashSet;
+use strum_macros::EnumIter;
+use uuid::Uuid;
+
+#[derive(Debug, PartialEq, Eq, Clone, EnumIter)]
+pub enum NotationError {
+    InvalidMeld,
+    InvalidTile,
+    MissingSuffix,
+    TooManyCopies,
+    UnclosedMeld,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandNotation {
+    pub hand: Hand,
+    pub bonus_tiles: Vec<TileId>,
+}
+
+const HONOURS_KINDS: [usize; 7] = [27, 28, 29, 30, 33, 31, 32];
+
+fn kind_from_notation(value: u32, suffix: char) -> Option<TileKind> {
+    let index = match (suffix, value) {
+        ('s', 1..=9) => value as usize - 1,
+        ('m', 1..=9) => 9 + value as usize - 1,
+        ('p', 1..=9) => 18 + value as usize - 1,
+        ('z', 1..=7) => HONOURS_KINDS[value as usize - 1],
+        ('f', 1..=8) => TILE_KINDS_NUM + value as usize - 1,
+        ('a', 1..=4) => TILE_KINDS_NUM + 8 + value as usize - 1,
+        _ => return None,
+    };
+
+    Some(TileKind::from_index(index))
+}
+
+fn kind_to_notation(kind: &TileKind) -> (usize, char) {
+    let index = kind.index();
+
+    match index {
+        0..=8 => (index + 1, 's'),
+        9..=17 => (index - 8, 'm'),
+        18..=26 => (index - 17, 'p'),
+        _ if kind.is_bonus() && index < TILE_KINDS_NUM + 8 => (index - TILE_KINDS_NUM + 1, 'f'),
+        _ if kind.is_bonus() => (index - TILE_KINDS_NUM - 7, 'a'),
+        _ => (
+            HONOURS_KINDS.iter().position(|k| *k == index).unwrap() + 1,
+            'z',
+        ),
+    }
+}
+
+// The melds are printed in the usual order of the notation instead of the order of the kinds
+fn get_notation_order(tile: TileId) -> (usize, usize) {
+    let (value, suffix) = kind_to_notation(&TileKind::from_id(tile));
+
+    ("mpszfa".find(suffix).unwrap(), value)
+}
+
+// Looks like the notation instead of the summary format, which uses the Chinese characters
+pub fn is_notation(text: &str) -> bool {
+    text.is_ascii() && text.chars().any(|c| c.is_ascii_digit())
+}
+
+// Picks a different copy of the deck for each repeated tile
+fn take_tile_id(kind: TileKind, used: &mut FxHashSet<TileId>) -> Result<TileId, NotationError> {
+    let id = (0..DEFAULT_DECK.0.len())
+        .find(|id| TileKind::from_id(*id) == kind && !used.contains(id))
+        .ok_or(NotationError::TooManyCopies)?;
+    used.insert(id);
+
+    Ok(id)
+}
+
+fn parse_kinds(notation: &str) -> Result<Vec<TileKind>, NotationError> {
+    let mut kinds = Vec::new();
+    let mut values: Vec<u32> = Vec::new();
+
+    for c in notation.chars().filter(|c| !c.is_whitespace()) {
+        if let Some(value) = c.to_digit(10) {
+            values.push(value);
+            continue;
+        }
+
+        if values.is_empty() {
+            return Err(NotationError::InvalidTile);
+        }
+
+        for value in values.drain(..) {
+            kinds.push(kind_from_notation(value, c).ok_or(NotationError::InvalidTile)?);
+        }
+    }
+
+    if !values.is_empty() {
+        return Err(NotationError::MissingSuffix);
+    }
+
+    Ok(kinds)
+}
+
+pub fn parse_tiles(notation: &str) -> Result<Vec<TileId>, NotationError> {
+    let mut used = FxHashSet::default();
+
+    parse_kinds(notation)?
+        .into_iter()
+        .map(|kind| take_tile_id(kind, &mut used))
+        .collect()
+}
+
+pub fn format_tiles(tiles: &[TileId]) -> String {
+    let mut result = String::new();
+    let mut pending_suffix: Option<char> = None;
+
+    for tile in tiles {
+        let (value, suffix) = kind_to_notation(&TileKind::from_id(*tile));
+
+        if let Some(prev_suffix) = pending_suffix {
+            if prev_suffix != suffix {
+                result.push(prev_suffix);
+            }
+        }
+
+        result.push_str(&value.to_string());
+        pending_suffix = Some(suffix);
+    }
+
+    if let Some(suffix) = pending_suffix {
+        result.push(suffix);
+    }
+
+    result
+}
+
+pub fn parse_hand(notation: &str) -> Result<HandNotation, NotationError> {
+    let mut used = FxHashSet::default();
+    let mut hand = Hand::default();
+    let mut bonus_tiles = Vec::new();
+    let mut rest = notation.trim();
+
+    while !rest.is_empty() {
+        let (meld_concealed, closing) = match rest.chars().next().unwrap() {
+            '[' => (Some(false), ']'),
+            '(' => (Some(true), ')'),
+            _ => (None, ' '),
+        };
+
+        let (group, next_rest) = match meld_concealed {
+            Some(_) => {
+                let end = rest.find(closing).ok_or(NotationError::UnclosedMeld)?;
+                (&rest[1..end], &rest[end + 1..])
+            }
+            None => {
+                let end = rest.find([' ', '[', '(']).unwrap_or(rest.len());
+                (&rest[..end], &rest[end..])
+            }
+        };
+        rest = next_rest.trim_start();
+
+        // The same placeholder as in the summary for an empty group
+        if group == "_" {
+            continue;
+        }
+
+        let mut group_tiles = Vec::new();
+        for kind in parse_kinds(group)? {
+            let id = take_tile_id(kind, &mut used)?;
+            if kind.is_bonus() {
+                bonus_tiles.push(id);
+            } else {
+                group_tiles.push(id);
+            }
+        }
+
+        let Some(concealed) = meld_concealed else {
+            hand.list
+                .extend(group_tiles.into_iter().map(HandTile::from_id));
+            continue;
+        };
+
+        if !(3..=4).contains(&group_tiles.len()) {
+            return Err(NotationError::InvalidMeld);
+        }
+
+        let set_id = Uuid::new_v4().to_string();
+        for (idx, id) in group_tiles.into_iter().enumerate() {
+            // The fourth tile of a kong is not part of the list
+            if idx == 3 {
+                hand.kong_tiles.insert(KongTile {
+                    concealed,
+                    id,
+                    set_id: set_id.clone(),
+                });
+                continue;
+            }
+
+            hand.list.push(HandTile {
+                concealed,
+                id,
+                set_id: Some(set_id.clone()),
+            });
+        }
+    }
+
+    Ok(HandNotation { hand, bonus_tiles })
+}
+
+pub fn format_hand(hand: &Hand, bonus_tiles: &[TileId]) -> String {
+    let unmelded = hand
+        .list
+        .iter()
+        .filter(|tile| tile.set_id.is_none())
+        .map(|tile| tile.id)
+        .collect::<Vec<TileId>>();
+    let mut groups = [unmelded.as_slice(), bonus_tiles]
+        .iter()
+        .filter(|tiles| !tiles.is_empty())
+        .map(|tiles| format_tiles(tiles))
+        .collect::<Vec<String>>();
+
+    let mut melds = hand
+        .get_sets_groups()
+        .into_iter()
+        .filter(|(set_id, _)| set_id.is_some())
+        .map(|(_, mut tiles)| {
+            tiles.sort_by_key(|tile| get_notation_order(*tile));
+            tiles
+        })
+        .collect::<Vec<Vec<TileId>>>();
+    melds.sort_by_key(|tiles| get_notation_order(tiles[0]));
+
+    for meld in melds {
+        let concealed = hand
+            .list
+            .iter()
+            .find(|tile| tile.id == meld[0])
+            .map(|tile| tile.concealed)
+            .unwrap_or_else(|| {
+                hand.kong_tiles
+                    .iter()
+                    .any(|kong_tile| kong_tile.id == meld[0] && kong_tile.concealed)
+            });
+        let formatted = format_tiles(&meld);
+
+        groups.push(if concealed {
+            format!("({formatted})")
+        } else {
+            format!("[{formatted}]")
+        });
+    }
+
+    groups.join(" ")
+}
+
+impl Hand {
+    // The bonus tiles are ignored, since they are not part of the hand
+    pub fn from_notation(notation: &str) -> Result<Self, NotationError> {
+        parse_hand(notation).map(|parsed| parsed.hand)
+    }
+
+    pub fn to_notation(&self) -> String {
+        format_hand(self, &[])
+    }
+}
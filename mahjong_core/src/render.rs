@@ -0,0 +1,226 @@
+use crate::{
+    deck::DEFAULT_DECK,
+    notation,
+    tile_kind::{TileKind, BONUS_TILE_KINDS_NUM, TILE_KINDS_NUM},
+    Animal, Dragon, Flower, Season, Suit, Tile, TileId, Wind,
+};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use strum_macros::EnumIter;
+use ts_rs::TS;
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, EnumIter, TS)]
+#[ts(export)]
+pub enum TileFormat {
+    // The same as a single tile of the MPSZ notation, e.g. `5m`
+    Ascii,
+    #[default]
+    Chinese,
+    Emoji,
+    English,
+    // The Mahjong Tiles block, which doesn't have the animals, so they use the Chinese form
+    Unicode,
+}
+
+impl FromStr for TileFormat {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "ascii" => Ok(Self::Ascii),
+            "chinese" => Ok(Self::Chinese),
+            "emoji" => Ok(Self::Emoji),
+            "english" => Ok(Self::English),
+            "unicode" => Ok(Self::Unicode),
+            _ => Err(()),
+        }
+    }
+}
+
+fn render_chinese(tile: &Tile) -> String {
+    match tile {
+        Tile::Dragon(tile) => match tile.value {
+            Dragon::Red => "中",
+            Dragon::Green => "發",
+            Dragon::White => "白",
+        }
+        .to_string(),
+        Tile::Wind(tile) => tile.value.to_string(),
+        Tile::Flower(tile) => match tile.value {
+            Flower::Plum => "梅",
+            Flower::Orchid => "蘭",
+            Flower::Chrysanthemum => "菊",
+            Flower::Bamboo => "竹",
+        }
+        .to_string(),
+        Tile::Season(tile) => match tile.value {
+            Season::Spring => "春",
+            Season::Summer => "夏",
+            Season::Autumn => "秋",
+            Season::Winter => "冬",
+        }
+        .to_string(),
+        Tile::Animal(tile) => match tile.value {
+            Animal::Cat => "貓",
+            Animal::Mouse => "鼠",
+            Animal::Rooster => "雞",
+            Animal::Centipede => "蜈",
+        }
+        .to_string(),
+        Tile::Suit(tile) => {
+            let value_str =
+                ["一", "二", "三", "四", "五", "六", "七", "八", "九"][tile.value as usize - 1];
+            let suit_str = match tile.suit {
+                Suit::Bamboo => "索",
+                Suit::Dots => "筒",
+                Suit::Characters => "萬",
+            };
+            format!("{value_str}{suit_str}")
+        }
+    }
+}
+
+fn render_emoji(tile: &Tile) -> String {
+    match tile {
+        Tile::Suit(tile) => match tile.suit {
+            Suit::Bamboo => format!("🎋{}", tile.value),
+            Suit::Characters => format!("✨{}", tile.value),
+            Suit::Dots => format!("💠{}", tile.value),
+        },
+        Tile::Wind(tile) => match tile.value {
+            Wind::East => "🍃EA".to_string(),
+            Wind::North => "🍃NO".to_string(),
+            Wind::South => "🍃SO".to_string(),
+            Wind::West => "🍃WE".to_string(),
+        },
+        Tile::Dragon(tile) => match tile.value {
+            Dragon::Green => "🐉GR".to_string(),
+            Dragon::Red => "🐉RE".to_string(),
+            Dragon::White => "🐉WH".to_string(),
+        },
+        Tile::Flower(tile) => match tile.value {
+            Flower::Bamboo => "💮BA".to_string(),
+            Flower::Chrysanthemum => "💮CH".to_string(),
+            Flower::Orchid => "💮OR".to_string(),
+            Flower::Plum => "💮PL".to_string(),
+        },
+        Tile::Season(tile) => match tile.value {
+            Season::Autumn => "🌞AU".to_string(),
+            Season::Spring => "🌞SP".to_string(),
+            Season::Summer => "🌞SU".to_string(),
+            Season::Winter => "🌞WI".to_string(),
+        },
+        Tile::Animal(tile) => match tile.value {
+            Animal::Cat => "🐾CA".to_string(),
+            Animal::Centipede => "🐾CE".to_string(),
+            Animal::Mouse => "🐾MO".to_string(),
+            Animal::Rooster => "🐾RO".to_string(),
+        },
+    }
+}
+
+fn render_english(tile: &Tile) -> String {
+    match tile {
+        Tile::Suit(tile) => format!("{} {:?}", tile.value, tile.suit),
+        Tile::Wind(tile) => format!("{:?} Wind", tile.value),
+        Tile::Dragon(tile) => format!("{:?} Dragon", tile.value),
+        Tile::Flower(tile) => format!("{:?} Flower", tile.value),
+        Tile::Season(tile) => format!("{:?} Season", tile.value),
+        Tile::Animal(tile) => format!("{:?}", tile.value),
+    }
+}
+
+fn render_unicode(tile: &Tile) -> String {
+    let code = match tile {
+        Tile::Wind(tile) => match tile.value {
+            Wind::East => 0x1F000,
+            Wind::South => 0x1F001,
+            Wind::West => 0x1F002,
+            Wind::North => 0x1F003,
+        },
+        Tile::Dragon(tile) => match tile.value {
+            Dragon::Red => 0x1F004,
+            Dragon::Green => 0x1F005,
+            Dragon::White => 0x1F006,
+        },
+        Tile::Suit(tile) => {
+            let first = match tile.suit {
+                Suit::Characters => 0x1F007,
+                Suit::Bamboo => 0x1F010,
+                Suit::Dots => 0x1F019,
+            };
+            first + tile.value - 1
+        }
+        Tile::Flower(tile) => match tile.value {
+            Flower::Plum => 0x1F022,
+            Flower::Orchid => 0x1F023,
+            Flower::Bamboo => 0x1F024,
+            Flower::Chrysanthemum => 0x1F025,
+        },
+        Tile::Season(tile) => match tile.value {
+            Season::Spring => 0x1F026,
+            Season::Summer => 0x1F027,
+            Season::Autumn => 0x1F028,
+            Season::Winter => 0x1F029,
+        },
+        Tile::Animal(_) => return render_chinese(tile),
+    };
+
+    char::from_u32(code).unwrap().to_string()
+}
+
+pub fn render_tile(tile: &Tile, format: TileFormat) -> String {
+    match format {
+        TileFormat::Ascii => notation::format_tiles(&[tile.get_id()]),
+        TileFormat::Chinese => render_chinese(tile),
+        TileFormat::Emoji => render_emoji(tile),
+        TileFormat::English => render_english(tile),
+        TileFormat::Unicode => render_unicode(tile),
+    }
+}
+
+pub fn render_tiles(tiles: &[TileId], format: TileFormat) -> String {
+    let separator = match format {
+        TileFormat::Unicode => "",
+        _ => ",",
+    };
+
+    tiles
+        .iter()
+        .map(|tile| render_tile(DEFAULT_DECK.get_sure(*tile), format))
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+// For the ids that come from outside (e.g. a request), which can be out of the deck
+pub fn try_render_tiles(tiles: &[TileId], format: TileFormat) -> Option<String> {
+    if tiles.iter().any(|tile| *tile >= DEFAULT_DECK.0.len()) {
+        return None;
+    }
+
+    Some(render_tiles(tiles, format))
+}
+
+// Returns the first tile of the deck with the same content, since the rendered tile doesn't
+// include the id
+pub fn parse_tile(text: &str, format: TileFormat) -> Option<Tile> {
+    let text = text.trim();
+
+    (0..TILE_KINDS_NUM + BONUS_TILE_KINDS_NUM)
+        .map(|index| DEFAULT_DECK.get_sure(TileKind::from_index(index).get_tile_id()))
+        .find(|tile| {
+            let rendered = render_tile(tile, format);
+
+            match format {
+                TileFormat::English => rendered.eq_ignore_ascii_case(text),
+                _ => rendered == text,
+            }
+        })
+        .cloned()
+}
+
+impl Tile {
+    pub fn render(&self, format: TileFormat) -> String {
+        render_tile(self, format)
+    }
+}
@@ -1,7 +1,7 @@
 pub use self::decide_dealer::{DecideDealerWinds, SetInitialWindsError};
 use crate::{
-    game::GameStyle, macros::derive_game_common, Game, GamePhase, Hands, PlayerId, TileId, Wind,
-    WINDS_ROUND_ORDER,
+    game::GameStyle, macros::derive_game_common, ruleset::Ruleset, score::ScorePayment, GamePhase,
+    Hands, PlayerId, TileId, Wind, WINDS_ROUND_ORDER,
 };
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
@@ -19,6 +19,34 @@ pub struct RoundTileClaimed {
 
 pub type TileClaimed = Option<RoundTileClaimed>;
 
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub struct RoundDiscard {
+    pub claimed_by: Option<PlayerId>,
+    pub player_id: PlayerId,
+    pub tile: TileId,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, EnumIter, TS)]
+#[ts(export)]
+pub enum AbortiveDraw {
+    // Four kongs declared by more than one player
+    FourKongs,
+    // The same wind discarded by the four players in the first go-around
+    FourWinds,
+    // Nine different terminals and honours in the first draw of a player
+    NineTerminals,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub enum RoundOutcome {
+    AbortiveDraw(AbortiveDraw),
+    // The players with a ready hand when the wall was exhausted
+    ExhaustiveDraw(Vec<PlayerId>),
+    Mahjong(Vec<PlayerId>),
+}
+
 derive_game_common! {
 #[derive(TS)]
 #[ts(export)]
@@ -34,6 +62,15 @@ pub struct Round {
     pub wall_tile_drawn: Option<TileId>,
     pub wind: Wind,
     pub initial_winds: Option<u8>,
+    // The payments of the round, kept after it ends until the next round is dealt
+    #[serde(default)]
+    pub payments: Vec<ScorePayment>,
+    // How the previous round ended
+    #[serde(default)]
+    pub last_outcome: Option<RoundOutcome>,
+    // The tiles discarded in the round by each player, in order, including the claimed ones
+    #[serde(default)]
+    pub discards: Vec<RoundDiscard>,
 }}
 
 #[derive(Debug, EnumIter, Eq, PartialEq, Clone)]
@@ -56,6 +93,9 @@ impl Round {
             wind: Wind::East,
             east_player_index: 0,
             initial_winds: None,
+            payments: vec![],
+            last_outcome: None,
+            discards: vec![],
         }
     }
 
@@ -81,12 +121,12 @@ impl Round {
 }
 
 impl Round {
-    pub fn next_turn(&mut self, hands: &Hands) -> Result<(), NextTurnError> {
+    pub fn next_turn(&mut self, hands: &Hands, ruleset: &dyn Ruleset) -> Result<(), NextTurnError> {
         if self.wall_tile_drawn.is_none() {
             return Err(NextTurnError::StuckWallTileNotDrawn);
         }
 
-        let expected_tiles = hands.get_style().tiles_after_claim() - 1;
+        let expected_tiles = ruleset.tiles_after_claim() - 1;
 
         for hand_player in hands.0.keys() {
             let hand = hands.get(hand_player);
@@ -103,14 +143,14 @@ impl Round {
         self.tile_claimed = None;
 
         self.player_index += 1;
-        if self.player_index == Game::get_players_num(&self.style) {
+        if self.player_index == ruleset.get_players_num() {
             self.player_index = 0;
         }
 
         Ok(())
     }
 
-    fn common_next_round(&mut self, phase: &mut GamePhase) {
+    fn common_next_round(&mut self, phase: &mut GamePhase, ruleset: &dyn Ruleset) {
         let mut current_wind_index = WINDS_ROUND_ORDER
             .iter()
             .position(|r| r == &self.wind)
@@ -118,7 +158,7 @@ impl Round {
 
         self.consecutive_same_seats = 0;
         self.dealer_player_index += 1;
-        if self.dealer_player_index == Game::get_players_num(&self.style) {
+        if self.dealer_player_index == ruleset.get_players_num() {
             self.dealer_player_index = 0;
         }
 
@@ -136,12 +176,18 @@ impl Round {
         self.player_index = self.dealer_player_index;
     }
 
-    pub fn move_after_win(&mut self, phase: &mut GamePhase, winner_player_index: usize) {
+    pub fn move_after_win(
+        &mut self,
+        phase: &mut GamePhase,
+        winner_player_index: usize,
+        ruleset: &dyn Ruleset,
+    ) {
         self.wall_tile_drawn = None;
         self.tile_claimed = None;
+        self.discards.clear();
         self.round_index += 1;
 
-        let max_consecutive_same_seats = self.style.max_consecutive_same_seats();
+        let max_consecutive_same_seats = ruleset.max_consecutive_same_seats();
 
         if winner_player_index == self.dealer_player_index
             && self.consecutive_same_seats < max_consecutive_same_seats
@@ -151,23 +197,29 @@ impl Round {
             return;
         }
 
-        self.common_next_round(phase)
+        self.common_next_round(phase, ruleset)
     }
 
-    pub fn move_after_draw(&mut self, phase: &mut GamePhase) {
+    pub fn move_after_draw(
+        &mut self,
+        phase: &mut GamePhase,
+        dealer_keeps_seat: bool,
+        ruleset: &dyn Ruleset,
+    ) {
         self.wall_tile_drawn = None;
         self.tile_claimed = None;
+        self.discards.clear();
         self.round_index += 1;
 
-        let max_consecutive_same_seats = self.style.max_consecutive_same_seats();
+        let max_consecutive_same_seats = ruleset.max_consecutive_same_seats();
 
-        if self.consecutive_same_seats < max_consecutive_same_seats {
+        if dealer_keeps_seat && self.consecutive_same_seats < max_consecutive_same_seats {
             self.player_index = self.dealer_player_index;
             self.consecutive_same_seats += 1;
             return;
         }
 
-        self.common_next_round(phase)
+        self.common_next_round(phase, ruleset)
     }
 
     pub fn set_initial_winds(
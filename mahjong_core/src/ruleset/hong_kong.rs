@@ -0,0 +1,185 @@
+// http://mahjongtime.com/hong-kong-mahjong-scoring.html
+// https://en.wikipedia.org/wiki/Hong_Kong_mahjong_scoring_rules
+
+use super::{house_rules::DEFAULT_HOUSE_RULES, HouseRules, Ruleset};
+use crate::{
+    deck::DEFAULT_DECK,
+    hand::CanSayMahjongError,
+    meld::{get_is_pair, MeldType},
+    score::{ScoringContext, ScoringRule},
+    Flower, Hand, Season, Tile, FLOWERS_ORDER, SEASONS_ORDER, WINDS_ROUND_ORDER,
+};
+use rustc_hash::FxHashSet;
+
+// The default methods of `Ruleset` are already the Hong Kong rules
+#[derive(Clone, Copy, Debug)]
+pub struct HongKongRuleset<'a> {
+    pub house_rules: &'a HouseRules,
+}
+
+impl<'a> HongKongRuleset<'a> {
+    pub fn new(house_rules: &'a HouseRules) -> Self {
+        Self { house_rules }
+    }
+}
+
+impl Default for HongKongRuleset<'static> {
+    fn default() -> Self {
+        Self::new(&DEFAULT_HOUSE_RULES)
+    }
+}
+
+impl Ruleset for HongKongRuleset<'_> {
+    fn get_house_rules(&self) -> &HouseRules {
+        self.house_rules
+    }
+}
+
+pub(super) fn can_say_mahjong(
+    hand: &Hand,
+    tiles_after_claim: usize,
+) -> Result<(), CanSayMahjongError> {
+    if hand.is_dead {
+        return Err(CanSayMahjongError::DeadHand);
+    }
+
+    if hand.list.len() != tiles_after_claim {
+        return Err(CanSayMahjongError::CantDrop);
+    }
+
+    let tiles_without_meld: Vec<&Tile> = hand
+        .list
+        .iter()
+        .filter(|t| t.set_id.is_none())
+        .map(|t| &DEFAULT_DECK.0[t.id])
+        .collect();
+
+    let is_pair = get_is_pair(&tiles_without_meld);
+
+    if !is_pair {
+        return Err(CanSayMahjongError::NotPair);
+    }
+
+    Ok(())
+}
+
+pub(super) fn get_scoring_rules(
+    house_rules: &HouseRules,
+    context: &ScoringContext,
+) -> Vec<ScoringRule> {
+    let mut rules = Vec::new();
+    rules.push(ScoringRule::BasePoint);
+    let winner_melds = context.hand.get_melds();
+    let melds_without_pair = winner_melds
+        .melds
+        .iter()
+        .filter(|meld| meld.meld_type != MeldType::Pair)
+        .collect::<Vec<_>>();
+
+    if melds_without_pair
+        .iter()
+        .all(|meld| meld.meld_type == MeldType::Chow)
+    {
+        rules.push(ScoringRule::CommonHand);
+    }
+
+    if melds_without_pair
+        .iter()
+        .all(|meld| meld.meld_type == MeldType::Pung || meld.meld_type == MeldType::Kong)
+    {
+        rules.push(ScoringRule::AllInTriplets);
+    }
+
+    if melds_without_pair
+        .iter()
+        .filter(|meld| {
+            if meld.meld_type == MeldType::Chow {
+                return false;
+            }
+
+            let tile = &DEFAULT_DECK.0[meld.tiles[0]];
+
+            matches!(tile, Tile::Dragon(_))
+        })
+        .count()
+        == 3
+    {
+        rules.push(ScoringRule::GreatDragons);
+    }
+
+    if context.is_wall_empty {
+        rules.push(ScoringRule::LastWallTile);
+    }
+
+    if context.is_self_draw {
+        rules.push(ScoringRule::SelfDraw);
+    }
+
+    let mut flowers: FxHashSet<Flower> = FxHashSet::default();
+    let mut seasons: FxHashSet<Season> = FxHashSet::default();
+
+    for tile_id in context.bonus_tiles {
+        let tile = &DEFAULT_DECK.0[*tile_id];
+        match tile {
+            Tile::Flower(flower) => {
+                flowers.insert(flower.value.clone());
+            }
+            Tile::Season(season) => {
+                seasons.insert(season.value.clone());
+            }
+            _ => {}
+        }
+    }
+
+    if !house_rules.flowers_count {
+        return rules;
+    }
+
+    if flowers.is_empty() && seasons.is_empty() {
+        rules.push(ScoringRule::NoFlowersSeasons);
+    } else {
+        if flowers.len() == 4 {
+            rules.push(ScoringRule::AllFlowers);
+        }
+
+        if seasons.len() == 4 {
+            rules.push(ScoringRule::AllSeasons);
+        }
+
+        let player_wind = &context.seat_wind;
+        let has_seat_flower = flowers.iter().any(|flower| {
+            let flower_index = FLOWERS_ORDER.iter().position(|f| f == flower).unwrap();
+            WINDS_ROUND_ORDER[flower_index] == *player_wind
+        });
+        let has_seat_season = seasons.iter().any(|season| {
+            let season_index = SEASONS_ORDER.iter().position(|s| s == season).unwrap();
+            WINDS_ROUND_ORDER[season_index] == *player_wind
+        });
+
+        if has_seat_flower {
+            rules.push(ScoringRule::SeatFlower);
+        }
+
+        if has_seat_season {
+            rules.push(ScoringRule::SeatSeason);
+        }
+    }
+
+    rules
+}
+
+pub(super) fn get_scoring_rule_points(rule: &ScoringRule) -> u32 {
+    match rule {
+        ScoringRule::AllFlowers => 2,
+        ScoringRule::AllInTriplets => 3,
+        ScoringRule::AllSeasons => 2,
+        ScoringRule::BasePoint => 1,
+        ScoringRule::CommonHand => 1,
+        ScoringRule::GreatDragons => 8,
+        ScoringRule::LastWallTile => 1,
+        ScoringRule::NoFlowersSeasons => 1,
+        ScoringRule::SeatFlower => 1,
+        ScoringRule::SeatSeason => 1,
+        ScoringRule::SelfDraw => 1,
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{round::AbortiveDraw, score::ScoreItem};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+// What happens when several players can win with the same discard
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub enum DiscardWinners {
+    // The player that says mahjong first wins
+    #[default]
+    FirstCaller,
+    // Only the first of them in turn order after the discarder wins
+    HeadBump,
+    // All of them win, and the discarder pays each one
+    Multiple,
+}
+
+// Options that change per club on top of the style rules. Missing fields use the default values,
+// so new options can be added without breaking the stored games.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+#[serde(default)]
+pub struct HouseRules {
+    // The conditions that end the round without a winner, when declared or detected
+    pub abortive_draws: Vec<AbortiveDraw>,
+    // A false mahjong or an illegal exposed meld make the player hand dead
+    pub chombo_dead_hand: bool,
+    // When it has a value, a false mahjong or an illegal exposed meld are not just rejected, the
+    // hand is revealed and the player pays this to each other player
+    pub chombo_penalty: Option<ScoreItem>,
+    pub chow_only_from_left: bool,
+    pub concealed_kongs_shown: bool,
+    // At an exhaustive draw the dealer keeps the seat only if their hand is ready
+    pub dealer_keeps_seat_if_ready: bool,
+    pub discard_winners: DiscardWinners,
+    pub exhaustive_draw_passes_dealer: bool,
+    pub flowers_count: bool,
+    // The player that feeds a big hand pays for it (pao). Only some clubs use it.
+    pub liability_payments: bool,
+    // At an exhaustive draw, each player without a ready hand pays this to each ready player
+    pub ready_hand_payment: Option<ScoreItem>,
+}
+
+impl Default for HouseRules {
+    fn default() -> Self {
+        Self {
+            abortive_draws: vec![],
+            chombo_dead_hand: false,
+            chombo_penalty: None,
+            chow_only_from_left: true,
+            concealed_kongs_shown: false,
+            dealer_keeps_seat_if_ready: false,
+            discard_winners: DiscardWinners::default(),
+            exhaustive_draw_passes_dealer: false,
+            flowers_count: true,
+            liability_payments: false,
+            ready_hand_payment: None,
+        }
+    }
+}
+
+lazy_static! {
+    // For the rulesets that are not built from a game
+    pub(super) static ref DEFAULT_HOUSE_RULES: HouseRules = HouseRules::default();
+}
@@ -0,0 +1,190 @@
+pub use self::hong_kong::HongKongRuleset;
+pub use self::house_rules::{DiscardWinners, HouseRules};
+pub use self::singapore::SingaporeRuleset;
+use crate::{
+    game::GameStyle,
+    hand::CanSayMahjongError,
+    meld::{
+        get_is_chow, get_is_kong, get_is_pung, get_is_winning_shape, MeldType, PossibleMeld,
+        SetCheckOpts,
+    },
+    round::AbortiveDraw,
+    score::{ScoreItem, ScoringContext, ScoringRule},
+    tile_kind::TileKindCounts,
+    Game, Hand, Tile,
+};
+use std::ops::Deref;
+
+mod hong_kong;
+mod house_rules;
+mod singapore;
+
+// The rules that can differ between styles. The game logic should use this trait instead of
+// matching the `GameStyle`, so new variants can be added as new implementations. The default
+// methods are the Hong Kong rules, so an implementation only overrides what differs from them.
+pub trait Ruleset {
+    fn get_house_rules(&self) -> &HouseRules;
+
+    // Deck composition
+    fn get_players_num(&self) -> usize {
+        4
+    }
+
+    fn has_tile(&self, tile: &Tile) -> bool {
+        !matches!(tile, Tile::Animal(_))
+    }
+
+    // Hand size
+    fn tiles_after_claim(&self) -> usize {
+        14
+    }
+
+    // Meld legality
+    fn is_chow(&self, opts: &SetCheckOpts) -> bool {
+        if self.get_house_rules().chow_only_from_left {
+            return get_is_chow(opts);
+        }
+
+        get_is_chow(&SetCheckOpts {
+            board_tile_player_diff: None,
+            ..opts.clone()
+        })
+    }
+
+    fn is_kong(&self, opts: &SetCheckOpts) -> bool {
+        get_is_kong(opts)
+    }
+
+    fn is_pung(&self, opts: &SetCheckOpts) -> bool {
+        get_is_pung(opts)
+    }
+
+    // Higher priority claims are resolved first when several players want the same discard
+    fn get_claim_priority(&self, meld: &PossibleMeld) -> u8 {
+        if meld.is_mahjong {
+            return 2;
+        }
+
+        match MeldType::from_tiles(&meld.tiles) {
+            Some(MeldType::Kong) | Some(MeldType::Pung) => 1,
+            _ => 0,
+        }
+    }
+
+    // Winning shapes
+    fn can_say_mahjong(&self, hand: &Hand) -> Result<(), CanSayMahjongError> {
+        hong_kong::can_say_mahjong(hand, self.tiles_after_claim())
+    }
+
+    fn is_winning_shape(&self, counts: &TileKindCounts) -> bool {
+        get_is_winning_shape(counts)
+    }
+
+    // If the seven pairs and the thirteen orphans are winning shapes
+    fn has_special_shapes(&self) -> bool {
+        false
+    }
+
+    fn get_discard_winners(&self) -> DiscardWinners {
+        self.get_house_rules().discard_winners.clone()
+    }
+
+    // Visibility
+    fn are_concealed_kongs_shown(&self) -> bool {
+        self.get_house_rules().concealed_kongs_shown
+    }
+
+    // Scoring
+    fn get_scoring_rules(&self, context: &ScoringContext) -> Vec<ScoringRule> {
+        hong_kong::get_scoring_rules(self.get_house_rules(), context)
+    }
+
+    fn get_scoring_rule_points(&self, rule: &ScoringRule) -> u32 {
+        hong_kong::get_scoring_rule_points(rule)
+    }
+
+    fn has_bonus_bites(&self) -> bool {
+        false
+    }
+
+    fn has_liability_payments(&self) -> bool {
+        self.get_house_rules().liability_payments
+    }
+
+    fn get_ready_hand_payment(&self) -> Option<ScoreItem> {
+        self.get_house_rules().ready_hand_payment
+    }
+
+    // Penalties
+    fn get_chombo_penalty(&self) -> Option<ScoreItem> {
+        self.get_house_rules().chombo_penalty
+    }
+
+    fn has_chombo_dead_hand(&self) -> bool {
+        self.get_house_rules().chombo_dead_hand
+    }
+
+    // Round progression
+    fn max_consecutive_same_seats(&self) -> usize {
+        3
+    }
+
+    fn dealer_keeps_seat_after_draw(&self) -> bool {
+        !self.get_house_rules().exhaustive_draw_passes_dealer
+    }
+
+    fn dealer_keeps_seat_if_ready(&self) -> bool {
+        self.get_house_rules().dealer_keeps_seat_if_ready
+    }
+
+    fn get_abortive_draws(&self) -> Vec<AbortiveDraw> {
+        self.get_house_rules().abortive_draws.clone()
+    }
+}
+
+impl dyn Ruleset + '_ {
+    // The scoring rules of the win, and the sum of their points
+    pub fn get_context_score(&self, context: &ScoringContext) -> (Vec<ScoringRule>, u32) {
+        let scoring_rules = self.get_scoring_rules(context);
+        let points = scoring_rules
+            .iter()
+            .map(|rule| self.get_scoring_rule_points(rule))
+            .sum::<u32>();
+
+        (scoring_rules, points)
+    }
+}
+
+// The ruleset of a game style. It only borrows the house rules and it is not boxed, so it is cheap
+// to build in the hot paths (e.g. the possible melds of every AI action).
+#[derive(Clone, Copy, Debug)]
+pub enum StyleRuleset<'a> {
+    HongKong(HongKongRuleset<'a>),
+    Singapore(SingaporeRuleset<'a>),
+}
+
+impl<'a> Deref for StyleRuleset<'a> {
+    type Target = dyn Ruleset + 'a;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::HongKong(ruleset) => ruleset,
+            Self::Singapore(ruleset) => ruleset,
+        }
+    }
+}
+
+impl GameStyle {
+    pub fn get_ruleset<'a>(&self, house_rules: &'a HouseRules) -> StyleRuleset<'a> {
+        match self {
+            Self::HongKong => StyleRuleset::HongKong(HongKongRuleset::new(house_rules)),
+            Self::Singapore => StyleRuleset::Singapore(SingaporeRuleset::new(house_rules)),
+        }
+    }
+}
+
+impl Game {
+    pub fn get_ruleset(&self) -> StyleRuleset<'_> {
+        self.style.get_ruleset(&self.house_rules)
+    }
+}
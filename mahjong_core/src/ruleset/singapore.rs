@@ -0,0 +1,34 @@
+use super::{house_rules::DEFAULT_HOUSE_RULES, HouseRules, Ruleset};
+use crate::Tile;
+
+// For now it is the same as the Hong Kong style, but with the animal tiles and the bonus bites
+#[derive(Clone, Copy, Debug)]
+pub struct SingaporeRuleset<'a> {
+    pub house_rules: &'a HouseRules,
+}
+
+impl<'a> SingaporeRuleset<'a> {
+    pub fn new(house_rules: &'a HouseRules) -> Self {
+        Self { house_rules }
+    }
+}
+
+impl Default for SingaporeRuleset<'static> {
+    fn default() -> Self {
+        Self::new(&DEFAULT_HOUSE_RULES)
+    }
+}
+
+impl Ruleset for SingaporeRuleset<'_> {
+    fn get_house_rules(&self) -> &HouseRules {
+        self.house_rules
+    }
+
+    fn has_tile(&self, _tile: &Tile) -> bool {
+        true
+    }
+
+    fn has_bonus_bites(&self) -> bool {
+        self.house_rules.flowers_count
+    }
+}
@@ -1,16 +1,14 @@
-// http://mahjongtime.com/hong-kong-mahjong-scoring.html
-// https://en.wikipedia.org/wiki/Hong_Kong_mahjong_scoring_rules
-
 use crate::{
-    deck::DEFAULT_DECK, meld::MeldType, Flower, Game, PlayerId, Season, Tile, FLOWERS_ORDER,
-    SEASONS_ORDER, WINDS_ROUND_ORDER,
+    deck::DEFAULT_DECK, Animal, AnimalTile, FlowerTile, Game, Hand, PlayerId, SeasonTile, Tile,
+    TileId, Wind, FLOWERS_ORDER, SEASONS_ORDER,
 };
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 use ts_rs::TS;
 
-pub type ScoreItem = u32;
+pub type ScoreItem = i32;
 pub type ScoreMap = FxHashMap<PlayerId, ScoreItem>;
 
 #[derive(Clone, Debug, Serialize, Deserialize, TS)]
@@ -48,6 +46,98 @@ impl Score {
 
         Self(score)
     }
+
+    pub fn transfer(&mut self, payment: &ScorePayment) {
+        *self.0.entry(payment.from.clone()).or_default() -= payment.amount;
+        *self.0.entry(payment.to.clone()).or_default() += payment.amount;
+    }
+}
+
+// Combinations of bonus tiles that are paid instantly by the other players
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum BonusBite {
+    AllFlowers,
+    AllSeasons,
+    CatAndMouse,
+    RoosterAndCentipede,
+}
+
+impl BonusBite {
+    fn get_tiles(&self) -> Vec<Tile> {
+        match self {
+            Self::AllFlowers => FLOWERS_ORDER
+                .iter()
+                .map(|value| {
+                    Tile::Flower(FlowerTile {
+                        id: 0,
+                        value: value.clone(),
+                    })
+                })
+                .collect(),
+            Self::AllSeasons => SEASONS_ORDER
+                .iter()
+                .map(|value| {
+                    Tile::Season(SeasonTile {
+                        id: 0,
+                        value: value.clone(),
+                    })
+                })
+                .collect(),
+            Self::CatAndMouse => [Animal::Cat, Animal::Mouse]
+                .into_iter()
+                .map(|value| Tile::Animal(AnimalTile { id: 0, value }))
+                .collect(),
+            Self::RoosterAndCentipede => [Animal::Rooster, Animal::Centipede]
+                .into_iter()
+                .map(|value| Tile::Animal(AnimalTile { id: 0, value }))
+                .collect(),
+        }
+    }
+
+    pub fn get_amount(&self) -> ScoreItem {
+        match self {
+            Self::AllFlowers | Self::AllSeasons => 2,
+            Self::CatAndMouse | Self::RoosterAndCentipede => 1,
+        }
+    }
+
+    // Returns the bites that are completed by the new tile (but that were not complete before)
+    pub fn from_new_tile(bonus_tiles: &[TileId], new_tile: TileId) -> Vec<Self> {
+        let new_tile_full = &DEFAULT_DECK.0[new_tile];
+
+        Self::iter()
+            .filter(|bite| {
+                let tiles = bite.get_tiles();
+
+                tiles.iter().any(|t| t.is_same_content(new_tile_full))
+                    && tiles.iter().all(|t| {
+                        bonus_tiles
+                            .iter()
+                            .any(|id| DEFAULT_DECK.0[*id].is_same_content(t))
+                    })
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum ScorePaymentReason {
+    Bite(BonusBite),
+    Chombo,
+    DiscardWin,
+    Liability,
+    ReadyHand,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ScorePayment {
+    pub amount: ScoreItem,
+    pub from: PlayerId,
+    pub reason: ScorePaymentReason,
+    pub to: PlayerId,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, EnumIter)]
@@ -65,155 +155,261 @@ pub enum ScoringRule {
     SelfDraw,
 }
 
+// What the scoring rules need to know about a win, so a hand can be scored without a full game
+pub struct ScoringContext<'a> {
+    pub bonus_tiles: &'a [TileId],
+    // With the winning tile and the melds set
+    pub hand: &'a Hand,
+    pub is_self_draw: bool,
+    pub is_wall_empty: bool,
+    pub seat_wind: Wind,
+}
+
 impl Game {
-    fn get_scoring_rules_points(scoring_rules: &Vec<ScoringRule>) -> u32 {
-        let mut round_points = 0;
-
-        for rule in scoring_rules {
-            round_points += match rule {
-                ScoringRule::AllFlowers => 2,
-                ScoringRule::AllInTriplets => 3,
-                ScoringRule::AllSeasons => 2,
-                ScoringRule::BasePoint => 1,
-                ScoringRule::CommonHand => 1,
-                ScoringRule::GreatDragons => 8,
-                ScoringRule::LastWallTile => 1,
-                ScoringRule::NoFlowersSeasons => 1,
-                ScoringRule::SeatFlower => 1,
-                ScoringRule::SeatSeason => 1,
-                ScoringRule::SelfDraw => 1,
+    pub fn get_scoring_context<'a>(&'a self, winner_player: &PlayerId) -> ScoringContext<'a> {
+        ScoringContext {
+            bonus_tiles: self
+                .table
+                .bonus_tiles
+                .0
+                .get(winner_player)
+                .map(|tiles| tiles.as_slice())
+                .unwrap_or_default(),
+            hand: self.table.hands.0.get(winner_player).unwrap(),
+            is_self_draw: self.round.tile_claimed.is_none(),
+            is_wall_empty: self.table.draw_wall.is_empty(),
+            seat_wind: self.round.get_player_wind(&self.players.0, winner_player),
+        }
+    }
+
+    pub fn get_hand_score(&self, winner_player: &PlayerId) -> (Vec<ScoringRule>, u32) {
+        let ruleset = self.get_ruleset();
+
+        ruleset.get_context_score(&self.get_scoring_context(winner_player))
+    }
+
+    pub fn calculate_hand_score(&mut self, winner_player: &PlayerId) -> (Vec<ScoringRule>, u32) {
+        {
+            let score = &mut self.score;
+            let current_player_score = score.get(winner_player);
+            if current_player_score.is_none() {
+                return (vec![], 0);
             }
         }
 
-        round_points
+        let (scoring_rules, round_points) = self.get_hand_score(winner_player);
+
+        let current_player_score = self.score.get(winner_player).unwrap();
+
+        self.score.insert(
+            winner_player,
+            current_player_score + round_points as ScoreItem,
+        );
+
+        (scoring_rules, round_points)
     }
 
-    fn get_scoring_rules(&self, winner_player: &PlayerId) -> Vec<ScoringRule> {
-        let mut rules = Vec::new();
-        rules.push(ScoringRule::BasePoint);
-        let empty_bonus = vec![];
-        let winner_hand = self.table.hands.0.get(winner_player).unwrap();
-        let winner_melds = winner_hand.get_melds();
-        let melds_without_pair = winner_melds
-            .melds
-            .iter()
-            .filter(|meld| meld.meld_type != MeldType::Pair)
-            .collect::<Vec<_>>();
+    pub fn pay_win(
+        &mut self,
+        winner_player: &PlayerId,
+        payer_player: &PlayerId,
+        reason: ScorePaymentReason,
+    ) -> ScorePayment {
+        let (_, round_points) = self.get_hand_score(winner_player);
+        let payment = ScorePayment {
+            amount: round_points as ScoreItem,
+            from: payer_player.clone(),
+            reason,
+            to: winner_player.clone(),
+        };
+
+        self.score.transfer(&payment);
+
+        payment
+    }
 
-        let winner_bonus = self
-            .table
-            .bonus_tiles
-            .0
-            .get(winner_player)
-            .unwrap_or(&empty_bonus);
+    // Scores the winner, and if there is a discarder (with several winners on the same discard)
+    // or a liable player, they pay for it
+    pub fn settle_win(&mut self, winner_player: &PlayerId, discarder_player: Option<&PlayerId>) {
+        if let Some(liable_player) = self.get_liable_player(winner_player) {
+            self.pay_win(winner_player, &liable_player, ScorePaymentReason::Liability);
+        } else if let Some(discarder_player) = discarder_player {
+            self.pay_win(
+                winner_player,
+                discarder_player,
+                ScorePaymentReason::DiscardWin,
+            );
+        } else {
+            self.calculate_hand_score(winner_player);
+        }
+    }
 
-        if melds_without_pair
-            .iter()
-            .all(|meld| meld.meld_type == MeldType::Chow)
-        {
-            rules.push(ScoringRule::CommonHand);
+    // The player that fed the last meld of an exposed Great Dragons or Great Winds, or the final
+    // tile of a hand with four exposed melds
+    pub fn get_liable_player(&self, winner_player: &PlayerId) -> Option<PlayerId> {
+        if !self.get_ruleset().has_liability_payments() {
+            return None;
         }
 
-        if melds_without_pair
-            .iter()
-            .all(|meld| meld.meld_type == MeldType::Pung || meld.meld_type == MeldType::Kong)
-        {
-            rules.push(ScoringRule::AllInTriplets);
+        let hand = self.table.hands.0.get(winner_player)?;
+        let sets_groups = hand.get_sets_groups();
+        let get_suppliers = |is_tile_kind: fn(&Tile) -> bool| {
+            hand.meld_suppliers
+                .iter()
+                .filter(|supplier| {
+                    let tiles = sets_groups.get(&Some(supplier.set_id.clone()));
+
+                    tiles.is_some_and(|tiles| {
+                        let tiles_full = tiles
+                            .iter()
+                            .map(|id| &DEFAULT_DECK.0[*id])
+                            .collect::<Vec<_>>();
+
+                        tiles_full.len() >= 3
+                            && tiles_full.iter().all(|t| t.is_same_content(tiles_full[0]))
+                            && is_tile_kind(tiles_full[0])
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let dragons_suppliers = get_suppliers(|t| matches!(t, Tile::Dragon(_)));
+
+        if dragons_suppliers.len() == 3 {
+            return Some(dragons_suppliers[2].player_id.clone());
         }
 
-        if melds_without_pair
-            .iter()
-            .filter(|meld| {
-                if meld.meld_type == MeldType::Chow {
-                    return false;
-                }
+        let winds_suppliers = get_suppliers(|t| matches!(t, Tile::Wind(_)));
+
+        if winds_suppliers.len() == 4 {
+            return Some(winds_suppliers[3].player_id.clone());
+        }
 
-                let tile = &DEFAULT_DECK.0[meld.tiles[0]];
+        let tile_claimed = self.round.tile_claimed.as_ref()?;
 
-                matches!(tile, Tile::Dragon(_))
-            })
-            .count()
-            == 3
+        if hand.meld_suppliers.len() == 4
+            && tile_claimed.by.as_ref() == Some(winner_player)
+            && hand
+                .list
+                .iter()
+                .any(|t| t.id == tile_claimed.id && t.set_id.is_none())
         {
-            rules.push(ScoringRule::GreatDragons);
+            return Some(tile_claimed.from.clone());
         }
 
-        if self.table.draw_wall.is_empty() {
-            rules.push(ScoringRule::LastWallTile);
-        }
+        None
+    }
+
+    // For a false mahjong or an illegal exposed meld. If the house rules don't have a penalty it
+    // returns false, and the action is just rejected
+    pub fn pay_chombo_penalty(&mut self, player_id: &PlayerId) -> bool {
+        let ruleset = self.style.get_ruleset(&self.house_rules);
+        let penalty = match ruleset.get_chombo_penalty() {
+            Some(penalty) => penalty,
+            None => return false,
+        };
+        let hand = match self.table.hands.0.get_mut(player_id) {
+            Some(hand) if !hand.is_dead => hand,
+            _ => return false,
+        };
+
+        hand.is_revealed = true;
+        hand.is_dead = ruleset.has_chombo_dead_hand();
+
+        for other_player in self.players.0.clone() {
+            if &other_player == player_id {
+                continue;
+            }
 
-        if self.round.tile_claimed.is_none() {
-            rules.push(ScoringRule::SelfDraw);
+            let payment = ScorePayment {
+                amount: penalty,
+                from: player_id.clone(),
+                reason: ScorePaymentReason::Chombo,
+                to: other_player,
+            };
+
+            self.score.transfer(&payment);
+            self.round.payments.push(payment);
         }
 
-        let mut flowers: FxHashSet<Flower> = FxHashSet::default();
-        let mut seasons: FxHashSet<Season> = FxHashSet::default();
+        true
+    }
 
-        for tile_id in winner_bonus {
-            let tile = &DEFAULT_DECK.0[*tile_id];
-            match tile {
-                Tile::Flower(flower) => {
-                    flowers.insert(flower.value.clone());
-                }
-                Tile::Season(season) => {
-                    seasons.insert(season.value.clone());
-                }
-                _ => {}
+    pub fn get_ready_players(&self) -> Vec<PlayerId> {
+        let ruleset = self.get_ruleset();
+
+        self.players
+            .iter()
+            .filter(|player_id| {
+                self.table
+                    .hands
+                    .0
+                    .get(*player_id)
+                    .is_some_and(|hand| hand.is_ready(&*ruleset))
+            })
+            .cloned()
+            .collect()
+    }
+
+    // At an exhaustive draw the ready hands are revealed, and if the house rules have a payment,
+    // the players that are not ready pay it to each ready player
+    pub fn settle_exhaustive_draw(&mut self, ready_players: &[PlayerId]) {
+        for player_id in ready_players {
+            if let Some(hand) = self.table.hands.0.get_mut(player_id) {
+                hand.is_revealed = true;
             }
         }
 
-        if flowers.is_empty() && seasons.is_empty() {
-            rules.push(ScoringRule::NoFlowersSeasons);
-        } else {
-            if flowers.len() == 4 {
-                rules.push(ScoringRule::AllFlowers);
-            }
+        let payment_amount = match self.get_ruleset().get_ready_hand_payment() {
+            Some(payment_amount) => payment_amount,
+            None => return,
+        };
 
-            if seasons.len() == 4 {
-                rules.push(ScoringRule::AllSeasons);
-            }
+        for ready_player in ready_players {
+            for other_player in self.players.0.clone() {
+                if ready_players.contains(&other_player) {
+                    continue;
+                }
 
-            let player_wind = self.round.get_player_wind(&self.players.0, winner_player);
-            let has_seat_flower = flowers.iter().any(|flower| {
-                let flower_index = FLOWERS_ORDER.iter().position(|f| f == flower).unwrap();
-                WINDS_ROUND_ORDER[flower_index] == player_wind
-            });
-            let has_seat_season = seasons.iter().any(|season| {
-                let season_index = SEASONS_ORDER.iter().position(|s| s == season).unwrap();
-                WINDS_ROUND_ORDER[season_index] == player_wind
-            });
-
-            if has_seat_flower {
-                rules.push(ScoringRule::SeatFlower);
-            }
+                let payment = ScorePayment {
+                    amount: payment_amount,
+                    from: other_player,
+                    reason: ScorePaymentReason::ReadyHand,
+                    to: ready_player.clone(),
+                };
 
-            if has_seat_season {
-                rules.push(ScoringRule::SeatSeason);
+                self.score.transfer(&payment);
+                self.round.payments.push(payment);
             }
         }
-
-        rules
     }
-}
 
-impl Game {
-    pub fn calculate_hand_score(&mut self, winner_player: &PlayerId) -> (Vec<ScoringRule>, u32) {
-        {
-            let score = &mut self.score;
-            let current_player_score = score.get(winner_player);
-            if current_player_score.is_none() {
-                return (vec![], 0);
-            }
+    pub fn pay_bonus_bites(&mut self, player_id: &PlayerId, tile_id: TileId) -> Vec<BonusBite> {
+        if !self.get_ruleset().has_bonus_bites() {
+            return vec![];
         }
 
-        let scoring_rules = self.get_scoring_rules(winner_player);
-        let round_points = Self::get_scoring_rules_points(&scoring_rules);
+        let bonus_tiles = self.table.bonus_tiles.get_or_create(player_id).clone();
+        let bites = BonusBite::from_new_tile(&bonus_tiles, tile_id);
 
-        let current_player_score = self.score.get(winner_player).unwrap();
+        for bite in bites.iter() {
+            for other_player in self.players.0.clone() {
+                if &other_player == player_id {
+                    continue;
+                }
 
-        self.score
-            .insert(winner_player, current_player_score + round_points);
+                let payment = ScorePayment {
+                    amount: bite.get_amount(),
+                    from: other_player,
+                    reason: ScorePaymentReason::Bite(bite.clone()),
+                    to: player_id.clone(),
+                };
 
-        (scoring_rules, round_points)
+                self.score.transfer(&payment);
+                self.round.payments.push(payment);
+            }
+        }
+
+        bites
     }
 }
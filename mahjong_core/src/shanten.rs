@@ -0,0 +1,213 @@
+// Shanten is the number of tiles that a hand needs to change to be ready. A ready hand has a
+// shanten of 0, and a complete hand has -1. It is calculated with the counts of each tile kind
+// instead of the tiles, so it is much faster than enumerating the possible melds.
+
+use crate::{
+    tile_kind::{TileKind, TileKindCounts, TILE_KINDS_NUM},
+    TileId,
+};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub struct TileAcceptance {
+    pub tile: TileId,
+    pub unseen: usize,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub struct DiscardAnalysis {
+    pub acceptance: Vec<TileAcceptance>,
+    pub shanten: i8,
+    pub tile: TileId,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub struct ShantenAnalysis {
+    // The tiles that reduce the shanten when the hand is waiting to draw
+    pub acceptance: Vec<TileAcceptance>,
+    // When the hand has the extra tile, the result of each discard, the best ones first
+    pub discards: Vec<DiscardAnalysis>,
+    pub shanten: i8,
+}
+
+// The best shanten of the standard and, when the ruleset accepts them, the special shapes. The
+// special shapes are only possible without exposed melds.
+pub fn get_shanten(counts: &TileKindCounts, melds_num: usize, special_shapes: bool) -> i8 {
+    let standard_shanten = get_standard_shanten(counts, melds_num);
+
+    if !special_shapes || melds_num > 0 {
+        return standard_shanten;
+    }
+
+    standard_shanten
+        .min(get_seven_pairs_shanten(counts))
+        .min(get_thirteen_orphans_shanten(counts))
+}
+
+pub fn get_standard_shanten(counts: &TileKindCounts, melds_num: usize) -> i8 {
+    let mut counts = *counts;
+    let mut best_shanten = 8;
+
+    search_standard_shanten(&mut counts, 0, melds_num, 0, false, &mut best_shanten);
+
+    best_shanten
+}
+
+pub fn get_seven_pairs_shanten(counts: &TileKindCounts) -> i8 {
+    let pairs = counts.iter().filter(|count| **count >= 2).count() as i8;
+    let kinds = counts.iter().filter(|count| **count >= 1).count() as i8;
+
+    6 - pairs + (7 - kinds).max(0)
+}
+
+pub fn get_thirteen_orphans_shanten(counts: &TileKindCounts) -> i8 {
+    let orphans_counts = counts
+        .iter()
+        .enumerate()
+        .filter(|(kind, _)| TileKind::from_index(*kind).is_terminal_or_honour())
+        .map(|(_, count)| *count)
+        .collect::<Vec<_>>();
+    let kinds = orphans_counts.iter().filter(|count| **count >= 1).count() as i8;
+    let has_pair = orphans_counts.iter().any(|count| *count >= 2);
+
+    13 - kinds - has_pair as i8
+}
+
+// The kinds that reduce the shanten when drawn
+pub fn get_acceptance(
+    counts: &TileKindCounts,
+    melds_num: usize,
+    special_shapes: bool,
+) -> Vec<TileKind> {
+    let shanten = get_shanten(counts, melds_num, special_shapes);
+    let mut counts_with_draw = *counts;
+    let special_shapes = special_shapes && melds_num == 0;
+    let kinds_num = counts.iter().filter(|count| **count >= 1).count();
+
+    (0..TILE_KINDS_NUM)
+        .filter(|kind| {
+            if counts[*kind] >= 4 || !could_improve(counts, *kind, special_shapes, kinds_num) {
+                return false;
+            }
+
+            counts_with_draw[*kind] += 1;
+            let new_shanten = get_shanten(&counts_with_draw, melds_num, special_shapes);
+            counts_with_draw[*kind] -= 1;
+
+            new_shanten < shanten
+        })
+        .map(TileKind::from_index)
+        .collect()
+}
+
+// A kind that is not close to any tile of the hand can only improve the special shapes, so most
+// of the kinds can be skipped without calculating the shanten
+fn could_improve(
+    counts: &TileKindCounts,
+    kind: usize,
+    special_shapes: bool,
+    kinds_num: usize,
+) -> bool {
+    let tile_kind = TileKind::from_index(kind);
+
+    if special_shapes && (kinds_num < 7 || tile_kind.is_terminal_or_honour()) {
+        return true;
+    }
+
+    if !tile_kind.is_suit() {
+        return counts[kind] > 0;
+    }
+
+    (kind.saturating_sub(2)..=(kind + 2).min(TILE_KINDS_NUM - 1))
+        .filter(|other| TileKind::from_index(*other).is_same_suit(&tile_kind))
+        .any(|other| counts[other] > 0)
+}
+
+fn search_standard_shanten(
+    counts: &mut TileKindCounts,
+    start_kind: usize,
+    melds: usize,
+    partials: usize,
+    has_pair: bool,
+    best_shanten: &mut i8,
+) {
+    let kind = match (start_kind..TILE_KINDS_NUM).find(|kind| counts[*kind] > 0) {
+        Some(kind) => kind,
+        None => {
+            // More than four groups can't be used, so the extra partial melds don't count
+            let partials = partials.min(4usize.saturating_sub(melds));
+            let shanten = 8 - 2 * melds as i8 - partials as i8 - has_pair as i8;
+            *best_shanten = (*best_shanten).min(shanten);
+            return;
+        }
+    };
+
+    // Each remaining tile can reduce the shanten by at most 2/3 (when it is part of a meld), so
+    // the branch can be skipped when it can't improve the best result
+    let remaining = counts[kind..].iter().map(|count| *count as i8).sum::<i8>();
+    let lowest_shanten = 8 - 2 * melds as i8 - partials as i8 - 1 - remaining * 2 / 3;
+    if lowest_shanten >= *best_shanten {
+        return;
+    }
+
+    let is_suit = kind < 27;
+    let value = kind % 9;
+
+    let mut try_group = |group_counts: &mut TileKindCounts,
+                         group: &[usize],
+                         group_melds: usize,
+                         group_partials: usize,
+                         group_has_pair: bool| {
+        if group.iter().any(|k| group_counts[*k] == 0) {
+            return;
+        }
+
+        group.iter().for_each(|k| group_counts[*k] -= 1);
+        search_standard_shanten(
+            group_counts,
+            kind,
+            group_melds,
+            group_partials,
+            group_has_pair,
+            best_shanten,
+        );
+        group.iter().for_each(|k| group_counts[*k] += 1);
+    };
+
+    if counts[kind] >= 3 {
+        try_group(counts, &[kind, kind, kind], melds + 1, partials, has_pair);
+    }
+
+    if is_suit && value <= 6 {
+        try_group(
+            counts,
+            &[kind, kind + 1, kind + 2],
+            melds + 1,
+            partials,
+            has_pair,
+        );
+    }
+
+    if counts[kind] >= 2 {
+        if !has_pair {
+            try_group(counts, &[kind, kind], melds, partials, true);
+        }
+
+        try_group(counts, &[kind, kind], melds, partials + 1, has_pair);
+    }
+
+    if is_suit && value <= 7 {
+        try_group(counts, &[kind, kind + 1], melds, partials + 1, has_pair);
+    }
+
+    if is_suit && value <= 6 {
+        try_group(counts, &[kind, kind + 2], melds, partials + 1, has_pair);
+    }
+
+    // The tile is not used in any group
+    try_group(counts, &[kind], melds, partials, has_pair);
+}
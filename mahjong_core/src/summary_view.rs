@@ -8,70 +8,16 @@ use uuid::Uuid;
 use crate::{
     deck::DEFAULT_DECK,
     hand::{HandPossibleMeld, KongTile, SetIdContent},
+    notation,
+    render::{parse_tile, render_tile, TileFormat},
     round::{Round, RoundTileClaimed},
     score::ScoringRule,
     table::{BonusTiles, PositionTilesOpts},
-    Board, Deck, Dragon, DragonTile, DrawWall, Flower, FlowerTile, Game, GamePhase, Hand, HandTile,
-    Hands, Season, SeasonTile, Suit, SuitTile, Tile, TileId, Wind, WindTile,
+    Board, DrawWall, Game, GamePhase, Hand, HandTile, Hands, Tile, TileId, Wind,
 };
 
 pub fn print_game_tile(tile: &Tile) -> String {
-    let mut result = String::new();
-
-    match tile {
-        Tile::Dragon(tile) => {
-            let dragon_letter = match tile.value {
-                Dragon::Red => '中',
-                Dragon::Green => '發',
-                Dragon::White => '白',
-            };
-            result.push(dragon_letter);
-        }
-        Tile::Wind(tile) => {
-            let wind_letter = tile.value.to_string();
-            result.push_str(&wind_letter);
-        }
-        Tile::Flower(tile) => {
-            let flower_letter = match tile.value {
-                Flower::Plum => '梅',
-                Flower::Orchid => '蘭',
-                Flower::Chrysanthemum => '菊',
-                Flower::Bamboo => '竹',
-            };
-            result.push(flower_letter);
-        }
-        Tile::Season(tile) => {
-            let season_letter = match tile.value {
-                Season::Spring => '春',
-                Season::Summer => '夏',
-                Season::Autumn => '秋',
-                Season::Winter => '冬',
-            };
-            result.push(season_letter);
-        }
-        Tile::Suit(tile) => {
-            let value_str = match tile.value {
-                1 => '一',
-                2 => '二',
-                3 => '三',
-                4 => '四',
-                5 => '五',
-                6 => '六',
-                7 => '七',
-                8 => '八',
-                9 => '九',
-                _ => panic!("Invalid value"),
-            };
-            let suit_letter = match tile.suit {
-                Suit::Bamboo => '索',
-                Suit::Dots => '筒',
-                Suit::Characters => '萬',
-            };
-            result.push_str(&format!("{:}{:}", value_str, suit_letter));
-        }
-    }
-
-    result
+    render_tile(tile, TileFormat::Chinese)
 }
 
 impl FromStr for GamePhase {
@@ -343,65 +289,17 @@ impl Game {
 
 impl Tile {
     pub fn from_summary(summary: &str) -> Self {
-        let first_char = summary.chars().nth(0).unwrap();
-        let tile = match first_char {
-            '一' | '二' | '三' | '四' | '五' | '六' | '七' | '八' | '九' => {
-                let value = match first_char {
-                    '一' => 1,
-                    '二' => 2,
-                    '三' => 3,
-                    '四' => 4,
-                    '五' => 5,
-                    '六' => 6,
-                    '七' => 7,
-                    '八' => 8,
-                    '九' => 9,
-                    _ => panic!("Invalid value"),
-                };
-                let suit = match summary.chars().nth(1).unwrap() {
-                    '索' => Suit::Bamboo,
-                    '筒' => Suit::Dots,
-                    '萬' => Suit::Characters,
-                    _ => panic!("Invalid suit"),
-                };
-                Self::Suit(SuitTile { id: 0, value, suit })
-            }
-            '東' | '南' | '西' | '北' => {
-                let value = Wind::from_str(&first_char.to_string()).unwrap();
-                Self::Wind(WindTile { id: 0, value })
-            }
-            '中' | '發' | '白' => {
-                let value = match first_char {
-                    '中' => Dragon::Red,
-                    '發' => Dragon::Green,
-                    '白' => Dragon::White,
-                    _ => panic!("Invalid dragon"),
-                };
-                Self::Dragon(DragonTile { id: 0, value })
-            }
-            '梅' | '蘭' | '菊' | '竹' => {
-                let value = match first_char {
-                    '梅' => Flower::Plum,
-                    '蘭' => Flower::Orchid,
-                    '菊' => Flower::Chrysanthemum,
-                    '竹' => Flower::Bamboo,
-                    _ => panic!("Invalid flower"),
-                };
-                Self::Flower(FlowerTile { id: 0, value })
-            }
-            '春' | '夏' | '秋' | '冬' => {
-                let value = match first_char {
-                    '春' => Season::Spring,
-                    '夏' => Season::Summer,
-                    '秋' => Season::Autumn,
-                    '冬' => Season::Winter,
-                    _ => panic!("Invalid season"),
-                };
-                Self::Season(SeasonTile { id: 0, value })
-            }
-            _ => panic!("Invalid summary: {summary}"),
-        };
-        Deck::find_tile_without_id(tile)
+        if notation::is_notation(summary) {
+            let tiles = notation::parse_tiles(summary).unwrap();
+            return DEFAULT_DECK.get_sure(tiles[0]).clone();
+        }
+
+        // Only the first characters are used, since there can be others after the tile (e.g. `...`)
+        let get_prefix = |len: usize| summary.trim().chars().take(len).collect::<String>();
+
+        parse_tile(&get_prefix(2), TileFormat::Chinese)
+            .or_else(|| parse_tile(&get_prefix(1), TileFormat::Chinese))
+            .unwrap_or_else(|| panic!("Invalid summary: {summary}"))
     }
 
     pub fn summary_from_ids(ids: &[TileId]) -> String {
@@ -416,6 +314,10 @@ impl Tile {
     }
 
     pub fn ids_from_summary(summary: &str) -> Vec<TileId> {
+        if notation::is_notation(summary) {
+            return notation::parse_tiles(summary).unwrap();
+        }
+
         summary
             .split(',')
             .filter(|tile| !tile.is_empty())
@@ -468,6 +370,10 @@ impl HandTile {
 
 impl Hand {
     pub fn from_summary(summary: &str) -> Self {
+        if notation::is_notation(summary) {
+            return Self::from_notation(summary).unwrap();
+        }
+
         let mut hand = Self::new(
             summary
                 .split(' ')
@@ -619,6 +525,11 @@ impl Board {
 
 impl Board {
     pub fn push_by_summary(&mut self, summary: &str) {
+        if notation::is_notation(summary) {
+            self.0.extend(notation::parse_tiles(summary).unwrap());
+            return;
+        }
+
         summary
             .split(',')
             .filter(|tile| !tile.is_empty())
@@ -649,6 +560,12 @@ impl DrawWall {
 
 impl BonusTiles {
     pub fn set_from_summary(&mut self, player_id: &str, summary: &str) {
+        if notation::is_notation(summary) {
+            let parsed = notation::parse_hand(summary).unwrap();
+            self.0.insert(player_id.to_string(), parsed.bonus_tiles);
+            return;
+        }
+
         self.0.insert(
             player_id.to_string(),
             summary
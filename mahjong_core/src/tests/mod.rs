@@ -1,10 +1,16 @@
 mod test_ai;
+mod test_danger;
 mod test_deck;
 mod test_game;
 mod test_game_summary;
 mod test_hand;
 mod test_meld;
+mod test_notation;
+mod test_render;
 mod test_round;
+mod test_ruleset;
 mod test_score;
+mod test_shanten;
 mod test_table;
+mod test_tile_kind;
 mod utils;
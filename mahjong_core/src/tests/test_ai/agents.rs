@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        ai::{
+            AIDifficulty, AIProfile, AIStyle, AgentKind, MahjongAgent, PlayExitLocation, StandardAI,
+        },
+        game_summary::GameSummary,
+        meld::PossibleMeld,
+        Game, TileId,
+    };
+    use pretty_assertions::assert_eq;
+    use rustc_hash::FxHashSet;
+    use strum::IntoEnumIterator;
+
+    // Never declares melds and discards the last tile of the hand
+    struct PassiveAgent;
+
+    impl MahjongAgent for PassiveAgent {
+        fn choose_melds(&mut self, _: &GameSummary, _: Vec<PossibleMeld>) -> Vec<PossibleMeld> {
+            vec![]
+        }
+
+        fn choose_discards(&mut self, observation: &GameSummary) -> Vec<TileId> {
+            let hand = observation.hand.as_ref().unwrap();
+
+            vec![hand.list.last().unwrap().id]
+        }
+    }
+
+    fn play_with_agent(agent: Option<Box<dyn MahjongAgent>>) -> (PlayExitLocation, String) {
+        let mut game = Game::from_summary(
+            "- P1: 一萬,一萬,一萬,三萬,五萬,七萬,九萬,一筒,三筒,五筒,七筒,九筒,一索,三索
+             Turn: P1, Phase: Playing",
+        );
+        let ai_players = FxHashSet::from_iter(game.players.0.clone());
+        let player_id = game.players.0[0].clone();
+        let mut game_ai = StandardAI::new(&mut game, ai_players, FxHashSet::default());
+
+        if let Some(agent) = agent {
+            game_ai.set_agent(&player_id, agent);
+        }
+
+        let result = game_ai.play_action(false);
+
+        (result.exit_location, game.table.board.to_summary())
+    }
+
+    #[test]
+    fn test_agent_decides_melds() {
+        assert_eq!(
+            play_with_agent(None),
+            (PlayExitLocation::MeldCreated, "".to_string())
+        );
+        assert_eq!(
+            play_with_agent(Some(Box::new(PassiveAgent))),
+            (PlayExitLocation::TileDiscarded, "三索".to_string())
+        );
+    }
+
+    #[test]
+    fn test_agents_discard_from_hand() {
+        for agent_kind in AgentKind::iter() {
+            let mut game = Game::from_summary(
+                "- P1: 147m258p369s12345z
+                 Turn: P1, Phase: Playing",
+            );
+            let player_id = game.players.0[0].clone();
+            let game_summary = GameSummary::from_game(&game, &player_id).unwrap();
+            let hand = game_summary.hand.clone().unwrap();
+            let discards = agent_kind.create_agent().choose_discards(&game_summary);
+
+            assert_eq!(discards.len(), 14, "{agent_kind:?}");
+            assert!(discards.iter().all(|tile| hand.get_has_tile(tile)));
+
+            let ai_players = FxHashSet::from_iter(game.players.0.clone());
+            let mut game_ai = StandardAI::new(&mut game, ai_players, FxHashSet::default());
+            game_ai.set_agent(&player_id, agent_kind.create_agent());
+
+            assert_eq!(
+                game_ai.play_action(false).exit_location,
+                PlayExitLocation::TileDiscarded
+            );
+        }
+    }
+
+    #[test]
+    fn test_profiles_discard_from_hand() {
+        let game = Game::from_summary(
+            "- P1: 147m258p369s12345z
+             Turn: P1, Phase: Playing",
+        );
+        let player_id = game.players.0[0].clone();
+        let game_summary = GameSummary::from_game(&game, &player_id).unwrap();
+        let hand = game_summary.hand.clone().unwrap();
+
+        for difficulty in AIDifficulty::iter() {
+            for style in AIStyle::iter() {
+                let profile = AIProfile { difficulty, style };
+                let discards = profile.create_agent().choose_discards(&game_summary);
+
+                assert_eq!(discards.len(), 14, "{profile}");
+                assert!(discards.iter().all(|tile| hand.get_has_tile(tile)));
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod test {
+    use crate::{ai::get_best_drops, game_summary::GameSummary, Game, Tile, TileKind};
+    use pretty_assertions::assert_eq;
+
+    fn get_drops_kinds(summary: &str) -> Option<Vec<TileKind>> {
+        let game = Game::from_summary(summary);
+        let game_summary = GameSummary::from_game(&game, &game.players.0[0]).unwrap();
+
+        get_best_drops(&game_summary)
+            .map(|drops| drops.into_iter().map(TileKind::from_id).collect())
+    }
+
+    fn get_kind(summary: &str) -> TileKind {
+        TileKind::from_id(Tile::id_from_summary(summary))
+    }
+
+    #[test]
+    fn test_get_best_drops_minimises_shanten() {
+        let drops = get_drops_kinds(
+            "- P1: 123m56m456p789s11z3z
+             Turn: P1, Phase: Playing",
+        )
+        .unwrap();
+
+        assert_eq!(drops[0], get_kind("3z"));
+        assert_eq!(drops.len(), 13);
+    }
+
+    #[test]
+    fn test_get_best_drops_keeps_faan_tiles() {
+        let drops = get_drops_kinds(
+            "- P1: 123m456p789s22z77z3z
+             Turn: P1, Phase: Playing",
+        )
+        .unwrap();
+        let get_position = |kind: TileKind| drops.iter().position(|k| *k == kind).unwrap();
+
+        assert_eq!(drops[0], get_kind("3z"));
+        // The red dragons can give faan, unlike the south wind for the east player
+        assert!(get_position(get_kind("2z")) < get_position(get_kind("7z")));
+    }
+
+    #[test]
+    fn test_get_best_drops_only_keeps_the_scored_tiles() {
+        let drops = get_drops_kinds(
+            "- P1: 123m456p789s11z77z3z
+             Turn: P1, Phase: Playing",
+        )
+        .unwrap();
+        let get_position = |kind: TileKind| drops.iter().position(|k| *k == kind).unwrap();
+
+        // The seat and round wind doesn't give faan in the scoring rules, unlike the red dragons
+        assert!(get_position(get_kind("1z")) < get_position(get_kind("7z")));
+    }
+
+    #[test]
+    fn test_get_best_drops_without_extra_tile() {
+        let drops = get_drops_kinds(
+            "- P1: 123m456p789s22z77z
+             Turn: P1, Phase: Playing",
+        );
+
+        assert_eq!(drops, None);
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        ai::{get_best_melds, get_scored_melds, ScoredMeld},
+        game_summary::GameSummary,
+        meld::MeldType,
+        Game, Tile,
+    };
+
+    fn get_scored_player_melds(summary: &str, player_id: &str) -> Vec<ScoredMeld> {
+        let game = Game::from_summary(summary);
+        let player_id = player_id.to_string();
+        let game_summary = GameSummary::from_game(&game, &player_id).unwrap();
+        let melds = game.get_possible_melds_for_player(&player_id, false);
+
+        get_scored_melds(&game_summary, melds)
+    }
+
+    // The melds of the second player with the tile discarded by the first one
+    fn get_scored_claims(hand: &str, discarded: &str) -> Vec<ScoredMeld> {
+        let discarded_tile = Tile::id_from_summary(discarded);
+
+        get_scored_player_melds(
+            &format!(
+                "- P2: {hand}
+                 Board: {discarded}
+                 Turn: P1, Phase: Playing
+                 Discarded: {discarded}"
+            ),
+            "1",
+        )
+        .into_iter()
+        .filter(|scored_meld| scored_meld.meld.tiles.contains(&discarded_tile))
+        .collect()
+    }
+
+    fn get_meld_type(scored_meld: &ScoredMeld) -> Option<MeldType> {
+        MeldType::from_tiles(&scored_meld.meld.tiles)
+    }
+
+    #[test]
+    fn test_claims_that_improve_the_hand() {
+        let claims = get_scored_claims("13p89p123m456m789m", "2p");
+
+        assert_eq!(claims.len(), 1);
+        assert!(claims[0].is_worth);
+        assert_eq!(claims[0].shanten, 0);
+    }
+
+    #[test]
+    fn test_refuses_claims_that_move_away_from_ready() {
+        // The chow would break the pair of the ready hand
+        let claims = get_scored_claims("44567m234p567p89s", "3m");
+
+        assert!(!claims.is_empty());
+        assert!(claims
+            .iter()
+            .all(|scored_meld| !scored_meld.is_worth && scored_meld.shanten == 1));
+    }
+
+    #[test]
+    fn test_refuses_kongs_that_break_the_hand() {
+        // The fourth tile is part of the wait of the ready hand
+        let scored_melds = get_scored_player_melds(
+            "- P1: 22223m567p789s11z9p
+             Turn: P1, Phase: Playing",
+            "0",
+        );
+        let kong = scored_melds
+            .iter()
+            .find(|scored_meld| get_meld_type(scored_meld) == Some(MeldType::Kong))
+            .unwrap();
+        let pung = scored_melds
+            .iter()
+            .find(|scored_meld| get_meld_type(scored_meld) == Some(MeldType::Pung))
+            .unwrap();
+
+        assert!(!kong.is_worth);
+        // Setting aside the concealed pung is still possible
+        assert!(pung.is_worth);
+    }
+
+    #[test]
+    fn test_accepts_kongs_with_an_unused_tile() {
+        let game = Game::from_summary(
+            "- P1: 9999m123p567p789s1z
+             Turn: P1, Phase: Playing",
+        );
+        let player_id = game.players.0[0].clone();
+        let game_summary = GameSummary::from_game(&game, &player_id).unwrap();
+        let melds = game.get_possible_melds_for_player(&player_id, false);
+        let best_melds = get_best_melds(&game_summary, melds);
+
+        // The kong goes first for the replacement tile
+        assert_eq!(
+            MeldType::from_tiles(&best_melds[0].tiles),
+            Some(MeldType::Kong)
+        );
+    }
+}
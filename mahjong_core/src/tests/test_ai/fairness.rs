@@ -0,0 +1,227 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        ai::{
+            AgentAction, AgentActionError, ChoiceSource, MahjongAgent, PlayExitLocation,
+            ShantenAgent, StandardAI,
+        },
+        game_summary::GameSummary,
+        meld::PossibleMeld,
+        table::DrawWall,
+        Game, GamePhase, TileId,
+    };
+    use pretty_assertions::assert_eq;
+    use rustc_hash::FxHashSet;
+
+    // Tries to win with any tiles and to discard the tiles that it doesn't have
+    struct CheatingAgent;
+
+    impl MahjongAgent for CheatingAgent {
+        fn choose_melds(
+            &mut self,
+            observation: &GameSummary,
+            melds: Vec<PossibleMeld>,
+        ) -> Vec<PossibleMeld> {
+            melds
+                .into_iter()
+                .map(|meld| PossibleMeld {
+                    is_mahjong: true,
+                    player_id: observation.players.0[1].clone(),
+                    ..meld
+                })
+                .collect()
+        }
+
+        fn choose_discards(&mut self, observation: &GameSummary) -> Vec<TileId> {
+            let hand = observation.hand.as_ref().unwrap();
+
+            (0..144).filter(|tile| !hand.get_has_tile(tile)).collect()
+        }
+    }
+
+    #[test]
+    fn test_observation_hides_the_wall_and_the_hands() {
+        let game = Game::from_summary(
+            "- P1: 1234m
+             - P2: 5678m
+             - P3: 1234p
+             Turn: P1, Phase: Playing",
+        );
+        let mut other_game = game.clone();
+        let get_observation = |observed_game: &Game| {
+            serde_json::to_string(
+                &GameSummary::from_game(observed_game, &observed_game.players.0[0]).unwrap(),
+            )
+            .unwrap()
+        };
+
+        let (player_2, player_3) = (&game.players.0[1], &game.players.0[2]);
+        let hand_2 = game.table.hands.get(player_2).unwrap();
+        let hand_3 = game.table.hands.get(player_3).unwrap();
+        other_game.table.hands.insert(player_2, hand_3);
+        other_game.table.hands.insert(player_3, hand_2);
+
+        assert_eq!(get_observation(&game), get_observation(&other_game));
+
+        let mut playing_game = Game::new(None);
+        playing_game.play_until_middle_of_round();
+        let mut reversed_game = playing_game.clone();
+        let mut wall_tiles = vec![];
+        playing_game
+            .table
+            .draw_wall
+            .iter_all(&mut wall_tiles)
+            .count();
+        wall_tiles.reverse();
+        reversed_game.table.draw_wall = DrawWall::new_full(wall_tiles);
+
+        assert_ne!(
+            playing_game
+                .table
+                .draw_wall
+                .get_next(&playing_game.round.wind),
+            reversed_game
+                .table
+                .draw_wall
+                .get_next(&playing_game.round.wind)
+        );
+        assert_eq!(
+            get_observation(&playing_game),
+            get_observation(&reversed_game)
+        );
+    }
+
+    #[test]
+    fn test_validate_action() {
+        let mut game = Game::from_summary(
+            "- P1: 一萬,一萬,一萬,三萬,五萬,七萬,九萬,一筒,三筒,五筒,七筒,九筒,一索,三索
+             - P2: 二萬
+             Turn: P1, Phase: Playing",
+        );
+        let player_1 = game.players.0[0].clone();
+        let player_2 = game.players.0[1].clone();
+        let possible_melds = game.get_possible_melds_for_player(&player_1, false);
+        let hand_1 = game.table.hands.get(&player_1).unwrap();
+        let tile_2 = game.table.hands.get(&player_2).unwrap().list[0].id;
+        let game_ai = StandardAI::new(&mut game, FxHashSet::default(), FxHashSet::default());
+        let validate =
+            |player_id, action| game_ai.validate_action(player_id, &action, &possible_melds);
+
+        assert_eq!(
+            validate(&player_1, AgentAction::Meld(possible_melds[0].clone())),
+            Ok(())
+        );
+        assert_eq!(
+            validate(&player_2, AgentAction::Meld(possible_melds[0].clone())),
+            Err(AgentActionError::WrongPlayer)
+        );
+        assert_eq!(
+            validate(
+                &player_1,
+                AgentAction::Meld(PossibleMeld {
+                    is_mahjong: true,
+                    ..possible_melds[0].clone()
+                })
+            ),
+            Err(AgentActionError::MeldNotPossible)
+        );
+        assert_eq!(
+            validate(&player_1, AgentAction::Discard(hand_1.list[0].id)),
+            Ok(())
+        );
+        assert_eq!(
+            validate(&player_1, AgentAction::Discard(tile_2)),
+            Err(AgentActionError::TileNotInHand)
+        );
+        assert_eq!(
+            validate(&player_2, AgentAction::Discard(tile_2)),
+            Err(AgentActionError::NotPlayerTurn)
+        );
+    }
+
+    #[test]
+    fn test_driver_ignores_invalid_actions() {
+        let mut game = Game::from_summary(
+            "- P1: 一萬,一萬,一萬,三萬,五萬,七萬,九萬,一筒,三筒,五筒,七筒,九筒,一索,三索
+             Turn: P1, Phase: Playing",
+        );
+        let ai_players = FxHashSet::from_iter(game.players.0.clone());
+        let player_id = game.players.0[0].clone();
+        let hand = game.table.hands.get(&player_id).unwrap();
+        let mut game_ai = StandardAI::new(&mut game, ai_players, FxHashSet::default());
+        game_ai.set_agent(&player_id, Box::new(CheatingAgent));
+
+        assert_eq!(
+            game_ai.play_action(false).exit_location,
+            PlayExitLocation::TileDiscarded
+        );
+        assert_eq!(game.phase, GamePhase::Playing);
+        assert!(hand.get_has_tile(&game.table.board.0[0]));
+    }
+
+    #[test]
+    fn test_metadata_records_the_decisions() {
+        let mut game = Game::from_summary(
+            "- P1: 一萬,一萬,一萬,三萬,五萬,七萬,九萬,一筒,三筒,五筒,七筒,九筒,一索,三索
+             Turn: P1, Phase: Playing",
+        );
+        let ai_players = FxHashSet::from_iter(game.players.0.clone());
+        let player_id = game.players.0[0].clone();
+        let mut game_ai = StandardAI::new(&mut game, ai_players.clone(), FxHashSet::default());
+        game_ai.set_agent(&player_id, Box::new(CheatingAgent));
+
+        let result = game_ai.play_action(true);
+        let metadata = result.metadata.unwrap();
+
+        assert_eq!(
+            metadata.chosen,
+            Some(AgentAction::Discard(game.table.board.0[0]))
+        );
+        assert_eq!(metadata.chosen_source, Some(ChoiceSource::RandomFallback));
+        assert!(!metadata.candidates.is_empty());
+        assert!(metadata
+            .candidates
+            .iter()
+            .all(|candidate| candidate.player_id == player_id && candidate.score.is_none()));
+        assert_eq!(metadata.rejected.len(), metadata.candidates.len());
+        assert!(metadata.rejected.iter().all(|rejected| matches!(
+            (&rejected.action, &rejected.error),
+            (AgentAction::Meld(_), AgentActionError::WrongPlayer)
+                | (AgentAction::Discard(_), AgentActionError::TileNotInHand)
+        )));
+
+        let mut game_ai_without_metadata =
+            StandardAI::new(&mut game, ai_players, FxHashSet::default());
+
+        assert_eq!(game_ai_without_metadata.play_action(false).metadata, None);
+    }
+
+    #[test]
+    fn test_metadata_has_the_scores_of_the_agent() {
+        let mut game = Game::from_summary(
+            "- P1: 一萬,一萬,三萬,五萬,七萬,九萬,一筒,三筒,五筒,七筒,九筒,一索,三索,五索
+             Turn: P1, Phase: Playing",
+        );
+        let player_id = game.players.0[0].clone();
+        let mut agent = ShantenAgent::default();
+        let observation = GameSummary::from_game(&game, &player_id).unwrap();
+        let discards = agent.choose_discards(&observation);
+        let scores = agent.explain();
+        let ai_players = FxHashSet::from_iter(game.players.0.clone());
+        let mut game_ai = StandardAI::new(&mut game, ai_players, FxHashSet::default());
+        game_ai.set_agent(&player_id, Box::new(ShantenAgent::default()));
+
+        let metadata = game_ai.play_action(true).metadata.unwrap();
+
+        assert_eq!(metadata.chosen, Some(AgentAction::Discard(discards[0])));
+        assert_eq!(metadata.chosen_source, Some(ChoiceSource::Agent));
+        assert_eq!(
+            metadata
+                .candidates
+                .iter()
+                .map(|candidate| (candidate.action.clone(), candidate.score.unwrap()))
+                .collect::<Vec<_>>(),
+            scores
+        );
+    }
+}
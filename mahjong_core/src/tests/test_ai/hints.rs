@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        ai::{get_hints, Hint, HintAction, HintReason},
+        game_summary::GameSummary,
+        Game, Tile,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn get_player_hints(summary: &str, player_id: &str) -> Vec<Hint> {
+        let game = Game::from_summary(summary);
+        let game_summary = GameSummary::from_game(&game, &player_id.to_string()).unwrap();
+
+        get_hints(&game_summary)
+    }
+
+    // The hints of the second player after the first one discarded the tile
+    fn get_claim_hints(hand: &str, discarded: &str) -> Vec<Hint> {
+        get_player_hints(
+            &format!(
+                "- P2: {hand}
+                 Board: {discarded}
+                 Turn: P1, Phase: Playing
+                 Discarded: {discarded}"
+            ),
+            "1",
+        )
+    }
+
+    #[test]
+    fn test_hints_of_the_discards() {
+        let hints = get_player_hints(
+            "- P1: 123m456p789s1122z5s
+             Turn: P1, Phase: Playing",
+            "0",
+        );
+
+        assert_eq!(
+            hints[0].action,
+            HintAction::Discard {
+                tile: Tile::id_from_summary("5s")
+            }
+        );
+        assert_eq!(hints[0].reason, HintReason::KeepsShanten);
+        assert_eq!((hints[0].shanten, hints[0].acceptance), (0, 4));
+        assert!(hints
+            .iter()
+            .all(|hint| matches!(hint.action, HintAction::Discard { .. })));
+        assert!(hints
+            .iter()
+            .any(|hint| hint.reason == HintReason::MovesAwayFromReady));
+    }
+
+    #[test]
+    fn test_hints_of_the_claims() {
+        let hints = get_claim_hints("13p89p123m456m789m", "2p");
+
+        assert!(matches!(hints[0].action, HintAction::Claim { .. }));
+        assert_eq!(hints[0].reason, HintReason::ReducesShanten);
+        assert_eq!(hints[0].shanten, 0);
+
+        // The chow would break the pair of the ready hand
+        let other_hints = get_claim_hints("44567m234p567p89s", "3m");
+
+        assert_eq!(other_hints[0].action, HintAction::Pass);
+        assert_eq!(other_hints[0].reason, HintReason::NoGoodClaim);
+        assert_eq!(other_hints[0].shanten, 0);
+        assert!(other_hints[1..]
+            .iter()
+            .all(|hint| hint.reason == HintReason::MovesAwayFromReady));
+    }
+
+    #[test]
+    fn test_hints_of_the_mahjong() {
+        let hints = get_player_hints(
+            "- P1: 123m456p789s11z222z
+             Turn: P1, Phase: Playing",
+            "0",
+        );
+
+        // The melds are declared before the mahjong
+        assert!(matches!(hints[0].action, HintAction::Meld { .. }));
+        assert_eq!(hints[0].reason, HintReason::Mahjong);
+
+        let mahjong_hints = get_player_hints(
+            "- P1: 11z [123m] [456p] [789s] [222z]
+             Turn: P1, Phase: Playing",
+            "0",
+        );
+
+        assert_eq!(mahjong_hints[0].action, HintAction::Mahjong);
+        assert_eq!(mahjong_hints[0].reason, HintReason::Mahjong);
+    }
+}
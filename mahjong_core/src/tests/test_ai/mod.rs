@@ -1,2 +1,9 @@
+mod agents;
+mod best_drops;
+mod best_melds;
+mod fairness;
+mod hints;
 mod play_action;
+mod review;
+mod search;
 mod sort_by;
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        ai::{
+            get_hints, review_decision, review_game, GameDecision, HintAction, SearchAgent,
+            SearchBudget,
+        },
+        game_summary::GameSummary,
+        score::Score,
+        Game, Tile, Wind,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn create_evaluator() -> SearchAgent {
+        SearchAgent::new(SearchBudget {
+            iterations: 16,
+            time_ms: None,
+        })
+        .with_seed(1)
+    }
+
+    // The other players and the wall are needed for the playouts
+    fn get_ready_game() -> Game {
+        let mut game = Game::from_summary(
+            "- P1: 123m456p789s1122z7z
+             - P2: 2468m2468p2468s3z
+             - P3: 3579m3579p3579s4z
+             - P4: 1357m1357p1357s5z
+             Wall: Random
+             Turn: P1, Phase: Playing",
+        );
+        game.score = Score::new(&game.players.0);
+
+        game
+    }
+
+    fn get_discard_decision(game: &Game, tile: &str) -> GameDecision {
+        let action = HintAction::Discard {
+            tile: Tile::id_from_summary(tile),
+        };
+
+        GameDecision::from_game(game, &game.players.0[0], action).unwrap()
+    }
+
+    #[test]
+    fn test_review_flags_breaking_the_ready_hand() {
+        let game = get_ready_game();
+        let decision = get_discard_decision(&game, "1m");
+        let mistake = review_decision(&decision, &mut create_evaluator()).unwrap();
+
+        assert_eq!(
+            mistake.recommended,
+            HintAction::Discard {
+                tile: Tile::id_from_summary("7z")
+            }
+        );
+        assert_eq!(mistake.action, decision.action);
+        assert_eq!((mistake.round_wind, mistake.turn), (Wind::East, 1));
+        assert!(mistake.estimated_loss >= 0.25);
+    }
+
+    #[test]
+    fn test_review_of_the_recommended_actions() {
+        let game = get_ready_game();
+        let decisions = vec![
+            get_discard_decision(&game, "7z"),
+            get_discard_decision(&game, "1m"),
+        ];
+        let review = review_game(&decisions, &mut create_evaluator());
+
+        assert_eq!(review.decisions, 2);
+        assert_eq!(review.mistakes.len(), 1);
+        assert_eq!(review.mistakes[0].action, decisions[1].action);
+    }
+
+    #[test]
+    fn test_review_decision_of_a_claim() {
+        let mut game = Game::from_summary(
+            "- P1: 2468m2468p2468s3z
+             - P2: 13p99p [123m] [456m] [789m]
+             - P3: 3579m3579p3579s4z
+             - P4: 1357m1357s1z2z3z4z5z
+             Wall: Random
+             Board: 2p
+             Turn: P1, Phase: Playing
+             Discarded: 2p",
+        );
+        game.score = Score::new(&game.players.0);
+        let player_id = game.players.0[1].clone();
+        let hints = get_hints(&GameSummary::from_game(&game, &player_id).unwrap());
+        let pass_decision = GameDecision::from_game(&game, &player_id, HintAction::Pass).unwrap();
+
+        assert!(game.claim_tile(&player_id));
+        let HintAction::Claim { meld } = &hints[0].action else {
+            panic!("The claim is not recommended");
+        };
+        // In a different order than the hints, like the tiles sent by the client
+        let mut meld_tiles = meld.tiles.clone();
+        meld_tiles.reverse();
+        let decision = GameDecision::from_claimed_meld(&game, &player_id, &meld_tiles).unwrap();
+
+        assert_eq!(decision.action, hints[0].action);
+        // What the player could see before claiming the tile
+        assert_eq!(
+            decision.game_summary.board.0.last(),
+            Some(&Tile::id_from_summary("2p"))
+        );
+        assert_eq!(decision.game_summary.hand.as_ref().unwrap().len(), 13);
+        assert!(review_decision(&decision, &mut create_evaluator()).is_none());
+
+        let mistake = review_decision(&pass_decision, &mut create_evaluator()).unwrap();
+
+        assert_eq!(mistake.recommended, decision.action);
+    }
+}
@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        ai::{
+            get_best_drops, get_scored_drops, AgentAction, HintAction, MahjongAgent, SearchAgent,
+            SearchBudget, TileDrop,
+        },
+        game_summary::GameSummary,
+        Game, Tile, TileId, TileKind,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn create_agent() -> SearchAgent {
+        SearchAgent::new(SearchBudget {
+            iterations: 4,
+            time_ms: None,
+        })
+        .with_seed(1)
+    }
+
+    fn get_other_drops(drops: &[TileDrop], candidates: &[TileId]) -> Vec<TileId> {
+        drops
+            .iter()
+            .map(|drop| drop.id)
+            .filter(|tile| !candidates.contains(tile))
+            .collect()
+    }
+
+    #[test]
+    fn test_search_agent_keeps_the_ready_hand() {
+        let game = Game::from_summary(
+            "- P1: 123m456p789s1122z7z
+             Turn: P1, Phase: Playing",
+        );
+        let game_summary = GameSummary::from_game(&game, &game.players.0[0]).unwrap();
+        let discards = create_agent().choose_discards(&game_summary);
+
+        assert_eq!(
+            TileKind::from_id(discards[0]),
+            TileKind::from_id(Tile::id_from_summary("7z"))
+        );
+        // The only discard that keeps the hand ready, so there is nothing to compare
+        assert_eq!(discards, get_best_drops(&game_summary).unwrap());
+    }
+
+    #[test]
+    fn test_search_agent_reorders_the_best_drops() {
+        let mut game = Game::new(None);
+        game.play_until_middle_of_round();
+        let player_id = game.get_current_player().unwrap();
+        let game_summary = GameSummary::from_game(&game, &player_id).unwrap();
+        let mut agent = create_agent();
+        let discards = agent.choose_discards(&game_summary);
+        let drops = get_scored_drops(&game_summary).unwrap();
+
+        let mut sorted_discards = discards.clone();
+        sorted_discards.sort();
+        let mut sorted_drops = drops.iter().map(|drop| drop.id).collect::<Vec<TileId>>();
+        sorted_drops.sort();
+        assert_eq!(sorted_discards, sorted_drops);
+
+        // Only the best drops with the lowest shanten are compared
+        let candidates = drops
+            .iter()
+            .take(agent.candidates)
+            .filter(|drop| drop.shanten == drops[0].shanten)
+            .map(|drop| drop.id)
+            .collect::<Vec<TileId>>();
+        assert!(candidates.contains(&discards[0]));
+        assert_eq!(
+            discards[candidates.len()..],
+            get_other_drops(&drops, &candidates)
+        );
+    }
+
+    #[test]
+    fn test_search_agent_evaluates_the_failed_actions_as_the_worst() {
+        let game = Game::from_summary(
+            "- P1: 123m456p789s1122z7z
+             Turn: P1, Phase: Playing",
+        );
+        let game_summary = GameSummary::from_game(&game, &game.players.0[0]).unwrap();
+        let actions = [
+            HintAction::Discard {
+                tile: Tile::id_from_summary("7z"),
+            },
+            // Not in the hand, so it can't be discarded in any playout
+            HintAction::Discard {
+                tile: Tile::id_from_summary("9m"),
+            },
+        ];
+
+        let values = create_agent().evaluate_actions(&game_summary, &actions);
+
+        assert!(values[0].is_finite());
+        assert_eq!(values[1], f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_search_agent_compares_the_claims_with_passing() {
+        let game = Game::from_summary(
+            "- P2: 13p89p123m456m789m
+             Board: 2p
+             Turn: P1, Phase: Playing
+             Discarded: 2p",
+        );
+        let player_id = game.players.0[1].clone();
+        let game_summary = GameSummary::from_game(&game, &player_id).unwrap();
+        let melds = game.get_possible_melds_for_player(&player_id, false);
+        let claims = melds
+            .iter()
+            .filter(|meld| meld.tiles.contains(&Tile::id_from_summary("2p")))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut agent = create_agent();
+
+        let chosen = agent.choose_melds(&game_summary, melds);
+        let scores = agent.explain();
+
+        assert_eq!(claims.len(), 1);
+        assert_eq!(scores.len(), 1);
+        assert_eq!(scores[0].0, AgentAction::Meld(claims[0].clone()));
+        // The claim is only chosen when it wins more points than passing
+        if chosen.is_empty() {
+            assert!(scores[0].1 >= 0);
+        } else {
+            assert_eq!(chosen, claims);
+            assert!(scores[0].1 <= 0);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        ai::{get_scored_drops, get_scored_drops_for_profile, AIProfile, AIStyle},
+        game_summary::GameSummary,
+        round::RoundDiscard,
+        Game, Tile, TileKind,
+    };
+    use pretty_assertions::assert_eq;
+
+    // The second player has three exposed melds and discarded some tiles
+    fn get_game_summary(hand: &str) -> GameSummary {
+        let mut game = Game::from_summary(&format!(
+            "- P1: {hand}
+             - P2: 2468m [777p] [888p] [999p]
+             Board: 111z
+             Turn: P1, Phase: Playing"
+        ));
+        game.round.discards = ["5m", "1s", "9s", "4z"]
+            .iter()
+            .map(|tile| RoundDiscard {
+                claimed_by: None,
+                player_id: "1".to_string(),
+                tile: Tile::id_from_summary(tile),
+            })
+            .collect();
+
+        GameSummary::from_game(&game, &"0".to_string()).unwrap()
+    }
+
+    fn get_danger(game_summary: &GameSummary, tile: &str) -> u32 {
+        let kind = TileKind::from_id(Tile::id_from_summary(tile));
+
+        game_summary
+            .get_tiles_danger()
+            .into_iter()
+            .find(|tile_danger| TileKind::from_id(tile_danger.tile) == kind)
+            .unwrap()
+            .danger
+    }
+
+    #[test]
+    fn test_get_tiles_danger() {
+        let game_summary = get_game_summary("159m26p59s1234567z");
+
+        // All the copies are visible, so it can't be a pair
+        assert_eq!(get_danger(&game_summary, "1z"), 0);
+        // Discarded by the player
+        assert!(get_danger(&game_summary, "5m") < get_danger(&game_summary, "5s"));
+        // Few unseen tiles can form a wait with it
+        assert!(get_danger(&game_summary, "6p") < get_danger(&game_summary, "5s"));
+    }
+
+    #[test]
+    fn test_get_opponents_readiness() {
+        let mut game_summary = get_game_summary("159m26p59s1234567z");
+        let get_readiness = |summary: &GameSummary| {
+            summary
+                .get_opponents_readiness()
+                .into_iter()
+                .find(|opponent| opponent.player_id == "1")
+                .unwrap()
+                .readiness
+        };
+        let readiness = get_readiness(&game_summary);
+
+        assert_eq!(readiness, 48);
+
+        game_summary.round.discards[3].claimed_by = Some("1".to_string());
+        assert!(get_readiness(&game_summary) > readiness);
+    }
+
+    #[test]
+    fn test_scored_drops_fold_and_push() {
+        // Far from ready, so it discards the safest tiles first
+        let drops = get_scored_drops(&get_game_summary("159m26p59s1234567z")).unwrap();
+        assert_eq!(
+            TileKind::from_id(drops[0].id),
+            TileKind::from_id(Tile::id_from_summary("1z"))
+        );
+        assert!(drops
+            .windows(2)
+            .all(|pair| pair[0].danger <= pair[1].danger));
+
+        // Ready, so it keeps the hand even with a dangerous discard
+        let ready_drops = get_scored_drops(&get_game_summary("123m456p789s1122z5s")).unwrap();
+        assert_eq!(
+            TileKind::from_id(ready_drops[0].id),
+            TileKind::from_id(Tile::id_from_summary("5s"))
+        );
+        assert!(ready_drops[0].danger > 0);
+    }
+
+    #[test]
+    fn test_aggressive_style_pushes() {
+        let game_summary = get_game_summary("159m26p59s1234567z");
+        let profile = AIProfile {
+            style: AIStyle::Aggressive,
+            ..AIProfile::default()
+        };
+        let drops = get_scored_drops_for_profile(&game_summary, &profile).unwrap();
+        let best_shanten = drops.iter().map(|drop| drop.shanten).min().unwrap();
+
+        // The opponent is not ready enough to fold
+        assert_eq!(drops[0].shanten, best_shanten);
+        assert!(!drops
+            .windows(2)
+            .all(|pair| pair[0].danger <= pair[1].danger));
+    }
+}
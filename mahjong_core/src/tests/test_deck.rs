@@ -3,6 +3,7 @@ mod test {
     use crate::{
         deck::DEFAULT_DECK,
         game::{GameStyle, Players},
+        ruleset::HouseRules,
         table::PositionTilesOpts,
         Game,
     };
@@ -11,7 +12,7 @@ mod test {
     #[test]
     fn test_deck_total_count() {
         let keys = DEFAULT_DECK.0.len();
-        assert_eq!(keys, 144);
+        assert_eq!(keys, 148);
     }
 
     #[test]
@@ -21,14 +22,20 @@ mod test {
             let player = num.to_string();
             players.push(player);
         }
-        let mut table = DEFAULT_DECK.create_table(&players);
+        let mut table = DEFAULT_DECK.create_table(
+            &players,
+            &*GameStyle::HongKong.get_ruleset(&HouseRules::default()),
+        );
         table.draw_wall.position_tiles(None);
 
         assert_eq!(table.board.0.len(), 0);
         assert_eq!(table.draw_wall.len(), 144);
         assert_eq!(table.hands.0.keys().len(), 4);
 
-        let mut table_dead_wall = DEFAULT_DECK.create_table(&players);
+        let mut table_dead_wall = DEFAULT_DECK.create_table(
+            &players,
+            &*GameStyle::HongKong.get_ruleset(&HouseRules::default()),
+        );
         table_dead_wall
             .draw_wall
             .position_tiles(Some(PositionTilesOpts {
@@ -39,4 +46,19 @@ mod test {
         assert_eq!(table_dead_wall.draw_wall.len(), 144 - 14);
         assert_eq!(table_dead_wall.hands.0.keys().len(), 4);
     }
+
+    #[test]
+    fn test_create_table_counts_singapore() {
+        let mut players = Players::default();
+        for num in 0..Game::get_players_num(&GameStyle::Singapore) {
+            players.push(num.to_string());
+        }
+        let mut table = DEFAULT_DECK.create_table(
+            &players,
+            &*GameStyle::Singapore.get_ruleset(&HouseRules::default()),
+        );
+        table.draw_wall.position_tiles(None);
+
+        assert_eq!(table.draw_wall.len(), 148);
+    }
 }
@@ -1,3 +1,4 @@
+mod abortive_draws;
 mod base;
 mod discards;
 mod operations;
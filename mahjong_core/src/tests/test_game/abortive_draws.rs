@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        game::{AbortiveDrawError, CreateMeldError},
+        round::{AbortiveDraw, RoundOutcome},
+        Game, GamePhase, Tile,
+    };
+    use pretty_assertions::assert_eq;
+    use strum::IntoEnumIterator;
+
+    const NINE_TERMINALS_SUMMARY: &str = "
+        - P1: 一萬,九萬,一索,九索,一筒,九筒,東,南,西,二萬,三萬,四萬,五萬,六萬
+        - P2: 二索
+        - P3: 二筒
+        - P4: 三筒
+        Turn: P1, Dealer: P1, Phase: Playing";
+
+    #[test]
+    fn test_four_winds_is_detected_on_discard() {
+        let mut game = Game::from_summary(
+            "- P1: 二索
+             - P2: 二筒
+             - P3: 三筒
+             - P4: 東,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一索,二索,三索,四索,五索
+             Board: 東,東,東
+             Turn: P4, Dealer: P1, Phase: Playing",
+        );
+        game.house_rules.abortive_draws = vec![AbortiveDraw::FourWinds];
+
+        let result = game.discard_tile_to_board(&Tile::id_from_summary("東"));
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(game.phase, GamePhase::InitialShuffle);
+        assert_eq!(
+            game.round.last_outcome,
+            Some(RoundOutcome::AbortiveDraw(AbortiveDraw::FourWinds))
+        );
+        assert_eq!(game.round.player_index, 0);
+        assert_eq!(game.round.consecutive_same_seats, 1);
+    }
+
+    #[test]
+    fn test_four_winds_needs_the_house_rule() {
+        let mut game = Game::from_summary(
+            "- P1: 二索
+             - P2: 二筒
+             - P3: 三筒
+             - P4: 東,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一索,二索,三索,四索,五索
+             Board: 東,東,東
+             Turn: P4, Dealer: P1, Phase: Playing",
+        );
+
+        let result = game.discard_tile_to_board(&Tile::id_from_summary("東"));
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(game.phase, GamePhase::Playing);
+        assert_eq!(game.round.last_outcome, None);
+    }
+
+    #[test]
+    fn test_four_winds_with_a_concealed_kong() {
+        for (kong_summary, is_detected) in [
+            ("一萬,一萬,一萬,一萬", true),
+            ("*一萬,一萬,一萬,一萬", false),
+        ] {
+            let mut game = Game::from_summary(&format!(
+                "- P1: 二索 {kong_summary}
+                 - P2: 二筒
+                 - P3: 三筒
+                 - P4: 東,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一索,二索,三索,四索,五索
+                 Board: 東,東,東
+                 Turn: P4, Dealer: P1, Phase: Playing"
+            ));
+            game.house_rules.abortive_draws = vec![AbortiveDraw::FourWinds];
+
+            game.discard_tile_to_board(&Tile::id_from_summary("東"))
+                .unwrap();
+
+            let expected_outcome =
+                is_detected.then_some(RoundOutcome::AbortiveDraw(AbortiveDraw::FourWinds));
+
+            assert_eq!(game.round.last_outcome, expected_outcome, "{kong_summary}");
+        }
+    }
+
+    #[test]
+    fn test_four_kongs() {
+        let mut game = Game::from_summary(
+            "- P1: _ 一萬,一萬,一萬,一萬 二萬,二萬,二萬,二萬 三萬,三萬,三萬,三萬
+             - P2: 二索 四萬,四萬,四萬,四萬
+             - P3: 二筒
+             - P4: 三筒
+             Turn: P2, Dealer: P1, Phase: Playing",
+        );
+        game.house_rules.abortive_draws = vec![AbortiveDraw::FourKongs];
+
+        assert_eq!(game.check_abortive_draws(), Some(AbortiveDraw::FourKongs));
+        assert_eq!(game.phase, GamePhase::InitialShuffle);
+
+        let mut single_player_game = Game::from_summary(
+            "- P1: _ 一萬,一萬,一萬,一萬 二萬,二萬,二萬,二萬 三萬,三萬,三萬,三萬 四萬,四萬,四萬,四萬
+             - P2: 二索
+             - P3: 二筒
+             - P4: 三筒
+             Turn: P1, Dealer: P1, Phase: Playing",
+        );
+        single_player_game.house_rules.abortive_draws = vec![AbortiveDraw::FourKongs];
+
+        assert_eq!(single_player_game.check_abortive_draws(), None);
+        assert_eq!(single_player_game.phase, GamePhase::Playing);
+    }
+
+    #[test]
+    fn test_create_meld_ends_round_with_four_kongs() {
+        let mut game = Game::from_summary(
+            "- P1: _ 一萬,一萬,一萬,一萬 二萬,二萬,二萬,二萬 三萬,三萬,三萬,三萬
+             - P2: 四萬,四萬,四萬,四萬,二筒,三筒,四筒,五筒,六筒,七筒,七索,八索,九索,二索
+             - P3: 二筒
+             - P4: 三筒
+             Wall: 五萬,六萬
+             Turn: P2, Dealer: P1, Phase: Playing",
+        );
+        game.house_rules.abortive_draws = vec![AbortiveDraw::FourKongs];
+
+        let result = game.create_meld(
+            &"1".to_string(),
+            &Tile::ids_from_summary("四萬,四萬,四萬,四萬"),
+            false,
+            true,
+        );
+
+        assert_eq!(result, Err(CreateMeldError::EndRound));
+        assert_eq!(
+            game.round.last_outcome,
+            Some(RoundOutcome::AbortiveDraw(AbortiveDraw::FourKongs))
+        );
+    }
+
+    #[test]
+    fn test_declare_nine_terminals() {
+        let mut game = Game::from_summary(NINE_TERMINALS_SUMMARY);
+        game.house_rules.abortive_draws = vec![AbortiveDraw::NineTerminals];
+
+        let result = game.declare_abortive_draw(&"0".to_string(), &AbortiveDraw::NineTerminals);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(game.phase, GamePhase::InitialShuffle);
+        assert_eq!(
+            game.round.last_outcome,
+            Some(RoundOutcome::AbortiveDraw(AbortiveDraw::NineTerminals))
+        );
+    }
+
+    #[test]
+    fn test_declare_abortive_draw_errors() {
+        for error in AbortiveDrawError::iter() {
+            let mut game = Game::from_summary(NINE_TERMINALS_SUMMARY);
+            let abortive_draw = match error {
+                AbortiveDrawError::ConditionNotMet => AbortiveDraw::FourWinds,
+                AbortiveDrawError::NotAllowed => AbortiveDraw::NineTerminals,
+            };
+
+            if error == AbortiveDrawError::ConditionNotMet {
+                game.house_rules.abortive_draws = AbortiveDraw::iter().collect();
+            }
+
+            let result = game.declare_abortive_draw(&"0".to_string(), &abortive_draw);
+
+            assert_eq!(result, Err(error.clone()), "Test case: {:?}", error);
+            assert_eq!(game.phase, GamePhase::Playing);
+        }
+    }
+}
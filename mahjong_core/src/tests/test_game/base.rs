@@ -1,8 +1,7 @@
 #[cfg(test)]
 mod test {
     use crate::{
-        deck::DEFAULT_DECK, game::DrawTileResult, round::RoundTileClaimed, Board, DrawWall, Game,
-        Hands, Tile,
+        game::DrawTileResult, round::RoundTileClaimed, Board, DrawWall, Game, Hands, Tile,
     };
     use pretty_assertions::assert_eq;
 
@@ -110,7 +109,7 @@ mod test {
             assert_eq!(game.score.get(player), Some(&0));
         }
 
-        assert_eq!(game.table.draw_wall.len(), DEFAULT_DECK.0.len());
+        assert_eq!(game.table.draw_wall.len(), 144);
         assert_eq!(game.table.board.len(), 0);
     }
 }
@@ -69,4 +69,32 @@ Consecutive: 0, Discarded: 二萬
             assert_eq!(result, Err(error.clone()), "Test case: {:?}", error);
         }
     }
+
+    #[test]
+    fn test_get_possible_melds_by_discard() {
+        let mut game = Game::from_summary(
+            "- P1: 一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一索,二索,三索,東,九筒
+             - P2: 東,東,五索
+             - P3: 九筒,九筒,六索
+             - P4: 七索
+             Turn: P1, Phase: Playing",
+        );
+        let summary = game.get_summary();
+
+        let melds = game
+            .get_possible_melds_by_discard()
+            .into_iter()
+            .filter(|meld| meld.discard_tile.is_some())
+            .map(|meld| (meld.discard_tile.unwrap(), meld.player_id))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            melds,
+            vec![
+                (Tile::id_from_summary("東"), "1".to_string()),
+                (Tile::id_from_summary("九筒"), "2".to_string()),
+            ]
+        );
+        assert_eq!(game.get_summary(), summary);
+    }
 }
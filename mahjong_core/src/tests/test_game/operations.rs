@@ -2,6 +2,7 @@
 mod test {
     use crate::{
         game::{BreakMeldError, CreateMeldError},
+        hand::MeldSupplier,
         Game, Tile,
     };
     use pretty_assertions::assert_eq;
@@ -52,6 +53,11 @@ mod test {
     fn test_create_meld() {
         for error in CreateMeldError::iter() {
             let (summary, tiles_summary) = match error {
+                CreateMeldError::DeadHand => (
+                    "- P1: 一萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一索,二索,三索,二萬,二萬,二萬
+                     Turn: P1",
+                    "一索,二索,三索",
+                ),
                 CreateMeldError::EndRound => ("", ""),
                 CreateMeldError::NotMeld => (
                     "- P1: 一萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一索,二索,三索,二萬,二萬,二萬
@@ -71,6 +77,7 @@ mod test {
 
             let mut game = Game::from_summary(summary);
             let tiles = Tile::ids_from_summary(tiles_summary);
+            game.table.hands.0.get_mut("0").unwrap().is_dead = error == CreateMeldError::DeadHand;
 
             let result = game.create_meld(&"0".to_string(), &tiles, false, false);
 
@@ -91,4 +98,36 @@ mod test {
 
         assert_eq!(result, Ok(()), "Test case correct");
     }
+
+    #[test]
+    fn test_create_meld_records_supplier() {
+        let mut game = Game::from_summary(
+            "- P1: 九索
+             - P2: 一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一索,二索,三索,二筒,二筒
+             - P3: 九筒
+             - P4: 九索
+             Turn: P1
+             Discarded: 一萬(P2)",
+        );
+
+        let result = game.create_meld(
+            &"1".to_string(),
+            &Tile::ids_from_summary("一萬,二萬,三萬"),
+            false,
+            false,
+        );
+
+        assert_eq!(result, Ok(()));
+
+        let hand = game.table.hands.get(&"1".to_string()).unwrap();
+        let set_id = hand.list[0].set_id.clone().unwrap();
+
+        assert_eq!(
+            hand.meld_suppliers,
+            vec![MeldSupplier {
+                player_id: "0".to_string(),
+                set_id,
+            }]
+        );
+    }
 }
@@ -23,7 +23,10 @@ mod test {
     "tile_claimed": null,
     "wall_tile_drawn": null,
     "wind": "East",
-    "initial_winds": null
+    "initial_winds": null,
+    "payments": [],
+    "last_outcome": null,
+    "discards": []
   },
   "score": {
     "3": 0,
@@ -142,11 +145,17 @@ mod test {
     "hands": {
       "3": {
         "list": [],
-        "kong_tiles": []
+        "kong_tiles": [],
+        "meld_suppliers": [],
+        "is_dead": false,
+        "is_revealed": false
       },
       "2": {
         "list": [],
-        "kong_tiles": []
+        "kong_tiles": [],
+        "meld_suppliers": [],
+        "is_dead": false,
+        "is_revealed": false
       },
       "0": {
         "list": [
@@ -156,11 +165,17 @@ mod test {
             "set_id": null
           }
         ],
-        "kong_tiles": []
+        "kong_tiles": [],
+        "meld_suppliers": [],
+        "is_dead": false,
+        "is_revealed": false
       },
       "1": {
         "list": [],
-        "kong_tiles": []
+        "kong_tiles": [],
+        "meld_suppliers": [],
+        "is_dead": false,
+        "is_revealed": false
       }
     },
     "bonus_tiles": {
@@ -170,7 +185,20 @@ mod test {
     }
   },
   "version": "bd760511-27d9-4c32-a1bb-8d2795bc3c42",
-  "style": "HongKong"
+  "style": "HongKong",
+  "house_rules": {
+    "abortive_draws": [],
+    "chombo_dead_hand": false,
+    "chombo_penalty": null,
+    "chow_only_from_left": true,
+    "concealed_kongs_shown": false,
+    "dealer_keeps_seat_if_ready": false,
+    "discard_winners": "FirstCaller",
+    "exhaustive_draw_passes_dealer": false,
+    "flowers_count": true,
+    "liability_payments": false,
+    "ready_hand_payment": null
+  }
 }
 "#;
 
@@ -1,7 +1,14 @@
 #[cfg(test)]
 mod test {
-    use crate::{game_summary::GameSummary, hand::HandPossibleMeld, Game, Hand, Tile};
+    use crate::{
+        game_summary::{GameSummary, TileKindTracker},
+        hand::{HandPossibleMeld, HandWait},
+        round::RoundDiscard,
+        Game, Hand, Tile, TileId,
+    };
     use pretty_assertions::assert_eq;
+    use rand::{rngs::StdRng, SeedableRng};
+    use rustc_hash::FxHashSet;
 
     #[test]
     fn test_get_possible_melds() {
@@ -30,4 +37,157 @@ mod test {
         // One is own and one claimed
         assert_eq!(possible_melds, &["一萬,一萬,一萬 NO", "三索,三索,三索 NO"]);
     }
+
+    #[test]
+    fn test_get_hand_waits() {
+        let game = Game::from_summary(
+            "- P1: 一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一筒,一筒,東,東
+             - P2: 九索 *一筒,二筒,三筒
+             - P3: 九筒
+             - P4: 一索
+             Wall: 二索
+             Board: 東",
+        );
+        let game_summary = GameSummary::from_game(&game, &"0".to_string()).unwrap();
+
+        let mut hand_waits = game_summary.get_hand_waits();
+        hand_waits.sort_by_key(|wait| wait.tile);
+
+        assert_eq!(
+            hand_waits,
+            vec![
+                HandWait {
+                    faan: 2,
+                    tile: Tile::id_from_summary("一筒"),
+                    unseen: 1,
+                },
+                HandWait {
+                    faan: 2,
+                    tile: Tile::id_from_summary("東"),
+                    unseen: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_tiles_tracker() {
+        let mut game = Game::from_summary(
+            "- P1: 一萬,一萬,二萬
+             - P2: 九索 *一萬,二萬,三萬
+             - P3: 一萬
+             - P4: 一索
+             Board: 二萬",
+        );
+        game.table
+            .bonus_tiles
+            .0
+            .insert("1".to_string(), vec![Tile::id_from_summary("梅")]);
+        let game_summary = GameSummary::from_game(&game, &"0".to_string()).unwrap();
+
+        let tracker = game_summary.get_tiles_tracker();
+        let get_tracker = |summary: &str| {
+            tracker
+                .iter()
+                .find(|t| t.tile == Tile::id_from_summary(summary))
+                .unwrap()
+                .clone()
+        };
+
+        // The animals are not part of the Hong Kong style
+        assert_eq!(tracker.len(), 42);
+        assert_eq!(
+            get_tracker("一萬"),
+            TileKindTracker {
+                in_board: 0,
+                in_exposed: 1,
+                in_hand: 2,
+                tile: Tile::id_from_summary("一萬"),
+                unseen: 1,
+            }
+        );
+        assert_eq!(
+            get_tracker("二萬"),
+            TileKindTracker {
+                in_board: 1,
+                in_exposed: 1,
+                in_hand: 1,
+                tile: Tile::id_from_summary("二萬"),
+                unseen: 1,
+            }
+        );
+        assert_eq!(get_tracker("梅").in_exposed, 1);
+        assert_eq!(get_tracker("梅").unseen, 0);
+    }
+
+    #[test]
+    fn test_sample_game() {
+        let mut game = Game::new(None);
+        game.play_until_middle_of_round();
+        let player_id = game.get_current_player().unwrap();
+        let game_summary = GameSummary::from_game(&game, &player_id).unwrap();
+        let mut sampled_game = game_summary.sample_game(&mut StdRng::seed_from_u64(1));
+
+        assert_eq!(
+            sampled_game.table.hands.get(&player_id),
+            game.table.hands.get(&player_id)
+        );
+        assert_eq!(sampled_game.table.board, game.table.board);
+        assert_eq!(
+            sampled_game.table.draw_wall.len(),
+            game.table.draw_wall.len()
+        );
+        assert_eq!(sampled_game.round.player_index, game.round.player_index);
+        for other_player in game.players.iter() {
+            assert_eq!(
+                sampled_game.table.hands.get(other_player).unwrap().len(),
+                game.table.hands.get(other_player).unwrap().len()
+            );
+        }
+
+        // Every tile is only in one place
+        let mut sampled_tiles = sampled_game.table.board.0.clone();
+        for hand in sampled_game.table.hands.0.values() {
+            sampled_tiles.extend(hand.list.iter().map(|tile| tile.id));
+            sampled_tiles.extend(hand.kong_tiles.iter().map(|tile| tile.id));
+        }
+        sampled_tiles.extend(sampled_game.table.bonus_tiles.0.values().flatten());
+        let unique_tiles = sampled_tiles.iter().cloned().collect::<FxHashSet<TileId>>();
+        assert_eq!(unique_tiles.len(), sampled_tiles.len());
+
+        // The sampled game can be played from the same point
+        let hand = game_summary.hand.unwrap();
+        let tile = hand
+            .list
+            .iter()
+            .find(|tile| tile.set_id.is_none())
+            .unwrap()
+            .id;
+        assert!(sampled_game.discard_tile_to_board(&tile).is_ok());
+    }
+
+    #[test]
+    fn test_sample_game_keeps_the_discarder_of_the_claimed_tile() {
+        let mut game = Game::from_summary(
+            "- P1: 九索
+             - P2: 九筒
+             - P3: 一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一索,二索,三索,二筒,二筒
+             - P4: 九索
+             Turn: P1
+             Discarded: 一萬(P3)",
+        );
+        game.round.discards = vec![RoundDiscard {
+            claimed_by: Some("2".to_string()),
+            player_id: "0".to_string(),
+            tile: Tile::id_from_summary("一萬"),
+        }];
+        let game_summary = GameSummary::from_game(&game, &"2".to_string()).unwrap();
+
+        let sampled_game = game_summary.sample_game(&mut StdRng::seed_from_u64(1));
+        let tile_claimed = sampled_game.round.tile_claimed.unwrap();
+
+        // The claim skipped the previous player
+        assert_eq!(tile_claimed.by, Some("2".to_string()));
+        assert_eq!(tile_claimed.from, "0".to_string());
+    }
 }
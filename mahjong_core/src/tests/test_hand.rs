@@ -2,6 +2,7 @@
 mod test {
     use crate::{
         hand::{CanSayMahjongError, SortHandError},
+        ruleset::HongKongRuleset,
         Hand, Tile,
     };
     use pretty_assertions::assert_eq;
@@ -38,12 +39,18 @@ mod test {
         for error in CanSayMahjongError::iter() {
             let summary = match error {
                 CanSayMahjongError::CantDrop => "",
+                CanSayMahjongError::DeadHand => {
+                    "一萬,一萬 一筒,一筒,一筒 二筒,二筒,二筒 三筒,三筒,三筒 四筒,四筒,四筒"
+                }
                 CanSayMahjongError::NotPair => {
                     "一萬,二萬 一筒,一筒,一筒 二筒,二筒,二筒 三筒,三筒,三筒 四筒,四筒,四筒"
                 }
             };
 
-            let result = Hand::from_summary(summary).can_say_mahjong();
+            let mut hand = Hand::from_summary(summary);
+            hand.is_dead = error == CanSayMahjongError::DeadHand;
+
+            let result = hand.can_say_mahjong(&HongKongRuleset::default());
 
             assert_eq!(error, result.unwrap_err());
         }
@@ -51,8 +58,76 @@ mod test {
         let correct_result = Hand::from_summary(
             "一萬,一萬 一筒,一筒,一筒 二筒,二筒,二筒 三筒,三筒,三筒 四筒,四筒,四筒",
         )
-        .can_say_mahjong();
+        .can_say_mahjong(&HongKongRuleset::default());
 
         assert_eq!(correct_result, Ok(()));
     }
+
+    #[test]
+    fn test_is_ready() {
+        let ruleset = HongKongRuleset::default();
+
+        for (summary, expected) in [
+            (
+                "一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一筒,一筒,東,東",
+                true,
+            ),
+            (
+                "一萬,二萬 一筒,一筒,一筒 二筒,二筒,二筒 三筒,三筒,三筒 四筒,四筒,四筒",
+                true,
+            ),
+            (
+                "一萬,四萬,七萬,一筒,四筒,七筒,一索,四索,七索,東,南,西,北",
+                false,
+            ),
+            // With the extra tile, it is ready if a discard leaves it one tile away
+            (
+                "一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一筒,一筒,東,東,北",
+                true,
+            ),
+        ] {
+            assert_eq!(
+                Hand::from_summary(summary).is_ready(&ruleset),
+                expected,
+                "{summary}"
+            );
+        }
+
+        let mut dead_hand = Hand::from_summary("一萬,二萬,三萬,四萬");
+        assert!(dead_hand.is_ready(&ruleset));
+        dead_hand.is_dead = true;
+        assert!(!dead_hand.is_ready(&ruleset));
+    }
+
+    #[test]
+    fn test_get_waiting_tiles() {
+        for (summary, expected) in [
+            (
+                "一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一筒,一筒,東,東",
+                "一筒,東",
+            ),
+            (
+                "一萬,一萬,一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,九萬,九萬",
+                "一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬",
+            ),
+            // The player already has all the copies of 二萬
+            ("三萬,四萬,東,東 *二萬,二萬,二萬 *二萬,三萬,四萬", "五萬"),
+            (
+                "一萬,四萬,七萬,一筒,四筒,七筒,一索,四索,七索,東,南,西,北",
+                "",
+            ),
+            (
+                "一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一筒,一筒,東,東,北",
+                "",
+            ),
+        ] {
+            let mut waiting_tiles =
+                Hand::from_summary(summary).get_waiting_tiles(&HongKongRuleset::default());
+            let mut expected_tiles = Tile::ids_from_summary(expected);
+            waiting_tiles.sort();
+            expected_tiles.sort();
+
+            assert_eq!(waiting_tiles, expected_tiles, "{summary}");
+        }
+    }
 }
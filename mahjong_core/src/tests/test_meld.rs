@@ -3,6 +3,7 @@ mod test {
     use crate::deck::DEFAULT_DECK;
     use crate::hand::HandPossibleMeld;
     use crate::meld::{get_is_chow, get_is_kong, get_is_pung, PlayerDiff, SetCheckOpts};
+    use crate::ruleset::HongKongRuleset;
     use crate::{Hand, Tile};
     use pretty_assertions::assert_eq;
 
@@ -35,6 +36,27 @@ mod test {
             None,
             &["三筒,一筒,二筒 NO", "五筒,五筒,五筒 NO"],
         ),
+        (
+            "一索,一索,二索,三索,一索",
+            None,
+            &[
+                "一索,一索,一索 NO",
+                "一索,二索,三索 NO",
+                "一索,二索,三索 NO",
+                "二索,三索,一索 NO",
+            ],
+        ),
+        (
+            "五筒,五筒,五筒,五筒",
+            None,
+            &[
+                "五筒,五筒,五筒 NO",
+                "五筒,五筒,五筒,五筒 NO",
+                "五筒,五筒,五筒 NO",
+                "五筒,五筒,五筒 NO",
+                "五筒,五筒,五筒 NO",
+            ],
+        ),
     ];
 
     enum MeldFixtureArray {
@@ -92,7 +114,7 @@ mod test {
         {
             let hand = Hand::from_summary(hand_summary);
             let possible_melds = hand
-                .get_possible_melds(*player_diff, None, false)
+                .get_possible_melds(&HongKongRuleset::default(), *player_diff, None, false)
                 .into_iter()
                 .map(|m| {
                     let hand_possible_meld: HandPossibleMeld = m;
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        notation::{format_hand, format_tiles, parse_hand, parse_tiles, NotationError},
+        Game, Hand, Tile,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_tiles_notation() {
+        let tiles = parse_tiles("123m456p789s1234567z").unwrap();
+
+        assert_eq!(
+            Tile::summary_from_ids(&tiles),
+            "一萬,二萬,三萬,四筒,五筒,六筒,七索,八索,九索,東,南,西,北,白,發,中"
+        );
+        assert_eq!(format_tiles(&tiles), "123m456p789s1234567z");
+        assert_eq!(
+            Tile::summary_from_ids(&parse_tiles("1234f5678f1234a").unwrap()),
+            "梅,蘭,菊,竹,春,夏,秋,冬,貓,鼠,雞,蜈"
+        );
+
+        let repeated = parse_tiles("1111m").unwrap();
+        assert_eq!(format_tiles(&repeated), "1111m");
+        assert_eq!(
+            repeated
+                .iter()
+                .collect::<std::collections::HashSet<_>>()
+                .len(),
+            4
+        );
+
+        for (notation, error) in [
+            ("11111m", NotationError::TooManyCopies),
+            ("123", NotationError::MissingSuffix),
+            ("8z", NotationError::InvalidTile),
+            ("0m", NotationError::InvalidTile),
+            ("m", NotationError::InvalidTile),
+        ] {
+            assert_eq!(parse_tiles(notation), Err(error), "{notation}");
+        }
+    }
+
+    #[test]
+    fn test_hand_notation_round_trip() {
+        for notation in [
+            "123m456p789s11z",
+            "123m456p11z 15f [789s] (2222z)",
+            "11z [1111m] [234p] (555s)",
+            "1a [123m] [456m] [789m] [111z]",
+        ] {
+            let parsed = parse_hand(notation).unwrap();
+
+            assert_eq!(format_hand(&parsed.hand, &parsed.bonus_tiles), notation);
+        }
+
+        for (notation, error) in [
+            ("123m [45p]", NotationError::InvalidMeld),
+            ("123m (456p", NotationError::UnclosedMeld),
+        ] {
+            assert_eq!(parse_hand(notation), Err(error), "{notation}");
+        }
+    }
+
+    #[test]
+    fn test_hand_notation_summary() {
+        let hand = Hand::from_summary("123m456p11z [789s] (2222z)");
+
+        assert_eq!(
+            hand.to_summary_full().split(' ').next().unwrap(),
+            "一萬,二萬,三萬,四筒,五筒,六筒,東,東"
+        );
+        assert_eq!(hand.kong_tiles.len(), 1);
+        assert!(hand.kong_tiles.iter().all(|kong_tile| kong_tile.concealed));
+        assert_eq!(hand.to_notation(), "123m456p11z [789s] (2222z)");
+
+        let game = Game::from_summary(
+            r#"
+- P1: 123m456p11z 1f [789s]
+- P2: _
+- P3: _
+- P4: _
+Board: 1z9p
+        "#,
+        );
+
+        assert_eq!(game.table.hands.0["0"].to_notation(), "123m456p11z [789s]");
+        assert_eq!(Tile::summary_from_ids(&game.table.bonus_tiles.0["0"]), "梅");
+        assert_eq!(game.table.board.to_summary(), "東,九筒");
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        deck::DEFAULT_DECK,
+        render::{parse_tile, render_tiles, try_render_tiles, TileFormat},
+        Tile,
+    };
+    use pretty_assertions::assert_eq;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_render_round_trip() {
+        for format in TileFormat::iter() {
+            for tile in DEFAULT_DECK.0.iter() {
+                let rendered = tile.render(format);
+                let parsed = parse_tile(&rendered, format).unwrap();
+
+                assert!(parsed.is_same_content(tile), "{format:?} {rendered}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_tiles() {
+        let tiles = Tile::ids_from_summary("123m5p9s1z7z");
+
+        assert_eq!(render_tiles(&tiles, TileFormat::Unicode), "🀇🀈🀉🀝🀘🀀🀄");
+        assert_eq!(
+            render_tiles(&tiles, TileFormat::English),
+            "1 Characters,2 Characters,3 Characters,5 Dots,9 Bamboo,East Wind,Red Dragon"
+        );
+        assert_eq!(
+            render_tiles(&tiles, TileFormat::Ascii),
+            "1m,2m,3m,5p,9s,1z,7z"
+        );
+        assert_eq!(
+            render_tiles(&tiles, TileFormat::Chinese),
+            "一萬,二萬,三萬,五筒,九索,東,中"
+        );
+
+        let bonus = Tile::ids_from_summary("1f5f1a");
+        assert_eq!(render_tiles(&bonus, TileFormat::Unicode), "🀢🀦貓");
+        assert_eq!(
+            render_tiles(&bonus, TileFormat::English),
+            "Plum Flower,Spring Season,Cat"
+        );
+    }
+
+    #[test]
+    fn test_try_render_tiles() {
+        let tiles = Tile::ids_from_summary("1m1z");
+
+        assert_eq!(
+            try_render_tiles(&tiles, TileFormat::Ascii),
+            Some("1m,1z".to_string())
+        );
+        assert_eq!(
+            try_render_tiles(&[tiles[0], DEFAULT_DECK.0.len()], TileFormat::Ascii),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_tile() {
+        assert!(parse_tile("🀫", TileFormat::Unicode).is_none());
+        assert!(parse_tile("red dragon", TileFormat::English)
+            .unwrap()
+            .is_same_content(&Tile::from_summary("中")));
+        assert!(parse_tile("5 m", TileFormat::Ascii).is_none());
+    }
+}
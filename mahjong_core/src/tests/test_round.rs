@@ -3,6 +3,7 @@ mod test {
     use crate::{
         game::GameStyle,
         round::{DecideDealerWinds, NextTurnError, Round},
+        ruleset::HongKongRuleset,
         Game, GamePhase, Wind, WINDS_ROUND_ORDER,
     };
     use pretty_assertions::assert_eq;
@@ -83,7 +84,10 @@ mod test {
             let mut game = Game::from_summary(&parsed_summary);
             let expected_game = Game::from_summary(expected_round_summary);
 
-            game.round.next_turn(&game.table.hands).unwrap_or_default();
+            let ruleset = game.style.get_ruleset(&game.house_rules);
+            game.round
+                .next_turn(&game.table.hands, &*ruleset)
+                .unwrap_or_default();
 
             compare_rounds(&game.round, &expected_game.round, test_index);
         }
@@ -118,7 +122,8 @@ mod test {
             };
             let mut game = Game::from_summary(&summary);
 
-            let result = game.round.next_turn(&game.table.hands);
+            let ruleset = game.style.get_ruleset(&game.house_rules);
+            let result = game.round.next_turn(&game.table.hands, &*ruleset);
 
             assert_eq!(parsed_err, result.unwrap_err(), "Test index: {test_index}",);
         }
@@ -152,7 +157,11 @@ mod test {
         {
             let mut round = Round::from_summary(round_summary);
             let mut initial_phase = GamePhase::Playing;
-            round.move_after_win(&mut initial_phase, *winner_player_index);
+            round.move_after_win(
+                &mut initial_phase,
+                *winner_player_index,
+                &HongKongRuleset::default(),
+            );
 
             let expected_game = Game::from_summary(expected_round_summary);
 
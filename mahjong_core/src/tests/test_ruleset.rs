@@ -0,0 +1,361 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        game::GameStyle,
+        game_summary::GameSummary,
+        meld::PossibleMeld,
+        round::RoundOutcome,
+        ruleset::{DiscardWinners, HongKongRuleset, HouseRules, Ruleset},
+        score::{ScorePaymentReason, ScoringRule},
+        Game, GamePhase, Tile,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_claim_priority() {
+        let ruleset = HongKongRuleset::default();
+        let get_meld = |summary: &str, is_mahjong: bool| PossibleMeld {
+            discard_tile: None,
+            is_concealed: false,
+            is_mahjong,
+            is_upgrade: false,
+            player_id: "0".to_string(),
+            tiles: Tile::ids_from_summary(summary),
+        };
+
+        assert_eq!(
+            ruleset.get_claim_priority(&get_meld("一萬,二萬,三萬", false)),
+            0
+        );
+        assert_eq!(
+            ruleset.get_claim_priority(&get_meld("三萬,三萬,三萬", false)),
+            1
+        );
+        assert_eq!(ruleset.get_claim_priority(&get_meld("三萬,三萬", true)), 2);
+    }
+
+    #[test]
+    fn test_possible_melds_sorted_by_priority() {
+        let game = Game::from_summary(
+            "- P1: 一索
+             - P2: 一萬,二萬
+             - P3: 三萬,三萬
+             - P4: 九筒
+             Turn: P1
+             Discarded: 三萬",
+        );
+
+        let players = game
+            .get_possible_melds(false)
+            .iter()
+            .map(|meld| meld.player_id.clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(players, vec!["2".to_string(), "1".to_string()]);
+    }
+
+    #[test]
+    fn test_styles_rulesets() {
+        let animal = Tile::from_summary("貓");
+
+        assert!(!GameStyle::HongKong
+            .get_ruleset(&HouseRules::default())
+            .has_tile(&animal));
+        assert!(GameStyle::Singapore
+            .get_ruleset(&HouseRules::default())
+            .has_tile(&animal));
+        assert!(!GameStyle::HongKong
+            .get_ruleset(&HouseRules::default())
+            .has_bonus_bites());
+        assert!(GameStyle::Singapore
+            .get_ruleset(&HouseRules::default())
+            .has_bonus_bites());
+    }
+
+    #[test]
+    fn test_house_rules_chow_from_any_player() {
+        let mut game = Game::from_summary(
+            "- P1: 一索
+             - P2: 九筒
+             - P3: 一萬,二萬
+             - P4: 九索
+             Turn: P1
+             Discarded: 三萬",
+        );
+        let player_id = "2".to_string();
+
+        assert!(game
+            .get_possible_melds_for_player(&player_id, false)
+            .is_empty());
+
+        game.house_rules.chow_only_from_left = false;
+
+        assert_eq!(
+            game.get_possible_melds_for_player(&player_id, false).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_house_rules_exhaustive_draw_passes_dealer() {
+        for (passes_dealer, expected_dealer) in [(false, 0), (true, 1)] {
+            let mut game = Game::from_summary(
+                "- P1: 一萬
+                 - P2: 二萬
+                 - P3: 三萬
+                 - P4: 四萬
+                 Turn: P1, Dealer: P1",
+            );
+            game.house_rules.exhaustive_draw_passes_dealer = passes_dealer;
+
+            game.pass_null_round().unwrap();
+
+            assert_eq!(game.round.dealer_player_index, expected_dealer);
+        }
+    }
+
+    #[test]
+    fn test_house_rules_flowers_count() {
+        let mut game = Game::from_summary(
+            "- P1: _ 一萬,二萬,三萬 四萬,五萬,六萬 七萬,八萬,九萬 一筒,二筒,三筒 四筒,四筒 竹,菊,蘭,梅",
+        );
+        let player_id = "0".to_string();
+
+        let rules_with_flowers = game
+            .get_ruleset()
+            .get_scoring_rules(&game.get_scoring_context(&player_id));
+        assert!(rules_with_flowers.contains(&ScoringRule::AllFlowers));
+
+        game.house_rules.flowers_count = false;
+
+        let rules_without_flowers = game
+            .get_ruleset()
+            .get_scoring_rules(&game.get_scoring_context(&player_id));
+        assert!(!rules_without_flowers.contains(&ScoringRule::AllFlowers));
+        assert!(!rules_without_flowers.contains(&ScoringRule::NoFlowersSeasons));
+    }
+
+    fn get_discard_winners_game(summary: &str, discard_winners: DiscardWinners) -> Game {
+        let mut game = Game::from_summary(summary);
+        for player_id in game.players.0.clone() {
+            game.score.insert(&player_id, 0);
+        }
+        game.house_rules.discard_winners = discard_winners;
+        game
+    }
+
+    #[test]
+    fn test_house_rules_discard_winners_head_bump() {
+        let mut game = get_discard_winners_game(
+            "- P1: 一索
+             - P2: 三萬,三萬 一筒,二筒,三筒 四筒,五筒,六筒 七筒,八筒,九筒 一索,二索,三索
+             - P3: 一萬,二萬,東,東 四索,五索,六索 七索,八索,九索 南,南,南
+             - P4: 九筒
+             Turn: P1
+             Discarded: 三萬(P2)",
+            DiscardWinners::HeadBump,
+        );
+
+        game.say_mahjong(&"1".to_string()).unwrap();
+
+        assert!(game.score.get(&"1".to_string()).unwrap() > &0);
+        assert_eq!(game.score.get(&"0".to_string()), Some(&0));
+        assert_eq!(game.score.get(&"2".to_string()), Some(&0));
+
+        // P3 claims the discard but P2 is before in turn order, so P2 is the winner
+        let mut bumped_game = get_discard_winners_game(
+            "- P1: 一索
+             - P2: 一萬,二萬,東,東 四索,五索,六索 七索,八索,九索 南,南,南
+             - P3: 三萬,三萬 一筒,二筒,三筒 四筒,五筒,六筒 七筒,八筒,九筒 一索,二索,三索
+             - P4: 九筒
+             Turn: P1
+             Discarded: 三萬(P3)",
+            DiscardWinners::HeadBump,
+        );
+
+        assert_eq!(
+            bumped_game.get_other_discard_winners(&"2".to_string()),
+            vec!["1"]
+        );
+
+        bumped_game.say_mahjong(&"2".to_string()).unwrap();
+
+        assert!(bumped_game.score.get(&"1".to_string()).unwrap() > &0);
+        assert_eq!(bumped_game.score.get(&"0".to_string()), Some(&0));
+        assert_eq!(bumped_game.score.get(&"2".to_string()), Some(&0));
+        assert_eq!(bumped_game.table.hands.get_player_hand_len("1"), 14);
+        assert_eq!(bumped_game.table.hands.get_player_hand_len("2"), 13);
+        assert_eq!(bumped_game.round.player_index, 1);
+    }
+
+    #[test]
+    fn test_house_rules_discard_winners_first_caller() {
+        // P2 is before in turn order, but P3 says mahjong first
+        let mut game = get_discard_winners_game(
+            "- P1: 一索
+             - P2: 一萬,二萬,東,東 四索,五索,六索 七索,八索,九索 南,南,南
+             - P3: 三萬,三萬 一筒,二筒,三筒 四筒,五筒,六筒 七筒,八筒,九筒 一索,二索,三索
+             - P4: 九筒
+             Turn: P1
+             Discarded: 三萬(P3)",
+            DiscardWinners::default(),
+        );
+
+        game.say_mahjong(&"2".to_string()).unwrap();
+
+        assert!(game.score.get(&"2".to_string()).unwrap() > &0);
+        assert_eq!(game.score.get(&"0".to_string()), Some(&0));
+        assert_eq!(game.score.get(&"1".to_string()), Some(&0));
+        assert_eq!(
+            game.round.last_outcome,
+            Some(RoundOutcome::Mahjong(vec!["2".to_string()]))
+        );
+    }
+
+    #[test]
+    fn test_house_rules_discard_winners_multiple() {
+        let mut game = get_discard_winners_game(
+            "- P1: 一索
+             - P2: 三萬,三萬 一筒,二筒,三筒 四筒,五筒,六筒 七筒,八筒,九筒 一索,二索,三索
+             - P3: 一萬,二萬,東,東 四索,五索,六索 七索,八索,九索 南,南,南
+             - P4: 九筒
+             Turn: P1, Dealer: P3
+             Discarded: 三萬(P2)",
+            DiscardWinners::Multiple,
+        );
+
+        game.say_mahjong(&"1".to_string()).unwrap();
+
+        let first_winner_score = *game.score.get(&"1".to_string()).unwrap();
+        let second_winner_score = *game.score.get(&"2".to_string()).unwrap();
+
+        assert!(first_winner_score > 0);
+        assert!(second_winner_score > 0);
+        assert_eq!(
+            game.score.get(&"0".to_string()),
+            Some(&-(first_winner_score + second_winner_score))
+        );
+        assert_eq!(game.score.get(&"3".to_string()), Some(&0));
+        assert_eq!(game.table.hands.get_player_hand_len("2"), 13);
+
+        // The dealer is one of the winners, so it keeps the seat
+        assert_eq!(game.round.dealer_player_index, 2);
+        assert_eq!(game.round.consecutive_same_seats, 1);
+    }
+
+    #[test]
+    fn test_house_rules_discard_winners_skip_dead_hands() {
+        for discard_winners in [DiscardWinners::HeadBump, DiscardWinners::Multiple] {
+            let mut game = get_discard_winners_game(
+                "- P1: 一索
+                 - P2: 一萬,二萬,東,東 四索,五索,六索 七索,八索,九索 南,南,南
+                 - P3: 三萬,三萬 一筒,二筒,三筒 四筒,五筒,六筒 七筒,八筒,九筒 一索,二索,三索
+                 - P4: 九筒
+                 Turn: P1
+                 Discarded: 三萬(P3)",
+                discard_winners,
+            );
+            game.table.hands.0.get_mut("1").unwrap().is_dead = true;
+
+            assert!(game.get_other_discard_winners(&"2".to_string()).is_empty());
+
+            game.say_mahjong(&"2".to_string()).unwrap();
+
+            assert!(game.score.get(&"2".to_string()).unwrap() > &0);
+            assert_eq!(game.score.get(&"0".to_string()), Some(&0));
+            assert_eq!(game.score.get(&"1".to_string()), Some(&0));
+        }
+    }
+
+    #[test]
+    fn test_house_rules_concealed_kongs_shown() {
+        for concealed_kongs_shown in [false, true] {
+            let mut game = Game::from_summary(
+                "- P1: 一索
+                 - P2: 一萬 二萬,二萬,二萬,二萬
+                 - P3: 九筒
+                 - P4: 九索",
+            );
+            game.house_rules.concealed_kongs_shown = concealed_kongs_shown;
+
+            let summary = GameSummary::from_game(&game, &"0".to_string()).unwrap();
+            let visible = &summary.other_hands.0.get("1").unwrap().visible;
+            let expected_visible = if concealed_kongs_shown { 3 } else { 0 };
+
+            assert_eq!(visible.len(), expected_visible);
+            assert_eq!(visible.kong_tiles.len(), expected_visible / 3);
+        }
+    }
+
+    #[test]
+    fn test_house_rules_exhaustive_draw_settlement() {
+        for (dealer_summary, ready_hand_payment) in [("P1", None), ("P2", Some(1))] {
+            let mut game = Game::from_summary(&format!(
+                "- P1: 一萬,一萬,二萬,三萬
+                 - P2: 一萬,三萬,五萬,七萬
+                 - P3: 東,南,西,北
+                 - P4: 一筒
+                 Turn: P1, Dealer: {dealer_summary}"
+            ));
+            for player_id in game.players.0.clone() {
+                game.score.insert(&player_id, 0);
+            }
+            game.house_rules.dealer_keeps_seat_if_ready = true;
+            game.house_rules.ready_hand_payment = ready_hand_payment;
+
+            game.pass_null_round().unwrap();
+
+            let ready_players = vec!["0".to_string(), "3".to_string()];
+            let payment = ready_hand_payment.unwrap_or(0);
+
+            assert_eq!(
+                game.round.last_outcome,
+                Some(RoundOutcome::ExhaustiveDraw(ready_players))
+            );
+            assert_eq!(game.score.get(&"0".to_string()), Some(&(2 * payment)));
+            assert_eq!(game.score.get(&"1".to_string()), Some(&(-2 * payment)));
+            assert_eq!(game.score.get(&"3".to_string()), Some(&(2 * payment)));
+            assert_eq!(game.round.payments.len(), if payment > 0 { 4 } else { 0 });
+            assert!(game
+                .round
+                .payments
+                .iter()
+                .all(|round_payment| round_payment.reason == ScorePaymentReason::ReadyHand));
+
+            // Only the dealer that is ready keeps the seat
+            let expected_dealer = if dealer_summary == "P1" { 0 } else { 2 };
+            assert_eq!(game.round.dealer_player_index, expected_dealer);
+
+            let summary = GameSummary::from_game(&game, &"1".to_string()).unwrap();
+            assert_eq!(summary.other_hands.0.get("0").unwrap().visible.len(), 4);
+            assert_eq!(summary.other_hands.0.get("2").unwrap().visible.len(), 0);
+        }
+    }
+
+    #[test]
+    fn test_exhaustive_draw_settlement_at_the_end_of_the_game() {
+        let mut game = Game::from_summary(
+            "- P1: 一萬,一萬,二萬,三萬
+             - P2: 一萬,三萬,五萬,七萬
+             - P3: 東,南,西,北
+             - P4: 一筒
+             Turn: P1, Dealer: P4, Wind: 北",
+        );
+        for player_id in game.players.0.clone() {
+            game.score.insert(&player_id, 0);
+        }
+        game.house_rules.exhaustive_draw_passes_dealer = true;
+        game.house_rules.ready_hand_payment = Some(1);
+
+        game.pass_null_round().unwrap();
+
+        assert_eq!(game.phase, GamePhase::End);
+        assert_eq!(game.round.payments.len(), 4);
+        assert_eq!(game.score.get(&"0".to_string()), Some(&2));
+
+        // The payments are cleared when the next round is dealt
+        game.prepare_table(false);
+
+        assert!(game.round.payments.is_empty());
+    }
+}
@@ -1,6 +1,13 @@
 #[cfg(test)]
 mod test {
-    use crate::{score::ScoringRule, Game};
+    use crate::{
+        deck::DEFAULT_DECK,
+        game::GameStyle,
+        game_summary::GameSummary,
+        hand::{CanSayMahjongError, MeldSupplier},
+        score::{BonusBite, ScoringRule},
+        Game, Tile,
+    };
     use strum::IntoEnumIterator;
 
     #[test]
@@ -85,4 +92,179 @@ mod test {
             assert!(!scoring_rules.contains(&score_rule), "Rule: {}", score_rule);
         }
     }
+
+    #[test]
+    fn test_bonus_bites_paid_when_completed() {
+        let mut game = Game::from_summary(
+            "- P1: 一萬 貓,鼠
+             - P2: 二萬
+             - P3: 三萬
+             - P4: 四萬",
+        );
+        for player in game.players.0.clone() {
+            game.score.insert(&player, 0);
+        }
+        let player_id = "0".to_string();
+        let mouse_id = game
+            .table
+            .bonus_tiles
+            .get_or_create(&player_id)
+            .iter()
+            .find(|t| DEFAULT_DECK.0[**t].is_same_content(&Tile::from_summary("鼠")))
+            .cloned()
+            .unwrap();
+
+        assert_eq!(game.pay_bonus_bites(&player_id, mouse_id), vec![]);
+
+        game.style = GameStyle::Singapore;
+        let bites = game.pay_bonus_bites(&player_id, mouse_id);
+
+        assert_eq!(bites, vec![BonusBite::CatAndMouse]);
+        assert_eq!(game.score.get(&player_id), Some(&3));
+        assert_eq!(game.score.get(&"1".to_string()), Some(&-1));
+        assert_eq!(game.round.payments.len(), 3);
+    }
+
+    #[test]
+    fn test_bonus_bites_not_paid_when_incomplete() {
+        let mut game = Game::from_summary(
+            "- P1: 一萬 貓,雞
+             - P2: 二萬
+             - P3: 三萬
+             - P4: 四萬",
+        );
+        game.style = GameStyle::Singapore;
+        let player_id = "0".to_string();
+        let tiles = game.table.bonus_tiles.get_or_create(&player_id).clone();
+
+        for tile_id in tiles {
+            assert_eq!(game.pay_bonus_bites(&player_id, tile_id), vec![]);
+        }
+        assert!(game.round.payments.is_empty());
+    }
+
+    fn set_meld_suppliers(game: &mut Game, player_id: &str, suppliers: &[&str]) {
+        for other_player in game.players.0.clone() {
+            game.score.insert(&other_player, 0);
+        }
+
+        let hand = game.table.hands.0.get_mut(player_id).unwrap();
+        let mut set_ids = vec![];
+
+        for tile in hand.list.iter().filter(|t| !t.concealed) {
+            if !set_ids.contains(tile.set_id.as_ref().unwrap()) {
+                set_ids.push(tile.set_id.clone().unwrap());
+            }
+        }
+
+        hand.meld_suppliers = set_ids
+            .into_iter()
+            .zip(suppliers)
+            .map(|(set_id, supplier)| MeldSupplier {
+                player_id: supplier.to_string(),
+                set_id,
+            })
+            .collect();
+    }
+
+    #[test]
+    fn test_liability_great_dragons() {
+        let mut game = Game::from_summary(
+            "- P1: 一索
+             - P2: 一萬,一萬 一筒,二筒,三筒 *中,中,中 *發,發,發 *白,白,白
+             - P3: 九筒
+             - P4: 九索
+             Turn: P2",
+        );
+        game.house_rules.liability_payments = true;
+        set_meld_suppliers(&mut game, "1", &["2", "2", "0"]);
+
+        assert_eq!(
+            game.get_liable_player(&"1".to_string()),
+            Some("0".to_string())
+        );
+
+        game.say_mahjong(&"1".to_string()).unwrap();
+
+        let winner_score = *game.score.get(&"1".to_string()).unwrap();
+
+        assert!(winner_score > 0);
+        assert_eq!(game.score.get(&"0".to_string()), Some(&-winner_score));
+        assert_eq!(game.score.get(&"2".to_string()), Some(&0));
+    }
+
+    #[test]
+    fn test_liability_twelve_exposed_tiles() {
+        for liability_payments in [true, false] {
+            let mut game = Game::from_summary(
+                "- P1: 一索
+                 - P2: 三萬,三萬 *一筒,二筒,三筒 *四筒,五筒,六筒 *七筒,八筒,九筒 *一索,二索,三索
+                 - P3: 九筒
+                 - P4: 九索
+                 Turn: P1
+                 Discarded: 三萬(P2)",
+            );
+            game.house_rules.liability_payments = liability_payments;
+            set_meld_suppliers(&mut game, "1", &["3", "3", "2", "3"]);
+
+            game.say_mahjong(&"1".to_string()).unwrap();
+
+            let winner_score = *game.score.get(&"1".to_string()).unwrap();
+            let expected_discarder_score = if liability_payments { -winner_score } else { 0 };
+
+            assert!(winner_score > 0);
+            assert_eq!(
+                game.score.get(&"0".to_string()),
+                Some(&expected_discarder_score)
+            );
+            assert_eq!(game.score.get(&"3".to_string()), Some(&0));
+        }
+    }
+
+    #[test]
+    fn test_chombo_penalty() {
+        for (chombo_penalty, chombo_dead_hand) in [(None, false), (Some(8), false), (Some(8), true)]
+        {
+            let mut game = Game::from_summary(
+                "- P1: 一萬,一萬,一索,二索,四索 一筒,二筒,三筒 四筒,五筒,六筒 七筒,八筒,九筒
+                 - P2: 九筒
+                 - P3: 九索
+                 - P4: 九萬
+                 Turn: P1",
+            );
+            for player_id in game.players.0.clone() {
+                game.score.insert(&player_id, 0);
+            }
+            game.house_rules.chombo_penalty = chombo_penalty;
+            game.house_rules.chombo_dead_hand = chombo_dead_hand;
+            let player_id = "0".to_string();
+
+            assert!(game.say_mahjong(&player_id).is_err());
+            assert_eq!(
+                game.pay_chombo_penalty(&player_id),
+                chombo_penalty.is_some()
+            );
+
+            let penalty = chombo_penalty.unwrap_or(0);
+            let hand = game.table.hands.get(&player_id).unwrap();
+
+            assert_eq!(game.score.get(&player_id), Some(&(-3 * penalty)));
+            assert_eq!(game.score.get(&"1".to_string()), Some(&penalty));
+            assert_eq!(hand.is_revealed, chombo_penalty.is_some());
+            assert_eq!(hand.is_dead, chombo_dead_hand);
+
+            let summary = GameSummary::from_game(&game, &"1".to_string()).unwrap();
+            let visible_tiles = summary.other_hands.0.get(&player_id).unwrap().visible.len();
+
+            assert_eq!(visible_tiles == 14, chombo_penalty.is_some());
+
+            if chombo_dead_hand {
+                assert!(!game.pay_chombo_penalty(&player_id));
+                assert_eq!(
+                    game.say_mahjong(&player_id),
+                    Err(CanSayMahjongError::DeadHand)
+                );
+            }
+        }
+    }
 }
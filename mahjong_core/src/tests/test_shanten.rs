@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        game_summary::GameSummary,
+        ruleset::HongKongRuleset,
+        shanten::{
+            get_acceptance, get_seven_pairs_shanten, get_shanten, get_thirteen_orphans_shanten,
+            TileAcceptance,
+        },
+        tile_kind::get_kind_counts,
+        Game, Hand, Tile,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_get_shanten() {
+        for (summary, expected) in [
+            (
+                "一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一筒,一筒,東,東,東",
+                -1,
+            ),
+            (
+                "一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一筒,一筒,東,東",
+                0,
+            ),
+            (
+                "一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一筒,一筒,東,南",
+                1,
+            ),
+            (
+                "一萬,一萬,二萬,二萬,三筒,三筒,四筒,四筒,五索,五索,東,東,南",
+                0,
+            ),
+            ("一萬,九萬,一索,九索,一筒,九筒,東,南,西,北,中,發,白", 0),
+            (
+                "一萬,九萬,一索,九索,一筒,九筒,東,南,西,北,中,發,白,一萬",
+                -1,
+            ),
+            // The special shapes are not possible with exposed melds
+            (
+                "一萬,二萬,三萬,四萬 *五筒,五筒,五筒 *六筒,六筒,六筒 *七筒,七筒,七筒",
+                0,
+            ),
+            (
+                "一萬,四萬,七萬,一筒,四筒,七筒,一索,四索,七索,東,南,西,北",
+                6,
+            ),
+        ] {
+            let (counts, melds_num) = Hand::from_summary(summary).get_kind_counts();
+
+            assert_eq!(get_shanten(&counts, melds_num, true), expected, "{summary}");
+        }
+    }
+
+    #[test]
+    fn test_special_shapes_are_not_ready_in_hong_kong() {
+        let ruleset = HongKongRuleset::default();
+        let hand = Hand::from_summary("一萬,一萬,二萬,二萬,三筒,三筒,四筒,四筒,五索,五索,東,東,南");
+
+        assert_eq!(hand.get_shanten(&ruleset), 3);
+        assert!(!hand.is_ready(&ruleset));
+
+        let game = Game::from_summary(
+            "- P1: 一萬,一萬,二萬,二萬,三筒,三筒,四筒,四筒,五索,五索,東,東,南
+             - P2: 九索
+             - P3: 九筒
+             - P4: 一索",
+        );
+        let game_summary = GameSummary::from_game(&game, &"0".to_string()).unwrap();
+        let analysis = game_summary.get_shanten_analysis().unwrap();
+
+        assert_eq!(analysis.shanten, 3);
+    }
+
+    #[test]
+    fn test_special_shapes_shanten() {
+        let get_counts = |summary: &str| get_kind_counts(Tile::ids_from_summary(summary));
+
+        let pairs_counts = get_counts("一萬,一萬,二萬,二萬,三筒,三筒,四筒,四筒,五索,五索,東,東,東");
+        assert_eq!(get_seven_pairs_shanten(&pairs_counts), 1);
+
+        let orphans_counts = get_counts("一萬,九萬,一索,九索,一筒,九筒,東,南,西,北,中,中,五萬");
+        assert_eq!(get_thirteen_orphans_shanten(&orphans_counts), 1);
+    }
+
+    #[test]
+    fn test_get_acceptance() {
+        let hand =
+            Hand::from_summary("一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一筒,一筒,東,東");
+        let (counts, melds_num) = hand.get_kind_counts();
+
+        let acceptance = get_acceptance(&counts, melds_num, false)
+            .into_iter()
+            .map(|kind| kind.get_tile_id())
+            .collect::<Vec<_>>();
+
+        assert_eq!(acceptance, Tile::ids_from_summary("一筒,東"));
+    }
+
+    #[test]
+    fn test_get_shanten_analysis() {
+        let game = Game::from_summary(
+            "- P1: 一萬,二萬,三萬,四萬,五萬,六萬,七萬,八萬,九萬,一筒,一筒,東,東,北
+             - P2: 九索 *一筒,二筒,三筒
+             - P3: 九筒
+             - P4: 一索
+             Board: 東",
+        );
+        let game_summary = GameSummary::from_game(&game, &"0".to_string()).unwrap();
+
+        let analysis = game_summary.get_shanten_analysis().unwrap();
+        let best_discard = &analysis.discards[0];
+
+        assert_eq!(analysis.shanten, 0);
+        assert_eq!(best_discard.tile, Tile::id_from_summary("北"));
+        assert_eq!(best_discard.shanten, 0);
+        assert_eq!(
+            best_discard.acceptance,
+            vec![
+                TileAcceptance {
+                    tile: Tile::id_from_summary("一筒"),
+                    unseen: 1,
+                },
+                TileAcceptance {
+                    tile: Tile::id_from_summary("東"),
+                    unseen: 1,
+                },
+            ]
+        );
+        assert!(analysis.discards[1..]
+            .iter()
+            .all(|discard| discard.shanten > 0));
+    }
+}
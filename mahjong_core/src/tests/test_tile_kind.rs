@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        deck::DEFAULT_DECK,
+        meld::get_winning_shape_groups,
+        tile_kind::{get_kind_counts, TileKind, BONUS_TILE_KINDS_NUM, TILE_KINDS_NUM},
+        Tile,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_deck_kinds() {
+        let mut copies = [0; TILE_KINDS_NUM + BONUS_TILE_KINDS_NUM];
+
+        for tile in DEFAULT_DECK.0.iter() {
+            let kind = TileKind::from_id(tile.get_id());
+
+            assert_eq!(kind, tile.get_kind());
+            assert_eq!(kind.is_bonus(), tile.is_bonus());
+            assert_eq!(kind.is_terminal_or_honour(), tile.is_terminal_or_honour());
+            assert!(DEFAULT_DECK.0[kind.get_tile_id()].is_same_content(tile));
+
+            copies[kind.index()] += 1;
+        }
+
+        assert_eq!(copies[..TILE_KINDS_NUM], [4; TILE_KINDS_NUM]);
+        assert_eq!(copies[TILE_KINDS_NUM..], [1; BONUS_TILE_KINDS_NUM]);
+    }
+
+    #[test]
+    fn test_get_kind_counts() {
+        let counts = get_kind_counts(Tile::ids_from_summary("一索,一索,九筒,東,白,梅"));
+
+        assert_eq!(counts.iter().map(|count| *count as usize).sum::<usize>(), 5);
+        assert_eq!(counts[Tile::from_summary("一索").get_kind().index()], 2);
+        assert_eq!(counts[Tile::from_summary("九筒").get_kind().index()], 1);
+        assert_eq!(counts[Tile::from_summary("白").get_kind().index()], 1);
+    }
+
+    #[test]
+    fn test_get_winning_shape_groups() {
+        for (summary, expected_groups) in [
+            (
+                "一萬,一萬,一萬,二萬,二萬,二萬,三萬,三萬,三萬,東,東",
+                Some(vec![
+                    "東,東",
+                    "一萬,一萬,一萬",
+                    "二萬,二萬,二萬",
+                    "三萬,三萬,三萬",
+                ]),
+            ),
+            (
+                "一萬,一萬,一萬,二萬,三萬,四萬,五萬,五萬",
+                Some(vec!["五萬,五萬", "一萬,一萬,一萬", "二萬,三萬,四萬"]),
+            ),
+            ("八萬,九萬,一筒,東,東", None),
+            ("東,南,西,北,北", None),
+        ] {
+            let tiles = Tile::ids_from_summary(summary);
+            let groups = get_winning_shape_groups(&tiles).map(|groups| {
+                groups
+                    .iter()
+                    .map(|group| Tile::ids_from_summary(&Tile::summary_from_ids(group)))
+                    .collect::<Vec<_>>()
+            });
+            let expected = expected_groups.map(|expected_summaries| {
+                expected_summaries
+                    .iter()
+                    .map(|group| Tile::ids_from_summary(group))
+                    .collect::<Vec<_>>()
+            });
+
+            assert_eq!(groups, expected, "{summary}");
+        }
+    }
+}
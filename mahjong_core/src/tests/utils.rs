@@ -1,4 +1,5 @@
-use crate::{Game, Players};
+use crate::{ai::StandardAI, Game, GamePhase, Players};
+use rustc_hash::FxHashSet;
 
 impl Game {
     pub fn start_with_players(&mut self) {
@@ -9,4 +10,27 @@ impl Game {
         self.players.push("3".to_string());
         self.start(false);
     }
+
+    // Plays with the AI until the current player has to discard, after some discards
+    pub fn play_until_middle_of_round(&mut self) {
+        self.start_with_players();
+        let ai_players = FxHashSet::from_iter(self.players.0.clone());
+        let mut game_ai = StandardAI::new(self, ai_players, FxHashSet::default());
+        game_ai.dealer_order_deterministic = Some(true);
+        game_ai.can_draw_round = true;
+
+        for _ in 0..1000 {
+            let game = &game_ai.game;
+            if game.phase == GamePhase::Playing
+                && game.table.board.len() >= 12
+                && game
+                    .get_current_player()
+                    .is_some_and(|player_id| game.table.hands.get(&player_id).unwrap().len() == 14)
+            {
+                return;
+            }
+
+            game_ai.play_action(false);
+        }
+    }
 }
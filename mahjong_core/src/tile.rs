@@ -1,4 +1,6 @@
-use crate::{DragonTile, FlowerTile, SeasonTile, SuitTile, WindTile};
+use crate::{
+    tile_kind::TileKind, AnimalTile, DragonTile, FlowerTile, SeasonTile, SuitTile, WindTile,
+};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
@@ -12,6 +14,7 @@ pub enum Tile {
     Wind(WindTile),
     Flower(FlowerTile),
     Season(SeasonTile),
+    Animal(AnimalTile),
 }
 
 impl Tile {
@@ -22,6 +25,7 @@ impl Tile {
             Self::Wind(tile) => tile.id,
             Self::Season(tile) => tile.id,
             Self::Flower(tile) => tile.id,
+            Self::Animal(tile) => tile.id,
         }
     }
 
@@ -32,32 +36,16 @@ impl Tile {
             Self::Wind(_) => matches!(tile_b, Self::Wind(_)),
             Self::Season(_) => matches!(tile_b, Self::Season(_)),
             Self::Flower(_) => matches!(tile_b, Self::Flower(_)),
+            Self::Animal(_) => matches!(tile_b, Self::Animal(_)),
         }
     }
 
+    pub fn get_kind(&self) -> TileKind {
+        TileKind::from_tile(self)
+    }
+
     pub fn is_same_content(&self, tile_b: &Self) -> bool {
-        match self {
-            Self::Suit(tile_a) => match tile_b {
-                Self::Suit(tile_b) => tile_a.suit == tile_b.suit && tile_a.value == tile_b.value,
-                _ => false,
-            },
-            Self::Dragon(tile_a) => match tile_b {
-                Self::Dragon(tile_b) => tile_a.value == tile_b.value,
-                _ => false,
-            },
-            Self::Wind(tile_a) => match tile_b {
-                Self::Wind(tile_b) => tile_a.value == tile_b.value,
-                _ => false,
-            },
-            Self::Season(tile_a) => match tile_b {
-                Self::Season(tile_b) => tile_a.value == tile_b.value,
-                _ => false,
-            },
-            Self::Flower(tile_a) => match tile_b {
-                Self::Flower(tile_b) => tile_a.value == tile_b.value,
-                _ => false,
-            },
-        }
+        self.get_kind() == tile_b.get_kind()
     }
 
     fn cmp_custom_order(tile: &Self) -> u32 {
@@ -67,6 +55,7 @@ impl Tile {
             Self::Wind(_) => 2,
             Self::Season(_) => 3,
             Self::Flower(_) => 4,
+            Self::Animal(_) => 5,
         }
     }
 
@@ -101,13 +90,26 @@ impl Tile {
                     return tile_a.value.cmp(&tile_b.value);
                 }
             }
+            Self::Animal(tile_a) => {
+                if let Self::Animal(tile_b) = other {
+                    return tile_a.value.cmp(&tile_b.value);
+                }
+            }
         };
 
         Self::cmp_custom_order(self).cmp(&Self::cmp_custom_order(other))
     }
 
     pub fn is_bonus(&self) -> bool {
-        matches!(self, Self::Flower(_) | Self::Season(_))
+        matches!(self, Self::Flower(_) | Self::Season(_) | Self::Animal(_))
+    }
+
+    pub fn is_terminal_or_honour(&self) -> bool {
+        match self {
+            Self::Suit(tile) => tile.value == 1 || tile.value == 9,
+            Self::Dragon(_) | Self::Wind(_) => true,
+            _ => false,
+        }
     }
 }
 
@@ -119,6 +121,7 @@ impl Tile {
             Self::Wind(tile) => tile.id = id,
             Self::Season(tile) => tile.id = id,
             Self::Flower(tile) => tile.id = id,
+            Self::Animal(tile) => tile.id = id,
         }
     }
 }
@@ -0,0 +1,131 @@
+// The content of a tile without the id, so the copies of the same tile have the same kind. The first
+// kinds are the ones that can be part of melds, in the order: bamboo, characters and dots (from 1
+// to 9), winds (in the round order) and dragons. After them come the bonus tiles.
+
+use crate::{
+    deck::DEFAULT_DECK, Dragon, Tile, TileId, ANIMALS_ORDER, FLOWERS_ORDER, SEASONS_ORDER,
+    WINDS_ROUND_ORDER,
+};
+use lazy_static::lazy_static;
+
+pub const TILE_KINDS_NUM: usize = 34;
+pub const BONUS_TILE_KINDS_NUM: usize = 12;
+
+const SUIT_KINDS_NUM: u8 = 27;
+const WINDS_FIRST_KIND: u8 = 27;
+const DRAGONS_FIRST_KIND: u8 = 31;
+
+// The number of copies of each tile kind in the hand, excluding the bonus tiles
+pub type TileKindCounts = [u8; TILE_KINDS_NUM];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TileKind(u8);
+
+lazy_static! {
+    // Indexed by the tile id, so the kind of a tile doesn't need to match the content
+    static ref TILES_KINDS: Vec<TileKind> = DEFAULT_DECK.0.iter().map(TileKind::from_tile).collect();
+    // The first tile of the deck of each kind
+    static ref KINDS_TILES: Vec<TileId> = (0..TILE_KINDS_NUM + BONUS_TILE_KINDS_NUM)
+        .map(|index| {
+            TILES_KINDS
+                .iter()
+                .position(|kind| kind.index() == index)
+                .unwrap()
+        })
+        .collect();
+}
+
+impl TileKind {
+    pub fn from_tile(tile: &Tile) -> Self {
+        Self(match tile {
+            Tile::Suit(tile) => tile.suit as u8 * 9 + tile.value as u8 - 1,
+            Tile::Wind(tile) => WINDS_FIRST_KIND + get_position(WINDS_ROUND_ORDER, &tile.value),
+            Tile::Dragon(tile) => {
+                DRAGONS_FIRST_KIND
+                    + match tile.value {
+                        Dragon::Green => 0,
+                        Dragon::Red => 1,
+                        Dragon::White => 2,
+                    }
+            }
+            Tile::Flower(tile) => TILE_KINDS_NUM as u8 + get_position(FLOWERS_ORDER, &tile.value),
+            Tile::Season(tile) => {
+                TILE_KINDS_NUM as u8 + 4 + get_position(SEASONS_ORDER, &tile.value)
+            }
+            Tile::Animal(tile) => {
+                TILE_KINDS_NUM as u8 + 8 + get_position(ANIMALS_ORDER, &tile.value)
+            }
+        })
+    }
+
+    pub fn from_id(id: TileId) -> Self {
+        TILES_KINDS[id]
+    }
+
+    pub fn from_index(index: usize) -> Self {
+        debug_assert!(index < TILE_KINDS_NUM + BONUS_TILE_KINDS_NUM);
+
+        Self(index as u8)
+    }
+
+    // The kinds that can be part of melds
+    pub fn iter_normal() -> impl Iterator<Item = Self> {
+        (0..TILE_KINDS_NUM).map(Self::from_index)
+    }
+
+    pub fn index(&self) -> usize {
+        self.0 as usize
+    }
+
+    pub fn is_bonus(&self) -> bool {
+        self.index() >= TILE_KINDS_NUM
+    }
+
+    pub fn is_suit(&self) -> bool {
+        self.0 < SUIT_KINDS_NUM
+    }
+
+    pub fn is_terminal_or_honour(&self) -> bool {
+        !self.is_bonus() && (!self.is_suit() || matches!(self.get_suit_value(), Some(1 | 9)))
+    }
+
+    // From 1 to 9, only for the suit tiles
+    pub fn get_suit_value(&self) -> Option<u8> {
+        self.is_suit().then_some(self.0 % 9 + 1)
+    }
+
+    pub fn is_same_suit(&self, other: &Self) -> bool {
+        self.is_suit() && other.is_suit() && self.0 / 9 == other.0 / 9
+    }
+
+    pub fn get_deck_copies(&self) -> usize {
+        if self.is_bonus() {
+            1
+        } else {
+            4
+        }
+    }
+
+    // The representative tile of the kind, which is the first one in the deck
+    pub fn get_tile_id(&self) -> TileId {
+        KINDS_TILES[self.index()]
+    }
+}
+
+fn get_position<T: PartialEq>(list: &[T], value: &T) -> u8 {
+    list.iter().position(|item| item == value).unwrap() as u8
+}
+
+pub fn get_kind_counts(ids: impl IntoIterator<Item = TileId>) -> TileKindCounts {
+    let mut counts = [0; TILE_KINDS_NUM];
+
+    for id in ids {
+        let kind = TileKind::from_id(id);
+
+        if !kind.is_bonus() {
+            counts[kind.index()] += 1;
+        }
+    }
+
+    counts
+}
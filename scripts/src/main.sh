@@ -21,6 +21,7 @@ cd "$SCRIPTPATH/../.."
 
 USAGE="bash src/main.sh <command>
 Run various scripts for the Mahjong project
+  - bench: Run the benchmarks of the core hand checks and the simulation
   - check: Run all checks
   - check_docker: Run all checks inside docker
   - clippy: Run only clippy checks
@@ -39,6 +40,9 @@ Run various scripts for the Mahjong project
 
 main() {
   case "$1" in
+  bench)
+    run_bench
+    ;;
   check)
     run_check
     ;;
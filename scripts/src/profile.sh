@@ -6,3 +6,8 @@ profile_instruments() {
   cd cli
   cargo instruments -t time --release -- simulate -o
 }
+
+run_bench() {
+  cd mahjong_core
+  cargo bench --bench hand
+}
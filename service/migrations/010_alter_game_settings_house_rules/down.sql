@@ -0,0 +1 @@
+ALTER TABLE game_settings DROP COLUMN IF EXISTS house_rules;
@@ -0,0 +1 @@
+ALTER TABLE game_settings ADD COLUMN IF NOT EXISTS house_rules TEXT NOT NULL DEFAULT '{}';
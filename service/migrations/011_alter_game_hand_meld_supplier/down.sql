@@ -0,0 +1,2 @@
+ALTER TABLE game_hand DROP COLUMN IF EXISTS meld_supplier;
+ALTER TABLE game_hand DROP COLUMN IF EXISTS meld_supplier_index;
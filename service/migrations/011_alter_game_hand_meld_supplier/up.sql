@@ -0,0 +1,2 @@
+ALTER TABLE game_hand ADD COLUMN IF NOT EXISTS meld_supplier TEXT NULL;
+ALTER TABLE game_hand ADD COLUMN IF NOT EXISTS meld_supplier_index INT NULL;
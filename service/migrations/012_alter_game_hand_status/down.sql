@@ -0,0 +1,2 @@
+ALTER TABLE game_hand DROP COLUMN IF EXISTS is_dead;
+ALTER TABLE game_hand DROP COLUMN IF EXISTS is_revealed;
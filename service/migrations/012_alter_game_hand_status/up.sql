@@ -0,0 +1,2 @@
+ALTER TABLE game_hand ADD COLUMN IF NOT EXISTS is_dead BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE game_hand ADD COLUMN IF NOT EXISTS is_revealed BOOLEAN NOT NULL DEFAULT FALSE;
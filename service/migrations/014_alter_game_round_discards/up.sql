@@ -0,0 +1 @@
+ALTER TABLE game ADD COLUMN IF NOT EXISTS round_discards TEXT NULL;
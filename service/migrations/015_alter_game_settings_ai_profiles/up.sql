@@ -0,0 +1 @@
+ALTER TABLE game_settings ADD COLUMN IF NOT EXISTS ai_profiles TEXT NOT NULL DEFAULT '{}';
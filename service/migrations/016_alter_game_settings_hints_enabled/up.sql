@@ -0,0 +1 @@
+ALTER TABLE game_settings ADD COLUMN IF NOT EXISTS hints_enabled BOOLEAN NOT NULL DEFAULT TRUE;
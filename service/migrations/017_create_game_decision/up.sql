@@ -0,0 +1,9 @@
+DROP TABLE IF EXISTS game_decision;
+CREATE TABLE IF NOT EXISTS game_decision (
+    action TEXT NOT NULL,
+    decision_index INT NOT NULL,
+    game_id TEXT NOT NULL REFERENCES game (id) ON DELETE CASCADE,
+    game_summary TEXT NOT NULL,
+    player_id TEXT NOT NULL REFERENCES player (id) ON DELETE CASCADE,
+    PRIMARY KEY (game_id, decision_index)
+);
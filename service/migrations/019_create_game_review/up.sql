@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS game_review (
+    game_id TEXT NOT NULL REFERENCES game (id) ON DELETE CASCADE,
+    player_id TEXT NOT NULL REFERENCES player (id) ON DELETE CASCADE,
+    review TEXT NOT NULL,
+    PRIMARY KEY (game_id, player_id)
+);
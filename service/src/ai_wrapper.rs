@@ -6,6 +6,7 @@ use service_contracts::{GameSettings, ServiceGame};
 pub struct AIWrapper<'a> {
     standard_ai: StandardAI<'a>,
     game_settings: &'a mut GameSettings,
+    pub with_metadata: bool,
 }
 
 impl<'a> AIWrapper<'a> {
@@ -26,6 +27,10 @@ impl<'a> AIWrapper<'a> {
             standard_ai.sort_on_initial_draw = true;
         }
 
+        for (player_id, profile) in service_game.settings.ai_profiles.iter() {
+            standard_ai.set_agent(player_id, profile.create_agent());
+        }
+
         standard_ai.dealer_order_deterministic = Some(false);
         standard_ai.with_dead_wall = service_game.settings.dead_wall;
         standard_ai.shuffle_players = true;
@@ -33,6 +38,7 @@ impl<'a> AIWrapper<'a> {
         Self {
             standard_ai,
             game_settings: &mut service_game.settings,
+            with_metadata: false,
         }
     }
 
@@ -59,7 +65,7 @@ impl<'a> AIWrapper<'a> {
                 .contains(&current_player);
         }
 
-        let result = self.standard_ai.play_action(false);
+        let result = self.standard_ai.play_action(self.with_metadata);
 
         if result.changed && result.exit_location == PlayExitLocation::TileDiscarded {
             self.game_settings.last_discard_time = now_time;
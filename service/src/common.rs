@@ -1,12 +1,21 @@
 use crate::auth::{AuthInfo, GetAuthInfo};
 use async_trait::async_trait;
-use mahjong_core::{GameId, PlayerId};
+use mahjong_core::{
+    ai::{GameDecision, GameReview},
+    GameId, PlayerId,
+};
 use service_contracts::{ServiceGame, ServicePlayer, ServicePlayerGame};
 
 #[async_trait]
 pub trait Storage: Send + Sync {
     async fn get_auth_info(&self, get_auth_info: GetAuthInfo) -> Result<Option<AuthInfo>, String>;
     async fn get_game(&self, id: &GameId, use_cache: bool) -> Result<Option<ServiceGame>, String>;
+    async fn get_game_decisions(&self, game_id: &GameId) -> Result<Vec<GameDecision>, String>;
+    async fn get_game_review(
+        &self,
+        game_id: &GameId,
+        player_id: &PlayerId,
+    ) -> Result<Option<GameReview>, String>;
     async fn get_player_games(
         &self,
         player_id: &Option<PlayerId>,
@@ -15,6 +24,17 @@ pub trait Storage: Send + Sync {
     async fn get_player_total_score(&self, id: &PlayerId) -> Result<i32, String>;
     async fn save_auth_info(&self, auth_info: &AuthInfo) -> Result<(), String>;
     async fn save_game(&self, game: &ServiceGame) -> Result<(), String>;
+    async fn save_game_decision(
+        &self,
+        game_id: &GameId,
+        decision: &GameDecision,
+    ) -> Result<(), String>;
+    async fn save_game_review(
+        &self,
+        game_id: &GameId,
+        player_id: &PlayerId,
+        review: &GameReview,
+    ) -> Result<(), String>;
     async fn save_player(&self, player: &ServicePlayer) -> Result<(), String>;
     async fn delete_games(&self, ids: &[GameId]) -> Result<(), String>;
 }
@@ -11,7 +11,10 @@ use async_trait::async_trait;
 use diesel::pg::PgConnection;
 use diesel::prelude::*;
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
-use mahjong_core::{Game, GameId, PlayerId, Players};
+use mahjong_core::{
+    ai::{GameDecision, GameReview},
+    Game, GameId, PlayerId, Players,
+};
 use redis::Commands;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
@@ -22,8 +25,8 @@ pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
 
 use self::{
     models::{
-        DieselAuthInfoAnonymous, DieselGameBoard, DieselGameDrawWall, DieselGameHand,
-        DieselGameSettings,
+        DieselAuthInfoAnonymous, DieselGameBoard, DieselGameDecision, DieselGameDrawWall,
+        DieselGameHand, DieselGameReview, DieselGameSettings,
     },
     models_translation::DieselGameExtra,
 };
@@ -204,17 +207,40 @@ impl Storage for DBStorage {
         game.table.board = board;
         game.table.draw_wall = draw_wall;
 
+        let settings = settings.unwrap();
+        game.house_rules = settings.house_rules.clone();
+
         let service_game = ServiceGame {
             created_at: game_extra.created_at.and_utc().timestamp_millis(),
             game,
             players,
-            settings: settings.unwrap(),
+            settings,
             updated_at: game_extra.updated_at.and_utc().timestamp_millis(),
         };
 
         Ok(Some(service_game))
     }
 
+    async fn get_game_decisions(&self, game_id: &GameId) -> Result<Vec<GameDecision>, String> {
+        let mut connection = PgConnection::establish(&self.db_path).unwrap();
+
+        let decisions = DieselGameDecision::read_from_game(&mut connection, game_id);
+
+        Ok(decisions)
+    }
+
+    async fn get_game_review(
+        &self,
+        game_id: &GameId,
+        player_id: &PlayerId,
+    ) -> Result<Option<GameReview>, String> {
+        let mut connection = PgConnection::establish(&self.db_path).unwrap();
+
+        let review = DieselGameReview::read(&mut connection, game_id, player_id);
+
+        Ok(review)
+    }
+
     async fn get_player_games(
         &self,
         player_id: &Option<PlayerId>,
@@ -241,6 +267,31 @@ impl Storage for DBStorage {
         Ok(player)
     }
 
+    async fn save_game_decision(
+        &self,
+        game_id: &GameId,
+        decision: &GameDecision,
+    ) -> Result<(), String> {
+        let mut connection = PgConnection::establish(&self.db_path).unwrap();
+
+        DieselGameDecision::save(&mut connection, game_id, decision);
+
+        Ok(())
+    }
+
+    async fn save_game_review(
+        &self,
+        game_id: &GameId,
+        player_id: &PlayerId,
+        review: &GameReview,
+    ) -> Result<(), String> {
+        let mut connection = PgConnection::establish(&self.db_path).unwrap();
+
+        DieselGameReview::save(&mut connection, game_id, player_id, review);
+
+        Ok(())
+    }
+
     async fn save_player(&self, player: &ServicePlayer) -> Result<(), String> {
         let mut connection = PgConnection::establish(&self.db_path).unwrap();
 
@@ -258,6 +309,8 @@ impl Storage for DBStorage {
         DieselGameDrawWall::delete_games(&mut connection, ids);
         DieselGameHand::delete_games(&mut connection, ids);
         DieselGameSettings::delete_games(&mut connection, ids);
+        DieselGameDecision::delete_games(&mut connection, ids);
+        DieselGameReview::delete_games(&mut connection, ids);
         DieselGame::delete_games(&mut connection, ids);
 
         Ok(())
@@ -4,9 +4,11 @@ use super::schema::auth_info_email;
 use super::schema::auth_info_github;
 use super::schema::game;
 use super::schema::game_board;
+use super::schema::game_decision;
 use super::schema::game_draw_wall;
 use super::schema::game_hand;
 use super::schema::game_player;
+use super::schema::game_review;
 use super::schema::game_score;
 use super::schema::game_settings;
 use super::schema::player;
@@ -69,9 +71,12 @@ pub struct DieselGame {
     pub round_claimed_id: Option<i32>,
     pub round_consecutive_same_seats: i32,
     pub round_dealer_index: i32,
+    pub round_discards: Option<String>,
     pub round_east_player_index: i32,
     pub round_index: i32,
     pub round_initial_winds: Option<i32>,
+    pub round_last_outcome: Option<String>,
+    pub round_payments: Option<String>,
     pub round_player_index: i32,
     pub round_wall_tile_drawn: Option<i32>,
     pub round_wind: String,
@@ -99,6 +104,24 @@ pub struct DieselGameScore {
     pub score: i32,
 }
 
+#[derive(Insertable, Queryable, Clone)]
+#[diesel(table_name = game_decision)]
+pub struct DieselGameDecision {
+    pub action: String,
+    pub decision_index: i32,
+    pub game_id: GameId,
+    pub game_summary: String,
+    pub player_id: PlayerId,
+}
+
+#[derive(Insertable, Queryable, Clone)]
+#[diesel(table_name = game_review)]
+pub struct DieselGameReview {
+    pub game_id: GameId,
+    pub player_id: PlayerId,
+    pub review: String,
+}
+
 #[derive(Insertable, Queryable, Clone)]
 #[diesel(table_name = game_board)]
 pub struct DieselGameBoard {
@@ -126,6 +149,10 @@ pub struct DieselGameHand {
     pub tile_id: i32,
     pub tile_index: i32,
     pub is_kong: bool,
+    pub meld_supplier: Option<String>,
+    pub meld_supplier_index: Option<i32>,
+    pub is_dead: bool,
+    pub is_revealed: bool,
 }
 
 #[derive(Insertable, AsChangeset, Queryable, Clone)]
@@ -140,4 +167,7 @@ pub struct DieselGameSettings {
     pub game_id: GameId,
     pub last_discard_time: i64,
     pub dead_wall: bool,
+    pub house_rules: String,
+    pub ai_profiles: String,
+    pub hints_enabled: bool,
 }
@@ -1,27 +1,30 @@
 use super::models::{
     DieselAuthInfo, DieselAuthInfoAnonymous, DieselAuthInfoEmail, DieselAuthInfoGithub, DieselGame,
-    DieselGameBoard, DieselGameDrawWall, DieselGameHand, DieselGamePlayer, DieselGameScore,
-    DieselGameSettings, DieselPlayer,
+    DieselGameBoard, DieselGameDecision, DieselGameDrawWall, DieselGameHand, DieselGamePlayer,
+    DieselGameReview, DieselGameScore, DieselGameSettings, DieselPlayer,
 };
 use super::schema;
 use crate::auth::{AuthInfo, AuthInfoAnonymous, AuthInfoData, AuthInfoEmail, AuthInfoGithub};
 use diesel::prelude::*;
+use diesel::sql_types::Text;
 use diesel::PgConnection;
+use mahjong_core::ai::{GameDecision, GameReview};
 use mahjong_core::deck::DEFAULT_DECK;
-use mahjong_core::hand::KongTile;
+use mahjong_core::hand::{KongTile, MeldSupplier};
 use mahjong_core::{
     game::GameStyle,
     round::{Round, RoundTileClaimed},
 };
 use mahjong_core::{
     Board, BonusTiles, DrawWall, DrawWallPlace, Game, GameId, Hand, HandTile, Hands, PlayerId,
-    Score, ScoreMap, TileId,
+    Score, ScoreMap, SetId, TileId,
 };
 use rustc_hash::FxHashMap;
 use schema::player::dsl as player_dsl;
 use service_contracts::{
     AuthProvider, GameSettings, ServiceGame, ServicePlayer, ServicePlayerGame,
 };
+use std::collections::BTreeMap;
 use std::str::FromStr;
 use tracing::debug;
 
@@ -476,6 +479,17 @@ impl DieselGame {
             consecutive_same_seats: self.round_consecutive_same_seats as usize,
             east_player_index: self.round_east_player_index as usize,
             initial_winds: self.round_initial_winds.map(|w| w as u8),
+            payments: self
+                .round_payments
+                .map(|payments| serde_json::from_str(&payments).unwrap())
+                .unwrap_or_default(),
+            last_outcome: self
+                .round_last_outcome
+                .map(|outcome| serde_json::from_str(&outcome).unwrap()),
+            discards: self
+                .round_discards
+                .map(|discards| serde_json::from_str(&discards).unwrap())
+                .unwrap_or_default(),
         };
         let game = Game {
             name: self.name,
@@ -573,6 +587,13 @@ impl DieselGame {
             round_consecutive_same_seats: raw.round.consecutive_same_seats as i32,
             round_east_player_index: raw.round.east_player_index as i32,
             round_initial_winds: raw.round.initial_winds.map(|w| w as i32),
+            round_last_outcome: raw
+                .round
+                .last_outcome
+                .as_ref()
+                .map(|outcome| serde_json::to_string(outcome).unwrap()),
+            round_discards: Some(serde_json::to_string(&raw.round.discards).unwrap()),
+            round_payments: Some(serde_json::to_string(&raw.round.payments).unwrap()),
         }
     }
 
@@ -698,7 +719,7 @@ impl DieselGameScore {
                     .map(|(player_id, score)| Self {
                         game_id: service_game.game.id.clone(),
                         player_id: player_id.clone(),
-                        score: *score as i32,
+                        score: *score,
                     })
                     .collect::<Vec<Self>>();
 
@@ -731,11 +752,7 @@ impl DieselGameScore {
             wait_common();
         }
         .into_iter()
-        .map(|game_score| {
-            let score = game_score.score as u32;
-
-            (game_score.player_id, score)
-        })
+        .map(|game_score| (game_score.player_id, game_score.score))
         .collect::<ScoreMap>();
 
         Score(score_map)
@@ -940,15 +957,28 @@ impl DieselGameHand {
             .0
             .iter()
             .for_each(|(player_id, hand)| {
+                let get_meld_supplier = |set_id: &SetId| {
+                    hand.meld_suppliers
+                        .iter()
+                        .position(|s| Some(&s.set_id) == set_id.as_ref())
+                        .map(|index| (hand.meld_suppliers[index].player_id.clone(), index as i32))
+                        .unzip()
+                };
+
                 hand.list.iter().enumerate().for_each(|(tile_index, tile)| {
                     let tile_id = tile.id;
                     let concealed = if tile.concealed { 1 } else { 0 };
                     let set_id = tile.set_id.clone();
+                    let (meld_supplier, meld_supplier_index) = get_meld_supplier(&set_id);
 
                     let game_hand = Self {
                         concealed,
                         game_id: service_game.game.id.clone(),
+                        is_dead: hand.is_dead,
                         is_kong: false,
+                        is_revealed: hand.is_revealed,
+                        meld_supplier,
+                        meld_supplier_index,
                         player_id: player_id.clone(),
                         set_id,
                         tile_id: tile_id as i32,
@@ -965,11 +995,17 @@ impl DieselGameHand {
                         let tile_id = tile.id;
                         let concealed = if tile.concealed { 1 } else { 0 };
                         let set_id = tile.set_id.clone();
+                        let (meld_supplier, meld_supplier_index) =
+                            get_meld_supplier(&Some(set_id.clone()));
 
                         let game_hand = Self {
                             concealed,
                             game_id: service_game.game.id.clone(),
+                            is_dead: hand.is_dead,
                             is_kong: true,
+                            is_revealed: hand.is_revealed,
+                            meld_supplier,
+                            meld_supplier_index,
                             player_id: player_id.clone(),
                             set_id: Some(set_id),
                             tile_id: tile_id as i32,
@@ -994,7 +1030,11 @@ impl DieselGameHand {
                         let game_hand = Self {
                             concealed: 0,
                             game_id: service_game.game.id.clone(),
+                            is_dead: false,
                             is_kong: false,
+                            is_revealed: false,
+                            meld_supplier: None,
+                            meld_supplier_index: None,
                             player_id: player_id.clone(),
                             set_id: None,
                             tile_id: *tile_id as i32,
@@ -1021,6 +1061,8 @@ impl DieselGameHand {
         use schema::game_hand::dsl as game_hand_dsl;
         let mut hands = Hands::default();
         let mut bonus_tiles = BonusTiles::default();
+        let mut meld_suppliers: FxHashMap<PlayerId, BTreeMap<i32, MeldSupplier>> =
+            FxHashMap::default();
 
         loop {
             if let Ok(data) = game_hand_dsl::game_hand
@@ -1047,6 +1089,22 @@ impl DieselGameHand {
                     .get(&player_id)
                     .unwrap_or(&Hand::new(Vec::new()))
                     .clone();
+                current_hand.is_dead |= game_hand.is_dead;
+                current_hand.is_revealed |= game_hand.is_revealed;
+
+                if let (Some(supplier), Some(supplier_index), Some(set_id)) = (
+                    game_hand.meld_supplier,
+                    game_hand.meld_supplier_index,
+                    set_id.clone(),
+                ) {
+                    meld_suppliers.entry(player_id.clone()).or_default().insert(
+                        supplier_index,
+                        MeldSupplier {
+                            player_id: supplier,
+                            set_id,
+                        },
+                    );
+                }
 
                 if game_hand.is_kong {
                     current_hand.kong_tiles.insert(KongTile {
@@ -1066,6 +1124,12 @@ impl DieselGameHand {
             }
         });
 
+        for (player_id, player_meld_suppliers) in meld_suppliers {
+            if let Some(hand) = hands.0.get_mut(&player_id) {
+                hand.meld_suppliers = player_meld_suppliers.into_values().collect();
+            }
+        }
+
         (hands, bonus_tiles)
     }
 
@@ -1107,6 +1171,9 @@ impl DieselGameSettings {
             auto_sort_players,
             auto_stop_claim_meld,
             dead_wall: service_game.settings.dead_wall,
+            house_rules: serde_json::to_string(&service_game.settings.house_rules).unwrap(),
+            ai_profiles: serde_json::to_string(&service_game.settings.ai_profiles).unwrap(),
+            hints_enabled: service_game.settings.hints_enabled,
         };
 
         loop {
@@ -1154,6 +1221,9 @@ impl DieselGameSettings {
                 .map(|s| s.to_string())
                 .collect(),
             dead_wall: game_settings.dead_wall,
+            house_rules: serde_json::from_str(&game_settings.house_rules).unwrap_or_default(),
+            ai_profiles: serde_json::from_str(&game_settings.ai_profiles).unwrap_or_default(),
+            hints_enabled: game_settings.hints_enabled,
         })
     }
 
@@ -1165,3 +1235,110 @@ impl DieselGameSettings {
         });
     }
 }
+
+impl DieselGameDecision {
+    pub fn save(connection: &mut PgConnection, game_id: &GameId, decision: &GameDecision) {
+        let action = serde_json::to_string(&decision.action).unwrap();
+        let game_summary = serde_json::to_string(&decision.game_summary).unwrap();
+
+        // The index is taken in the same statement, so a retry after a conflict takes the next one
+        db_request(|| {
+            diesel::sql_query(
+                "INSERT INTO game_decision (action, decision_index, game_id, game_summary, player_id)
+                 SELECT $1, COALESCE(MAX(decision_index) + 1, 0), $2, $3, $4
+                 FROM game_decision WHERE game_id = $2",
+            )
+            .bind::<Text, _>(&action)
+            .bind::<Text, _>(game_id)
+            .bind::<Text, _>(&game_summary)
+            .bind::<Text, _>(&decision.game_summary.player_id)
+            .execute(connection)
+        });
+    }
+
+    pub fn read_from_game(connection: &mut PgConnection, game_id: &GameId) -> Vec<GameDecision> {
+        use schema::game_decision::dsl as game_decision_dsl;
+
+        loop {
+            if let Ok(data) = game_decision_dsl::game_decision
+                .filter(game_decision_dsl::game_id.eq(game_id))
+                .order(game_decision_dsl::decision_index.asc())
+                .load::<Self>(connection)
+            {
+                break data;
+            }
+            wait_common();
+        }
+        .into_iter()
+        .filter_map(|decision| {
+            Some(GameDecision {
+                action: serde_json::from_str(&decision.action).ok()?,
+                game_summary: serde_json::from_str(&decision.game_summary).ok()?,
+            })
+        })
+        .collect()
+    }
+
+    pub fn delete_games(connection: &mut PgConnection, game_ids: &[GameId]) {
+        db_request(|| {
+            diesel::delete(schema::game_decision::table)
+                .filter(schema::game_decision::dsl::game_id.eq_any(game_ids))
+                .execute(connection)
+        });
+    }
+}
+
+impl DieselGameReview {
+    pub fn save(
+        connection: &mut PgConnection,
+        game_id: &GameId,
+        player_id: &PlayerId,
+        review: &GameReview,
+    ) {
+        use schema::game_review::dsl as game_review_dsl;
+
+        let diesel_review = Self {
+            game_id: game_id.clone(),
+            player_id: player_id.clone(),
+            review: serde_json::to_string(review).unwrap(),
+        };
+
+        db_request(|| {
+            diesel::insert_into(schema::game_review::table)
+                .values(&diesel_review)
+                .on_conflict((game_review_dsl::game_id, game_review_dsl::player_id))
+                .do_update()
+                .set(game_review_dsl::review.eq(&diesel_review.review))
+                .execute(connection)
+        });
+    }
+
+    pub fn read(
+        connection: &mut PgConnection,
+        game_id: &GameId,
+        player_id: &PlayerId,
+    ) -> Option<GameReview> {
+        use schema::game_review::dsl as game_review_dsl;
+
+        loop {
+            if let Ok(data) = game_review_dsl::game_review
+                .filter(game_review_dsl::game_id.eq(game_id))
+                .filter(game_review_dsl::player_id.eq(player_id))
+                .first::<Self>(connection)
+                .optional()
+            {
+                break data;
+            }
+            wait_common();
+        }
+        .and_then(|review| serde_json::from_str(&review.review).ok())
+    }
+
+    pub fn delete_games(connection: &mut PgConnection, game_ids: &[GameId]) {
+        db_request(|| {
+            diesel::delete(schema::game_review::table)
+                .filter(schema::game_review::dsl::game_id.eq_any(game_ids))
+                .execute(connection)
+        });
+    }
+}
@@ -48,9 +48,12 @@ diesel::table! {
         round_claimed_id -> Nullable<Int4>,
         round_consecutive_same_seats -> Int4,
         round_dealer_index -> Int4,
+        round_discards -> Nullable<Text>,
         round_east_player_index -> Int4,
         round_index -> Int4,
         round_initial_winds -> Nullable<Int4>,
+        round_last_outcome -> Nullable<Text>,
+        round_payments -> Nullable<Text>,
         round_player_index -> Int4,
         round_wall_tile_drawn -> Nullable<Int4>,
         round_wind -> Text,
@@ -69,6 +72,16 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    game_decision (game_id, decision_index) {
+        action -> Text,
+        decision_index -> Int4,
+        game_id -> Text,
+        game_summary -> Text,
+        player_id -> Text,
+    }
+}
+
 diesel::table! {
     game_draw_wall (game_id, tile_id, place) {
         game_id -> Text,
@@ -87,6 +100,10 @@ diesel::table! {
         tile_id -> Int4,
         tile_index -> Int4,
         is_kong -> Bool,
+        meld_supplier -> Nullable<Text>,
+        meld_supplier_index -> Nullable<Int4>,
+        is_dead -> Bool,
+        is_revealed -> Bool,
     }
 }
 
@@ -98,6 +115,14 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    game_review (game_id, player_id) {
+        game_id -> Text,
+        player_id -> Text,
+        review -> Text,
+    }
+}
+
 diesel::table! {
     game_score (game_id, player_id) {
         game_id -> Text,
@@ -116,6 +141,9 @@ diesel::table! {
         game_id -> Text,
         last_discard_time -> Int8,
         dead_wall -> Bool,
+        house_rules -> Text,
+        ai_profiles -> Text,
+        hints_enabled -> Bool,
     }
 }
 
@@ -134,11 +162,15 @@ diesel::joinable!(auth_info_anonymous -> auth_info (user_id));
 diesel::joinable!(auth_info_email -> auth_info (user_id));
 diesel::joinable!(auth_info_github -> auth_info (user_id));
 diesel::joinable!(game_board -> game (game_id));
+diesel::joinable!(game_decision -> game (game_id));
+diesel::joinable!(game_decision -> player (player_id));
 diesel::joinable!(game_draw_wall -> game (game_id));
 diesel::joinable!(game_hand -> game (game_id));
 diesel::joinable!(game_hand -> player (player_id));
 diesel::joinable!(game_player -> game (game_id));
 diesel::joinable!(game_player -> player (player_id));
+diesel::joinable!(game_review -> game (game_id));
+diesel::joinable!(game_review -> player (player_id));
 diesel::joinable!(game_score -> game (game_id));
 diesel::joinable!(game_score -> player (player_id));
 diesel::joinable!(game_settings -> game (game_id));
@@ -151,9 +183,11 @@ diesel::allow_tables_to_appear_in_same_query!(
     auth_info_providers,
     game,
     game_board,
+    game_decision,
     game_draw_wall,
     game_hand,
     game_player,
+    game_review,
     game_score,
     game_settings,
     player,
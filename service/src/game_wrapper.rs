@@ -7,8 +7,11 @@ use crate::{
 };
 use actix_web::{web, HttpResponse};
 use mahjong_core::{
-    game::{DrawTileResult, GameVersion},
+    ai::{get_hints, get_review_evaluator, review_game, AIProfile, GameDecision, HintAction},
+    game::{CreateMeldError, DrawTileResult, GameVersion},
+    game_summary::GameSummary,
     hand::SetIdContent,
+    round::AbortiveDraw,
     Game, GamePhase, PlayerId, Players, TileId,
 };
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -17,8 +20,9 @@ use service_contracts::{
     AdminPostBreakMeldResponse, AdminPostClaimTileResponse, AdminPostCreateMeldRequest,
     AdminPostCreateMeldResponse, AdminPostDiscardTileResponse, AdminPostDrawTileResponse,
     AdminPostMovePlayerResponse, AdminPostSayMahjongResponse, GameSettings, GameSettingsSummary,
-    ServiceGame, ServiceGameSummary, ServicePlayer, SocketMessage, UserPostAIContinueRequest,
-    UserPostAIContinueResponse, UserPostJoinGameResponse, UserPostPassRoundResponse,
+    ServiceGame, ServiceGameSummary, ServicePlayer, SocketMessage, UserGetGameHintsResponse,
+    UserGetGameReviewResponse, UserPostAIContinueRequest, UserPostAIContinueResponse,
+    UserPostAbortiveDrawResponse, UserPostJoinGameResponse, UserPostPassRoundResponse,
     UserPostSayMahjongResponse, UserPostSetGameSettingsResponse, UserPostSortHandResponse,
 };
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -33,6 +37,7 @@ pub struct GameWrapper<'a> {
 #[derive(Default)]
 pub struct CreateGameOpts<'a> {
     pub ai_player_names: Option<&'a Vec<String>>,
+    pub ai_profiles: Option<&'a Vec<AIProfile>>,
     pub auto_sort_own: Option<&'a bool>,
     pub dead_wall: Option<&'a bool>,
     pub player_id: Option<&'a PlayerId>,
@@ -134,10 +139,27 @@ impl<'a> GameWrapper<'a> {
     }
 
     pub async fn handle_user_say_mahjong(&mut self, player_id: &PlayerId) -> ResponseCommon {
-        self.service_game
-            .game
-            .say_mahjong(player_id)
-            .map_err(|_| ServiceError::Custom("Error saying mahjong"))?;
+        let current_game = &mut self.service_game.game;
+        let hand_tiles = current_game
+            .table
+            .hands
+            .get(player_id)
+            .map(|hand| {
+                hand.list
+                    .iter()
+                    .filter(|tile| tile.set_id.is_none())
+                    .map(|tile| tile.id)
+                    .collect::<Vec<TileId>>()
+            })
+            .unwrap_or_default();
+        let decision = GameDecision::from_claimed_meld(current_game, player_id, &hand_tiles);
+
+        if current_game.say_mahjong(player_id).is_ok() {
+            self.save_decision(decision).await;
+        } else if !self.service_game.game.pay_chombo_penalty(player_id) {
+            // With the chombo penalty the false claim is applied instead of rejected
+            return Err(ServiceError::Custom("Error saying mahjong").into());
+        }
 
         self.sync_game_updated();
 
@@ -148,6 +170,25 @@ impl<'a> GameWrapper<'a> {
             .await
     }
 
+    pub async fn handle_user_abortive_draw(
+        &mut self,
+        player_id: &PlayerId,
+        abortive_draw: &AbortiveDraw,
+    ) -> ResponseCommon {
+        self.service_game
+            .game
+            .declare_abortive_draw(player_id, abortive_draw)
+            .map_err(|_| ServiceError::Custom("Error declaring the abortive draw"))?;
+
+        self.sync_game_updated();
+
+        let game = ServiceGameSummary::from_service_game(&self.service_game, player_id).unwrap();
+        let response = UserPostAbortiveDrawResponse(game);
+
+        self.save_and_return(&response, "Error declaring the abortive draw")
+            .await
+    }
+
     fn sync_game(&self) {
         let socket_server = loop {
             if let Ok(srv) = self.socket_server.lock() {
@@ -229,6 +270,68 @@ impl<'a> GameWrapper<'a> {
         }
     }
 
+    // The hints are only computed in the server, so the client can't skip the setting
+    pub fn user_get_hints(&self, player_id: &PlayerId) -> ResponseCommon {
+        if !self.service_game.settings.hints_enabled {
+            return Ok(HttpResponse::BadRequest().body("Hints are disabled"));
+        }
+
+        match GameSummary::from_game(&self.service_game.game, player_id) {
+            None => Err(ServiceError::ErrorLoadingGame.into()),
+            Some(game_summary) => {
+                Ok(HttpResponse::Ok().json(UserGetGameHintsResponse(get_hints(&game_summary))))
+            }
+        }
+    }
+
+    // Only the decisions of the player are reviewed, and during the game it would work as hints.
+    // After the game the decisions don't change, so the review is stored the first time.
+    pub async fn user_get_review(&self, player_id: &PlayerId) -> ResponseCommon {
+        let game_id = &self.service_game.game.id;
+        let has_ended = self.service_game.game.phase == GamePhase::End;
+
+        if !self.service_game.settings.hints_enabled && !has_ended {
+            return Ok(HttpResponse::BadRequest().body("The review is available after the game"));
+        }
+
+        if has_ended {
+            if let Ok(Some(review)) = self.storage.get_game_review(game_id, player_id).await {
+                return Ok(HttpResponse::Ok().json(UserGetGameReviewResponse(review)));
+            }
+        }
+
+        let decisions = self
+            .storage
+            .get_game_decisions(game_id)
+            .await
+            .map_err(|_| ServiceError::ErrorLoadingGame)?
+            .into_iter()
+            .filter(|decision| decision.game_summary.player_id == *player_id)
+            .collect::<Vec<GameDecision>>();
+        // It plays many rounds for each decision, so it doesn't block the server workers
+        let review = web::block(move || review_game(&decisions, &mut get_review_evaluator()))
+            .await
+            .map_err(|_| ServiceError::Custom("Error reviewing the game"))?;
+
+        if has_ended {
+            self.storage
+                .save_game_review(game_id, player_id, &review)
+                .await
+                .unwrap_or_else(|err| debug!("Error saving the review: {err}"));
+        }
+
+        Ok(HttpResponse::Ok().json(UserGetGameReviewResponse(review)))
+    }
+
+    async fn save_decision(&self, decision: Option<GameDecision>) {
+        if let Some(decision) = decision {
+            self.storage
+                .save_game_decision(&self.service_game.game.id, &decision)
+                .await
+                .unwrap_or_else(|err| debug!("Error saving the decision: {err}"));
+        }
+    }
+
     pub async fn handle_sort_hands(&mut self) -> ResponseCommon {
         for player in self.service_game.game.players.iter() {
             let hand = self
@@ -259,6 +362,7 @@ impl<'a> GameWrapper<'a> {
 
         let existing_settings = self.service_game.settings.clone();
         self.service_game.settings = settings.to_game_settings(player_id, &existing_settings);
+        self.service_game.game.house_rules = self.service_game.settings.house_rules.clone();
 
         let game_summary =
             ServiceGameSummary::from_service_game(&self.service_game, player_id).unwrap();
@@ -347,11 +451,15 @@ impl<'a> GameWrapper<'a> {
         body: &AdminPostAIContinueRequest,
     ) -> ResponseCommon {
         let mut standard_ai = AIWrapper::new(&mut self.service_game, body.draw);
+        standard_ai.with_metadata = true;
 
         let mut global_changed = false;
+        let mut metadata = vec![];
 
         loop {
-            let changed = standard_ai.play_action().changed;
+            let result = standard_ai.play_action();
+            let changed = result.changed;
+            metadata.extend(result.metadata);
             if !global_changed {
                 global_changed = changed;
             }
@@ -365,6 +473,7 @@ impl<'a> GameWrapper<'a> {
         let response: AdminPostAIContinueResponse = AdminPostAIContinueResponse {
             service_game: self.service_game.to_owned(),
             changed: global_changed,
+            metadata,
         };
 
         self.save_and_return(response, "Error with AI action").await
@@ -446,10 +555,10 @@ impl<'a> GameWrapper<'a> {
             return Err(ServiceError::Custom("Not your turn"));
         }
 
-        self.service_game
-            .game
-            .round
-            .next_turn(&self.service_game.game.table.hands)
+        let game = &mut self.service_game.game;
+        let ruleset = game.style.get_ruleset(&game.house_rules);
+        game.round
+            .next_turn(&game.table.hands, &*ruleset)
             .map_err(|_| ServiceError::Custom("Error moving player"))?;
 
         self.sync_game_updated();
@@ -467,10 +576,22 @@ impl<'a> GameWrapper<'a> {
             .unwrap()
             .as_millis();
 
-        self.service_game
+        let decision = self.get_current_player_id().ok().and_then(|player_id| {
+            GameDecision::from_game(
+                &self.service_game.game,
+                &player_id,
+                HintAction::Discard { tile: *tile_id },
+            )
+        });
+
+        if self
+            .service_game
             .game
             .discard_tile_to_board(tile_id)
-            .unwrap_or_default();
+            .is_ok()
+        {
+            self.save_decision(decision).await;
+        }
 
         let mut game = self.service_game.clone();
 
@@ -492,10 +613,22 @@ impl<'a> GameWrapper<'a> {
             .unwrap()
             .as_millis();
 
-        self.service_game
+        let decision = self.get_current_player_id().ok().and_then(|player_id| {
+            GameDecision::from_game(
+                &self.service_game.game,
+                &player_id,
+                HintAction::Discard { tile: *tile_id },
+            )
+        });
+
+        if self
+            .service_game
             .game
             .discard_tile_to_board(tile_id)
-            .unwrap_or_default();
+            .is_ok()
+        {
+            self.save_decision(decision).await;
+        }
 
         let mut game = self.service_game.clone();
 
@@ -596,15 +729,24 @@ impl<'a> GameWrapper<'a> {
         is_upgrade: bool,
         is_concealed: bool,
     ) -> Result<ServiceGameSummary, ServiceError> {
-        self.service_game
-            .game
-            .create_meld(
-                player_id,
-                &tiles.clone().into_iter().collect::<Vec<TileId>>(),
-                is_upgrade,
-                is_concealed,
-            )
-            .map_err(|_| ServiceError::Custom("Error when creating meld"))?;
+        let current_game = &mut self.service_game.game;
+        let meld_tiles = tiles.iter().copied().collect::<Vec<TileId>>();
+        let decision = GameDecision::from_claimed_meld(current_game, player_id, &meld_tiles);
+        let result = current_game.create_meld(player_id, &meld_tiles, is_upgrade, is_concealed);
+
+        match result {
+            Ok(()) => self.save_decision(decision).await,
+            Err(error) => {
+                // With the chombo penalty the illegal exposed meld is applied instead of rejected
+                let is_penalized = error == CreateMeldError::NotMeld
+                    && !is_concealed
+                    && self.service_game.game.pay_chombo_penalty(player_id);
+
+                if !is_penalized {
+                    return Err(ServiceError::Custom("Error when creating meld"));
+                }
+            }
+        }
 
         self.sync_game_updated();
 
@@ -615,11 +757,9 @@ impl<'a> GameWrapper<'a> {
     }
 
     pub async fn handle_admin_move_player(&mut self) -> ResponseCommon {
-        let success = self
-            .service_game
-            .game
-            .round
-            .next_turn(&self.service_game.game.table.hands);
+        let game = &mut self.service_game.game;
+        let ruleset = game.style.get_ruleset(&game.house_rules);
+        let success = game.round.next_turn(&game.table.hands, &*ruleset);
 
         match success {
             Ok(_) => {
@@ -751,7 +891,13 @@ fn create_game(player: &Option<ServicePlayer>, opts: &CreateGameOpts) -> Service
         players.push(player.as_ref().unwrap().id.clone());
     }
 
-    for _ in opts.ai_player_names.unwrap_or(&vec![]).iter() {
+    let ai_players_num = opts
+        .ai_player_names
+        .map(|names| names.len())
+        .max(opts.ai_profiles.map(|profiles| profiles.len()))
+        .unwrap_or(0);
+
+    for _ in 0..ai_players_num {
         let id = Players::new_player();
 
         players.push(id);
@@ -763,27 +909,32 @@ fn create_game(player: &Option<ServicePlayer>, opts: &CreateGameOpts) -> Service
     debug!("Going to add players to game");
     let empty_player_names = vec![];
     let player_names = opts.ai_player_names.unwrap_or(&empty_player_names);
+    let empty_profiles = vec![];
+    let profiles = opts.ai_profiles.unwrap_or(&empty_profiles);
 
     let timestamp = get_timestamp();
+    let mut settings = GameSettings::default();
 
     for (index, game_player) in game.players.iter().enumerate() {
         if index == 0 && player.is_some() {
             let player_clone = player.as_ref().unwrap().clone();
             players_set.insert(player_clone.id.clone(), player_clone);
         } else {
+            let ai_index = index - usize::from(player.is_some());
             let default_name = format!("Player {}", index);
+            let profile = profiles.get(ai_index).copied().unwrap_or_default();
+            let name = player_names.get(ai_index).unwrap_or(&default_name);
             let service_player = ServicePlayer {
                 created_at: timestamp.to_string(),
                 id: game_player.clone(),
                 is_ai: true,
-                name: player_names.get(index + 1).unwrap_or(&default_name).clone(),
+                name: format!("{name} ({profile})"),
             };
             players_set.insert(game_player.clone(), service_player);
+            settings.ai_profiles.insert(game_player.clone(), profile);
         }
     }
 
-    let mut settings = GameSettings::default();
-
     if let Some(dead_wall) = opts.dead_wall {
         settings.dead_wall = *dead_wall;
     }
@@ -797,6 +948,8 @@ fn create_game(player: &Option<ServicePlayer>, opts: &CreateGameOpts) -> Service
         settings.auto_stop_claim_meld.insert(player.id.clone());
     }
 
+    game.house_rules = settings.house_rules.clone();
+
     ServiceGame {
         created_at: timestamp,
         game,
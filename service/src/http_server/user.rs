@@ -5,15 +5,24 @@ use crate::http_server::base::{get_lock, DataSocketServer, DataStorage, GamesMan
 use crate::service_error::{ResponseCommon, ServiceError};
 use crate::user_wrapper::UserWrapper;
 use actix_web::{get, patch, post, web, HttpRequest, HttpResponse};
-use mahjong_core::GameId;
+use mahjong_core::{
+    render::{try_render_tiles, TileFormat},
+    GameId, TileId,
+};
 use service_contracts::{
-    Queries, QueriesResponses, UserLoadGameQuery, UserPatchInfoRequest, UserPostAIContinueRequest,
+    Queries, QueriesResponses, UserGetGameHintsQuery, UserGetGameReviewQuery, UserLoadGameQuery,
+    UserPatchInfoRequest, UserPostAIContinueRequest, UserPostAbortiveDrawRequest,
     UserPostClaimTileRequest, UserPostPassRoundRequest, UserPostSayMahjongRequest,
     UserPostSetAuthAnonRequest, UserPostSetAuthRequest, UserPostSetAuthResponse,
     UserPostSetGameSettingsRequest, UserPostSortHandRequest,
 };
 use tracing::debug;
 
+// The ids come from the request, so the invalid ones are logged as they are
+fn render_log_tiles(tiles: &[TileId]) -> String {
+    try_render_tiles(tiles, TileFormat::Ascii).unwrap_or_else(|| format!("{tiles:?}"))
+}
+
 #[post("/game")]
 async fn user_game_handler(
     storage: DataStorage,
@@ -51,6 +60,7 @@ async fn user_game_handler(
         }
         Queries::UserCreateGame {
             ai_player_names,
+            ai_profiles,
             auto_sort_own,
             dead_wall,
             ..
@@ -58,6 +68,7 @@ async fn user_game_handler(
             debug!("Creating game for user: {:?}", player_id);
             let create_game_opts = CreateGameOpts {
                 ai_player_names: ai_player_names.as_ref(),
+                ai_profiles: ai_profiles.as_ref(),
                 auto_sort_own: auto_sort_own.as_ref(),
                 dead_wall: dead_wall.as_ref(),
                 player_id: Some(player_id),
@@ -77,6 +88,8 @@ async fn user_game_handler(
             is_concealed,
             ..
         } => {
+            let meld_tiles = tiles.iter().copied().collect::<Vec<_>>();
+            debug!("Creating meld: {}", render_log_tiles(&meld_tiles));
             get_lock!(manager, game_id);
 
             let mut game_wrapper = GameWrapper::from_storage(&storage, game_id, srv, None).await?;
@@ -88,7 +101,7 @@ async fn user_game_handler(
             }
         }
         Queries::UserDiscardTile { game_id, tile_id } => {
-            debug!("Discarding tile");
+            debug!("Discarding tile: {}", render_log_tiles(&[*tile_id]));
             get_lock!(manager, game_id);
             let mut game_wrapper = GameWrapper::from_storage(&storage, game_id, srv, None).await?;
             let current_user_id = &game_wrapper.get_current_player_id()?;
@@ -153,6 +166,40 @@ async fn user_get_game_load(
     game_wrapper.user_load_game(&params.player_id)
 }
 
+#[get("/game/{game_id}/hints")]
+async fn user_get_game_hints(
+    storage: DataStorage,
+    game_id: web::Path<String>,
+    req: HttpRequest,
+    srv: DataSocketServer,
+) -> ResponseCommon {
+    let params = web::Query::<UserGetGameHintsQuery>::from_query(req.query_string())
+        .map_err(|_| ServiceError::Custom("Invalid player id"))?;
+
+    AuthHandler::new(&storage, &req).verify_user(&params.player_id)?;
+
+    let game_wrapper = GameWrapper::from_storage(&storage, &game_id, srv, None).await?;
+
+    game_wrapper.user_get_hints(&params.player_id)
+}
+
+#[get("/game/{game_id}/review")]
+async fn user_get_game_review(
+    storage: DataStorage,
+    game_id: web::Path<String>,
+    req: HttpRequest,
+    srv: DataSocketServer,
+) -> ResponseCommon {
+    let params = web::Query::<UserGetGameReviewQuery>::from_query(req.query_string())
+        .map_err(|_| ServiceError::Custom("Invalid player id"))?;
+
+    AuthHandler::new(&storage, &req).verify_user(&params.player_id)?;
+
+    let game_wrapper = GameWrapper::from_storage(&storage, &game_id, srv, None).await?;
+
+    game_wrapper.user_get_review(&params.player_id).await
+}
+
 #[post("/game/{game_id}/ai-continue")]
 async fn user_post_game_ai_continue(
     storage: DataStorage,
@@ -244,6 +291,26 @@ async fn user_post_game_say_mahjong(
     game_wrapper.handle_user_say_mahjong(&body.player_id).await
 }
 
+#[post("/game/{game_id}/abortive-draw")]
+async fn user_post_game_abortive_draw(
+    storage: DataStorage,
+    body: web::Json<UserPostAbortiveDrawRequest>,
+    game_id: web::Path<String>,
+    manager: GamesManagerData,
+    srv: DataSocketServer,
+    req: HttpRequest,
+) -> ResponseCommon {
+    AuthHandler::new(&storage, &req).verify_user(&body.player_id)?;
+
+    get_lock!(manager, game_id);
+
+    let mut game_wrapper = GameWrapper::from_storage(&storage, &game_id, srv, None).await?;
+
+    game_wrapper
+        .handle_user_abortive_draw(&body.player_id, &body.abortive_draw)
+        .await
+}
+
 #[post("/game/{game_id}/pass-round")]
 async fn user_post_game_pass_round(
     storage: DataStorage,
@@ -416,12 +483,15 @@ async fn user_patch_info(
 
 pub fn get_user_scope() -> actix_web::Scope {
     web::scope("/api/v1/user")
+        .service(user_get_game_hints)
         .service(user_get_game_load)
+        .service(user_get_game_review)
         .service(user_game_handler)
         .service(user_get_info)
         .service(user_patch_info)
         .service(user_post_auth)
         .service(user_post_auth_anonymous)
+        .service(user_post_game_abortive_draw)
         .service(user_post_game_ai_continue)
         .service(user_post_game_claim_tile)
         .service(user_post_game_join)
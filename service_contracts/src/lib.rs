@@ -3,8 +3,14 @@ use std::fmt::{self, Display};
 use ts_rs::TS;
 
 use mahjong_core::{
-    deck::DeckContent, game::GameVersion, game_summary::GameSummary, hand::SetIdContent, Game,
-    GameId, Hand, Hands, PlayerId, TileId,
+    ai::{AIProfile, GameReview, Hint, Metadata},
+    deck::DeckContent,
+    game::GameVersion,
+    game_summary::GameSummary,
+    hand::SetIdContent,
+    round::AbortiveDraw,
+    ruleset::HouseRules,
+    Game, GameId, Hand, Hands, PlayerId, TileId,
 };
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
@@ -16,11 +22,16 @@ mod service_player;
 #[ts(export)]
 pub struct GameSettings {
     pub ai_enabled: bool,
+    // The AI players without a profile use the default one
+    pub ai_profiles: FxHashMap<PlayerId, AIProfile>,
     pub auto_sort_players: FxHashSet<PlayerId>,
     pub auto_stop_claim_meld: FxHashSet<PlayerId>,
     pub dead_wall: bool,
     pub discard_wait_ms: Option<i32>,
     pub fixed_settings: bool,
+    // It can be disabled for ranked games
+    pub hints_enabled: bool,
+    pub house_rules: HouseRules,
     pub last_discard_time: i128,
 }
 
@@ -28,11 +39,14 @@ impl Default for GameSettings {
     fn default() -> Self {
         Self {
             ai_enabled: true,
+            ai_profiles: FxHashMap::default(),
             auto_sort_players: FxHashSet::default(),
             auto_stop_claim_meld: FxHashSet::default(),
             dead_wall: false,
             discard_wait_ms: Some(1000),
             fixed_settings: false,
+            hints_enabled: true,
+            house_rules: HouseRules::default(),
             last_discard_time: 0,
         }
     }
@@ -57,6 +71,8 @@ pub struct GameSettingsSummary {
     pub dead_wall: bool,
     pub discard_wait_ms: Option<i32>,
     pub fixed_settings: bool,
+    pub hints_enabled: bool,
+    pub house_rules: HouseRules,
     pub last_discard_time: String,
 }
 
@@ -69,6 +85,8 @@ impl GameSettingsSummary {
             dead_wall: settings.dead_wall,
             discard_wait_ms: settings.discard_wait_ms,
             fixed_settings: settings.fixed_settings,
+            hints_enabled: settings.hints_enabled,
+            house_rules: settings.house_rules.clone(),
             last_discard_time: settings.last_discard_time.to_string(),
         }
     }
@@ -91,6 +109,8 @@ impl GameSettingsSummary {
         new_settings.ai_enabled = self.ai_enabled;
         new_settings.discard_wait_ms = self.discard_wait_ms;
         new_settings.fixed_settings = self.fixed_settings;
+        new_settings.hints_enabled = self.hints_enabled;
+        new_settings.house_rules = self.house_rules.clone();
         new_settings.last_discard_time = self.last_discard_time.parse().unwrap_or(0);
 
         new_settings
@@ -191,6 +211,8 @@ pub enum Queries {
     },
     UserCreateGame {
         ai_player_names: Option<Vec<String>>,
+        // In the same order as the names
+        ai_profiles: Option<Vec<AIProfile>>,
         auto_sort_own: Option<bool>,
         dead_wall: Option<bool>,
         player_id: PlayerId,
@@ -296,6 +318,24 @@ pub struct UserLoadGameQuery {
 #[ts(export)]
 pub struct UserGetLoadGameResponse(pub ServiceGameSummary);
 
+#[derive(Deserialize, Serialize, TS)]
+#[ts(export)]
+pub struct UserGetGameHintsQuery {
+    pub player_id: PlayerId,
+}
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct UserGetGameHintsResponse(pub Vec<Hint>);
+
+#[derive(Deserialize, Serialize, TS)]
+#[ts(export)]
+pub struct UserGetGameReviewQuery {
+    pub player_id: PlayerId,
+}
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct UserGetGameReviewResponse(pub GameReview);
+
 #[derive(Deserialize, Serialize, TS)]
 #[ts(export)]
 pub struct UserPostSortHandRequest {
@@ -323,6 +363,8 @@ pub struct AdminPostAIContinueRequest {
 pub struct AdminPostAIContinueResponse {
     pub service_game: ServiceGame,
     pub changed: bool,
+    // The decisions of the AI in each action, including the last one without changes
+    pub metadata: Vec<Metadata>,
 }
 
 #[derive(Deserialize, Serialize, TS)]
@@ -337,6 +379,16 @@ pub struct UserPostAIContinueResponse {
     pub changed: bool,
 }
 
+#[derive(Deserialize, Serialize, TS)]
+#[ts(export)]
+pub struct UserPostAbortiveDrawRequest {
+    pub abortive_draw: AbortiveDraw,
+    pub player_id: PlayerId,
+}
+#[derive(Deserialize, Serialize, TS)]
+#[ts(export)]
+pub struct UserPostAbortiveDrawResponse(pub ServiceGameSummary);
+
 #[derive(Deserialize, Serialize, TS)]
 #[ts(export)]
 pub struct UserPostClaimTileRequest {
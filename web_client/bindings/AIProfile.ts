@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AIDifficulty } from "./AIDifficulty";
+import type { AIStyle } from "./AIStyle";
+
+export type AIProfile = { difficulty: AIDifficulty; style: AIStyle };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OpponentReadiness } from "./OpponentReadiness";
+import type { TileDanger } from "./TileDanger";
+
+export type DangerOverlay = {
+  opponents_readiness: Array<OpponentReadiness>;
+  tiles_danger: Array<TileDanger>;
+};
@@ -1,12 +1,14 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { GamePhase } from "./GamePhase";
 import type { GameStyle } from "./GameStyle";
+import type { HouseRules } from "./HouseRules";
 import type { Players } from "./Players";
 import type { Round } from "./Round";
 import type { Score } from "./Score";
 import type { Table } from "./Table";
 
 export type Game = {
+  house_rules: HouseRules;
   id: string;
   name: string;
   phase: GamePhase;
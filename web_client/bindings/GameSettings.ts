@@ -1,11 +1,16 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AIProfile } from "./AIProfile";
+import type { HouseRules } from "./HouseRules";
 
 export type GameSettings = {
   ai_enabled: boolean;
+  ai_profiles: { [key: string]: AIProfile };
   auto_sort_players: Array<string>;
   auto_stop_claim_meld: Array<string>;
   dead_wall: boolean;
   discard_wait_ms: null | number;
   fixed_settings: boolean;
+  hints_enabled: boolean;
+  house_rules: HouseRules;
   last_discard_time: bigint;
 };
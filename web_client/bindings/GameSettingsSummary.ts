@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HouseRules } from "./HouseRules";
 
 export type GameSettingsSummary = {
   ai_enabled: boolean;
@@ -7,5 +8,7 @@ export type GameSettingsSummary = {
   dead_wall: boolean;
   discard_wait_ms: null | number;
   fixed_settings: boolean;
+  hints_enabled: boolean;
+  house_rules: HouseRules;
   last_discard_time: string;
 };
@@ -4,6 +4,7 @@ import type { BonusTiles } from "./BonusTiles";
 import type { GamePhase } from "./GamePhase";
 import type { GameStyle } from "./GameStyle";
 import type { Hand } from "./Hand";
+import type { HouseRules } from "./HouseRules";
 import type { OtherPlayerHands } from "./OtherPlayerHands";
 import type { Players } from "./Players";
 import type { RoundSummary } from "./RoundSummary";
@@ -14,6 +15,7 @@ export type GameSummary = {
   bonus_tiles: BonusTiles;
   draw_wall_count: number;
   hand: Hand | null;
+  house_rules: HouseRules;
   id: string;
   other_hands: OtherPlayerHands;
   phase: GamePhase;
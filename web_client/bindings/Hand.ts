@@ -1,5 +1,12 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { HandTile } from "./HandTile";
 import type { KongTile } from "./KongTile";
+import type { MeldSupplier } from "./MeldSupplier";
 
-export type Hand = { kong_tiles: Array<KongTile>; list: Array<HandTile> };
+export type Hand = {
+  is_dead: boolean;
+  is_revealed: boolean;
+  kong_tiles: Array<KongTile>;
+  list: Array<HandTile>;
+  meld_suppliers: Array<MeldSupplier>;
+};
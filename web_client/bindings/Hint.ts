@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HintAction } from "./HintAction";
+import type { HintReason } from "./HintReason";
+
+export type Hint = {
+  acceptance: number;
+  action: HintAction;
+  danger: number;
+  reason: HintReason;
+  shanten: number;
+};
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PossibleMeld } from "./PossibleMeld";
+
+export type HintAction =
+  | { meld: PossibleMeld; type: "Claim" }
+  | { meld: PossibleMeld; type: "Kong" }
+  | { meld: PossibleMeld; type: "Meld" }
+  | { tile: number; type: "Discard" }
+  | { type: "Mahjong" }
+  | { type: "Pass" };
@@ -1,7 +1,10 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { HandTileStat } from "./HandTileStat";
+import type { HandWait } from "./HandWait";
 import type { PossibleMeld } from "./PossibleMeld";
 import type { ServicePlayerSummary } from "./ServicePlayerSummary";
+import type { ShantenAnalysis } from "./ShantenAnalysis";
+import type { TileKindTracker } from "./TileKindTracker";
 import type { VisibleMeld } from "./VisibleMeld";
 import type { Wind } from "./Wind";
 
@@ -14,9 +17,12 @@ export type PlayingExtras = {
   can_say_mahjong: boolean;
   dealer_player: null | ServicePlayerSummary;
   hand_stats: { [key: number]: HandTileStat };
+  hand_waits: Array<HandWait> | null;
   players_visible_melds: { [key: string]: Array<VisibleMeld> };
   players_winds: { [key: string]: Wind };
   playing_player: null | ServicePlayerSummary;
   possible_melds: Array<PossibleMeld>;
+  shanten_analysis: null | ShantenAnalysis;
+  tiles_tracker: Array<TileKindTracker> | null;
   turn_player: null | ServicePlayerSummary;
 };
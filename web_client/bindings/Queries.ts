@@ -1,8 +1,10 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AIProfile } from "./AIProfile";
 
 export type Queries =
   | {
       ai_player_names: Array<string> | null;
+      ai_profiles: Array<AIProfile> | null;
       auto_sort_own: boolean | null;
       dead_wall: boolean | null;
       player_id: string;
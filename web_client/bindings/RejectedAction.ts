@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AgentAction } from "./AgentAction";
+import type { AgentActionError } from "./AgentActionError";
+
+export type RejectedAction = {
+  action: AgentAction;
+  error: AgentActionError;
+  player_id: string;
+};
@@ -1,12 +1,18 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RoundDiscard } from "./RoundDiscard";
+import type { RoundOutcome } from "./RoundOutcome";
 import type { RoundTileClaimed } from "./RoundTileClaimed";
+import type { ScorePayment } from "./ScorePayment";
 import type { Wind } from "./Wind";
 
 export type Round = {
   consecutive_same_seats: number;
   dealer_player_index: number;
+  discards: Array<RoundDiscard>;
   east_player_index: number;
   initial_winds: null | number;
+  last_outcome: null | RoundOutcome;
+  payments: Array<ScorePayment>;
   player_index: number;
   round_index: number;
   tile_claimed: null | RoundTileClaimed;
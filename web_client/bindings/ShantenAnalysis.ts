@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DiscardAnalysis } from "./DiscardAnalysis";
+import type { TileAcceptance } from "./TileAcceptance";
+
+export type ShantenAnalysis = {
+  acceptance: Array<TileAcceptance>;
+  discards: Array<DiscardAnalysis>;
+  shanten: number;
+};
@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AnimalTile } from "./AnimalTile";
 import type { DragonTile } from "./DragonTile";
 import type { FlowerTile } from "./FlowerTile";
 import type { SeasonTile } from "./SeasonTile";
@@ -6,6 +7,7 @@ import type { SuitTile } from "./SuitTile";
 import type { WindTile } from "./WindTile";
 
 export type Tile =
+  | { Animal: AnimalTile }
   | { Dragon: DragonTile }
   | { Flower: FlowerTile }
   | { Season: SeasonTile }
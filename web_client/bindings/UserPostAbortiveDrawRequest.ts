@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AbortiveDraw } from "./AbortiveDraw";
+
+export type UserPostAbortiveDrawRequest = {
+  abortive_draw: AbortiveDraw;
+  player_id: string;
+};
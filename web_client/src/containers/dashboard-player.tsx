@@ -1,6 +1,12 @@
 import { EditOutlined, PlusCircleOutlined } from "@ant-design/icons";
+import type { AIDifficulty } from "bindings/AIDifficulty";
+import type { AIProfile } from "bindings/AIProfile";
+import type { AIStyle } from "bindings/AIStyle";
+import type { GameReview } from "bindings/GameReview";
+import type { HintAction } from "bindings/HintAction";
 import type { UserGetDashboardResponse } from "bindings/UserGetDashboardResponse";
 import dayjs from "dayjs";
+import type { TFunction } from "i18next";
 import Head from "next/head";
 import Link from "next/link";
 import { useRouter } from "next/router";
@@ -11,6 +17,7 @@ import { string } from "zod";
 
 import { tokenObserver } from "src/sdk/auth";
 import { HttpClient } from "src/sdk/http-client";
+import { formatTile, getDeck } from "src/sdk/pkg-wrapper";
 import Button from "src/ui/common/button";
 import Card from "src/ui/common/card";
 import Input from "src/ui/common/input";
@@ -27,6 +34,9 @@ export type TProps = {
   userId: string;
 };
 
+const aiDifficulties: AIDifficulty[] = ["Beginner", "Intermediate", "Strong"];
+const aiStyles: AIStyle[] = ["Aggressive", "Defensive", "ValueSeeking"];
+
 const simpleFormatDate = (timestamp: string): string => {
   const timestampNum = Number(timestamp);
 
@@ -37,6 +47,38 @@ const simpleFormatDate = (timestamp: string): string => {
   return day.format("YYYY-MM-DD HH:mm:ss");
 };
 
+const formatHintAction = (action: HintAction, t: TFunction): string => {
+  const deck = getDeck();
+  const formatTiles = (tiles: number[]) =>
+    tiles.map((tile) => formatTile(deck[tile])).join("");
+
+  switch (action.type) {
+    case "Claim":
+      return t("dashboard.review.claim", "Claim {{tiles}}", {
+        tiles: formatTiles(action.meld.tiles),
+      });
+    case "Discard":
+      return t("dashboard.review.discard", "Discard {{tiles}}", {
+        tiles: formatTiles([action.tile]),
+      });
+    case "Kong":
+      return t("dashboard.review.kong", "Kong {{tiles}}", {
+        tiles: formatTiles(action.meld.tiles),
+      });
+    case "Mahjong":
+      return t("dashboard.review.mahjong", "Mahjong");
+    case "Meld":
+      return t("dashboard.review.meld", "Declare {{tiles}}", {
+        tiles: formatTiles(action.meld.tiles),
+      });
+    case "Pass":
+      return t("dashboard.review.pass", "Pass");
+    default:
+      action satisfies never;
+      return "";
+  }
+};
+
 const DashboardUser = ({ userId }: TProps) => {
   const { t } = useTranslation();
 
@@ -50,8 +92,16 @@ const DashboardUser = ({ userId }: TProps) => {
   const [isNewGameModalOpen, setIsNewGameModalOpen] = useState(false);
   const [realPlayersNum, setRealPlayersNum] = useState(1);
   const [selectedGameId, setSelectGameId] = useState("");
+  const [gameReview, setGameReview] = useState<GameReview | null>(null);
+  const [isReviewLoading, setIsReviewLoading] = useState(false);
   const [useDeadWall, setUseDeadWall] = useState(false);
   const [autoSortOwn, setAutoSortOwn] = useState(true);
+  const [aiProfiles, setAIProfiles] = useState<AIProfile[]>(
+    Array.from({ length: 3 }).map(() => ({
+      difficulty: "Intermediate",
+      style: "Defensive",
+    })),
+  );
 
   const router = useRouter();
 
@@ -114,9 +164,26 @@ const DashboardUser = ({ userId }: TProps) => {
 
   const onGameClick = (gameId: string) => {
     setSelectGameId(gameId);
+    setGameReview(null);
     setIsPrevGameModalOpen(true);
   };
 
+  const onReviewClick = () => {
+    setIsReviewLoading(true);
+
+    HttpClient.userGetGameReview(selectedGameId, { player_id: userId })
+      .pipe(first())
+      .subscribe({
+        error: () => {
+          setIsReviewLoading(false);
+        },
+        next: (review) => {
+          setIsReviewLoading(false);
+          setGameReview(review);
+        },
+      });
+  };
+
   return (
     <PageContent>
       <Head>
@@ -323,6 +390,13 @@ const DashboardUser = ({ userId }: TProps) => {
           >
             {t("dashboard.yes", "Yes")}
           </Button>,
+          <Button
+            disabled={isReviewLoading}
+            key="review"
+            onClick={onReviewClick}
+          >
+            {t("dashboard.review.title", "Review")}
+          </Button>,
           <Button
             key="no"
             onClick={() => {
@@ -339,6 +413,40 @@ const DashboardUser = ({ userId }: TProps) => {
         title={t("dashboard.openGame") as string}
       >
         <p>{selectedGameId}</p>
+        {isReviewLoading && (
+          <p>{t("dashboard.review.loading", "Reviewing...")}</p>
+        )}
+        {gameReview && (
+          <div data-name="game-review">
+            <p>
+              {t(
+                "dashboard.review.summary",
+                "{{mistakes}} mistakes in {{decisions}} decisions",
+                {
+                  decisions: gameReview.decisions,
+                  mistakes: gameReview.mistakes.length,
+                },
+              )}
+            </p>
+            <ul>
+              {gameReview.mistakes.map((mistake, index) => (
+                <li key={index}>
+                  {t(
+                    "dashboard.review.mistake",
+                    "Round {{wind}}, turn {{turn}}: {{action}}, recommended: {{recommended}} (estimated loss: {{loss}})",
+                    {
+                      action: formatHintAction(mistake.action, t),
+                      loss: mistake.estimated_loss.toFixed(2),
+                      recommended: formatHintAction(mistake.recommended, t),
+                      turn: mistake.turn,
+                      wind: mistake.round_wind,
+                    },
+                  )}
+                </li>
+              ))}
+            </ul>
+          </div>
+        )}
       </Modal>
 
       <Modal
@@ -357,6 +465,7 @@ const DashboardUser = ({ userId }: TProps) => {
 
               HttpClient.userCreateGame({
                 ai_player_names: aiPlayersNames,
+                ai_profiles: aiProfiles.slice(0, aiPlayersNum),
                 auto_sort_own: autoSortOwn,
                 dead_wall: useDeadWall,
                 player_id: userId,
@@ -408,6 +517,51 @@ const DashboardUser = ({ userId }: TProps) => {
               })}
             </select>
           </div>
+          {aiProfiles.slice(0, 4 - realPlayersNum).map((aiProfile, i) => {
+            const setAIProfile = (newProfile: Partial<AIProfile>) => {
+              setAIProfiles(
+                aiProfiles.map((profile, index) =>
+                  index === i ? { ...profile, ...newProfile } : profile,
+                ),
+              );
+            };
+
+            return (
+              <div className="flex flex-row gap-[10px]" key={i}>
+                <p>
+                  {t("dashboard.aiPlayer", "AI Player {{number}}", {
+                    number: realPlayersNum + i + 1,
+                  })}
+                </p>
+                <select
+                  onChange={(e) =>
+                    setAIProfile({
+                      difficulty: e.target.value as AIDifficulty,
+                    })
+                  }
+                  value={aiProfile.difficulty}
+                >
+                  {aiDifficulties.map((difficulty) => (
+                    <option key={difficulty} value={difficulty}>
+                      {t(`dashboard.aiDifficulty.${difficulty}`, difficulty)}
+                    </option>
+                  ))}
+                </select>
+                <select
+                  onChange={(e) =>
+                    setAIProfile({ style: e.target.value as AIStyle })
+                  }
+                  value={aiProfile.style}
+                >
+                  {aiStyles.map((style) => (
+                    <option key={style} value={style}>
+                      {t(`dashboard.aiStyle.${style}`, style)}
+                    </option>
+                  ))}
+                </select>
+              </div>
+            );
+          })}
           <div className="flex flex-row gap-[10px]">
             <p>
               <label htmlFor="use-dead-wall">
@@ -92,6 +92,34 @@ const Settings = ({ serviceGameM, serviceGameSummary }: IProps) => {
     [t],
   );
 
+  const hintsOptions: SelectOption[] = useMemo(
+    () => [
+      {
+        label: t("game.option.yes"),
+        value: "yes",
+      },
+      {
+        label: t("game.option.no"),
+        value: "no",
+      },
+    ],
+    [t],
+  );
+
+  const liabilityPaymentsOptions: SelectOption[] = useMemo(
+    () => [
+      {
+        label: t("game.option.yes"),
+        value: "yes",
+      },
+      {
+        label: t("game.option.no"),
+        value: "no",
+      },
+    ],
+    [t],
+  );
+
   const discardWaitMsOptions: SelectOption[] = useMemo(
     () => [
       {
@@ -136,6 +164,27 @@ const Settings = ({ serviceGameM, serviceGameSummary }: IProps) => {
     });
   };
 
+  const onHintsChange = (value: string) => {
+    const boolValue = value === "yes";
+
+    serviceGameM.setGameSettings({
+      ...serviceGameSummary.settings,
+      hints_enabled: boolValue,
+    });
+  };
+
+  const onLiabilityPaymentsChange = (value: string) => {
+    const boolValue = value === "yes";
+
+    serviceGameM.setGameSettings({
+      ...serviceGameSummary.settings,
+      house_rules: {
+        ...serviceGameSummary.settings.house_rules,
+        liability_payments: boolValue,
+      },
+    });
+  };
+
   return (
     <>
       {/* Adding the language as the key or there are some issues with
@@ -195,6 +244,26 @@ const Settings = ({ serviceGameM, serviceGameSummary }: IProps) => {
             options={autoStopDrawMeldOptions}
             style={{ width: 120 }}
           />
+          <Text>{t("game.hints")}</Text>
+          <Select
+            defaultValue={serviceGameSummary.settings.hints_enabled ? "yes" : "no"}
+            disabled={serviceGameSummary.settings.fixed_settings}
+            onChange={onHintsChange}
+            options={hintsOptions}
+            style={{ width: 120 }}
+          />
+          <Text>{t("game.liabilityPayments")}</Text>
+          <Select
+            defaultValue={
+              serviceGameSummary.settings.house_rules.liability_payments
+                ? "yes"
+                : "no"
+            }
+            disabled={serviceGameSummary.settings.fixed_settings}
+            onChange={onLiabilityPaymentsChange}
+            options={liabilityPaymentsOptions}
+            style={{ width: 120 }}
+          />
         </div>
       </form>
     </>
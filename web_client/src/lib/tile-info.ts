@@ -176,5 +176,9 @@ export const getTileId = (tile: Tile): null | TileId => {
     return tile.Suit.id;
   }
 
+  if ("Animal" in tile) {
+    return tile.Animal.id;
+  }
+
   return null;
 };
@@ -1,6 +1,7 @@
 import type { GameSummary } from "bindings/GameSummary";
 import type { Hand } from "bindings/Hand";
 import type { HandTile } from "bindings/HandTile";
+import type { Metadata } from "bindings/Metadata";
 import type { ServiceGame } from "bindings/ServiceGame";
 import type { ServiceGameSummary } from "bindings/ServiceGameSummary";
 import type { ServicePlayerGame } from "bindings/ServicePlayerGame";
@@ -23,6 +24,7 @@ export type TAdminPostAIContinueRequest = {
 };
 export type TAdminPostAIContinueResponse = {
   changed: boolean;
+  metadata: Metadata[];
   service_game: ServiceGame;
 };
 
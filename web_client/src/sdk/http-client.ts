@@ -3,6 +3,10 @@ import type { AdminPostSortHandsResponse } from "bindings/AdminPostSortHandsResp
 import type { GetDeckResponse } from "bindings/GetDeckResponse";
 import type { Queries } from "bindings/Queries";
 import type { QueriesResponses } from "bindings/QueriesResponses";
+import type { UserGetGameHintsQuery } from "bindings/UserGetGameHintsQuery";
+import type { UserGetGameHintsResponse } from "bindings/UserGetGameHintsResponse";
+import type { UserGetGameReviewQuery } from "bindings/UserGetGameReviewQuery";
+import type { UserGetGameReviewResponse } from "bindings/UserGetGameReviewResponse";
 import type { UserGetInfoResponse } from "bindings/UserGetInfoResponse";
 import type { UserGetLoadGameResponse } from "bindings/UserGetLoadGameResponse";
 import type { UserLoadGameQuery } from "bindings/UserLoadGameQuery";
@@ -10,6 +14,8 @@ import type { UserPatchInfoRequest } from "bindings/UserPatchInfoRequest";
 import type { UserPatchInfoResponse } from "bindings/UserPatchInfoResponse";
 import type { UserPostAIContinueRequest } from "bindings/UserPostAIContinueRequest";
 import type { UserPostAIContinueResponse } from "bindings/UserPostAIContinueResponse";
+import type { UserPostAbortiveDrawRequest } from "bindings/UserPostAbortiveDrawRequest";
+import type { UserPostAbortiveDrawResponse } from "bindings/UserPostAbortiveDrawResponse";
 import type { UserPostClaimTileRequest } from "bindings/UserPostClaimTileRequest";
 import type { UserPostClaimTileResponse } from "bindings/UserPostClaimTileResponse";
 import type { UserPostJoinGameResponse } from "bindings/UserPostJoinGameResponse";
@@ -267,6 +273,18 @@ export const HttpClient = {
     );
   },
 
+  userAbortiveDraw(gameId: GameId, body: UserPostAbortiveDrawRequest) {
+    return from(
+      fetchJson<UserPostAbortiveDrawResponse>(
+        `/v1/user/game/${gameId}/abortive-draw`,
+        {
+          body: JSON.stringify(body),
+          method: "POST",
+        },
+      ),
+    );
+  },
+
   userBreakMeld: userCommon<"UserBreakMeld">,
 
   userClaimTile(gameId: GameId, body: UserPostClaimTileRequest) {
@@ -301,6 +319,22 @@ export const HttpClient = {
 
   userDrawTile: userCommon<"UserDrawTile">,
 
+  userGetGameHints(gameId: GameId, query: UserGetGameHintsQuery) {
+    return from(
+      fetchJson<UserGetGameHintsResponse>(
+        `/v1/user/game/${gameId}/hints?${qs.stringify(query)}`,
+      ),
+    );
+  },
+
+  userGetGameReview(gameId: GameId, query: UserGetGameReviewQuery) {
+    return from(
+      fetchJson<UserGetGameReviewResponse>(
+        `/v1/user/game/${gameId}/review?${qs.stringify(query)}`,
+      ),
+    );
+  },
+
   userGetInfo(userId: PlayerId) {
     return from(fetchJson<UserGetInfoResponse>(`/v1/user/info/${userId}`));
   },
@@ -1,13 +1,17 @@
 import type { Deck } from "bindings/Deck";
+import type { LibGetDangerOverlayParam } from "bindings/LibGetDangerOverlayParam";
+import type { LibGetDangerOverlayReturn } from "bindings/LibGetDangerOverlayReturn";
 import type { LibGetGamePlayingExtrasParam } from "bindings/LibGetGamePlayingExtrasParam";
 import type { LibGetGamePlayingExtrasReturn } from "bindings/LibGetGamePlayingExtrasReturn";
 import type { LibGetIsMeldParam } from "bindings/LibGetIsMeldParam";
 import type { LibGetPossibleMeldsParam } from "bindings/LibGetPossibleMeldsParam";
 import type { LibGetPossibleMeldsReturn } from "bindings/LibGetPossibleMeldsReturn";
 import type { Tile } from "bindings/Tile";
+import type { TileFormat } from "bindings/TileFormat";
 
 import {
   format_tile,
+  get_danger_overlay,
   get_deck,
   get_game_playing_extras,
   get_possible_melds,
@@ -22,7 +26,8 @@ export const isPung = (param: LibGetIsMeldParam) => is_pung(param);
 export const isChow = (param: LibGetIsMeldParam) => is_chow(param);
 export const isKong = (param: LibGetIsMeldParam) => is_kong(param);
 
-export const formatTile = (tile: Tile) => format_tile(tile);
+export const formatTile = (tile: Tile, format: TileFormat = "Emoji") =>
+  format_tile(tile, format);
 
 export const getPossibleMelds = (
   param: LibGetPossibleMeldsParam,
@@ -44,3 +49,7 @@ export type PlayingExtrasParsed = Omit<
 export const getGamePlayingExtras = (
   param: LibGetGamePlayingExtrasParam,
 ): PlayingExtrasParsed => get_game_playing_extras(param);
+
+export const getDangerOverlay = (
+  param: LibGetDangerOverlayParam,
+): LibGetDangerOverlayReturn => get_danger_overlay(param);
@@ -173,4 +173,8 @@ export const getIsSameTile = (tileA: Tile, tileB: Tile) => {
       tileA.Suit.value === tileB.Suit.value
     );
   }
+
+  if ("Animal" in tileA) {
+    return "Animal" in tileB && tileA.Animal.value === tileB.Animal.value;
+  }
 };
@@ -1,5 +1,9 @@
 #![deny(clippy::use_self, clippy::shadow_unrelated)]
-use mahjong_core::{deck::DEFAULT_DECK, ui::format_to_emoji, Tile};
+use mahjong_core::{
+    deck::DEFAULT_DECK,
+    render::{render_tile, TileFormat},
+    Tile,
+};
 pub use melds::{get_possible_melds, is_chow, is_kong, is_pung};
 use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
@@ -8,10 +12,11 @@ mod offscreen_game;
 mod service_game_summary;
 
 #[wasm_bindgen]
-pub fn format_tile(tile: JsValue) -> String {
+pub fn format_tile(tile: JsValue, format: JsValue) -> String {
     let tile: Tile = serde_wasm_bindgen::from_value(tile).unwrap();
+    let format: Option<TileFormat> = serde_wasm_bindgen::from_value(format).unwrap();
 
-    format_to_emoji(&tile)
+    render_tile(&tile, format.unwrap_or(TileFormat::Emoji))
 }
 
 #[wasm_bindgen]
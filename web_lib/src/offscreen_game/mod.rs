@@ -1,6 +1,7 @@
 use js_sys::Date;
 use mahjong_core::{
-    deck::DEFAULT_DECK, score::ScoringRule, Game, Hand, HandTile, PlayerId, Players, TileId,
+    deck::DEFAULT_DECK, score::ScoringRule, Game, Hand, HandTile, PlayerId, Players, ScoreItem,
+    TileId,
 };
 use offscreen_player::{OffscreenPlayer, OffscreenPlayers};
 use selecting_hand::{SelectingHand, SelectingHandTile};
@@ -85,11 +86,11 @@ impl OffscreenGame {
         }
     }
 
-    pub fn update_player_score(&mut self, player_id: PlayerId, score: u32) {
+    pub fn update_player_score(&mut self, player_id: PlayerId, score: ScoreItem) {
         self.game.score.0.insert(player_id, score);
     }
 
-    pub fn get_player_score(&self, player_id: PlayerId) -> u32 {
+    pub fn get_player_score(&self, player_id: PlayerId) -> ScoreItem {
         if !self.game.score.0.contains_key(&player_id) {
             return 0;
         }
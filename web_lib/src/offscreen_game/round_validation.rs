@@ -30,11 +30,12 @@ impl IsValidRoundResult {
 impl OffscreenGame {
     pub fn get_is_valid_round(&self) -> IsValidRoundResult {
         let mut hand_with_mahjong: Option<PlayerId> = None;
+        let ruleset = self.game.get_ruleset();
 
         for player in self.game.players.iter() {
             let hand = self.game.table.hands.get(player).unwrap();
 
-            if hand.can_say_mahjong().map_or(false, |_| true) {
+            if hand.can_say_mahjong(&*ruleset).is_ok() {
                 hand_with_mahjong = Some(player.clone());
                 break;
             }
@@ -1,6 +1,9 @@
 use mahjong_core::{
-    game_summary::{HandTileStat, VisibleMeld},
+    danger::{OpponentReadiness, TileDanger},
+    game_summary::{HandTileStat, TileKindTracker, VisibleMeld},
+    hand::HandWait,
     meld::PossibleMeld,
+    shanten::ShantenAnalysis,
     PlayerId, TileId, Wind,
 };
 use rustc_hash::FxHashMap;
@@ -20,10 +23,15 @@ struct PlayingExtras {
     can_say_mahjong: bool,
     dealer_player: Option<ServicePlayerSummary>,
     hand_stats: FxHashMap<TileId, HandTileStat>,
+    // The waits, the shanten and the tracker are hints, so they are missing when the game settings
+    // disable them
+    hand_waits: Option<Vec<HandWait>>,
     players_visible_melds: FxHashMap<PlayerId, Vec<VisibleMeld>>,
     players_winds: FxHashMap<PlayerId, Wind>,
     playing_player: Option<ServicePlayerSummary>,
     possible_melds: Vec<PossibleMeld>,
+    shanten_analysis: Option<ShantenAnalysis>,
+    tiles_tracker: Option<Vec<TileKindTracker>>,
     turn_player: Option<ServicePlayerSummary>,
 }
 
@@ -57,6 +65,14 @@ pub fn get_game_playing_extras(param: JsValue) -> JsValue {
         .cloned();
     let turn_player = parsed_val.0.get_turn_player();
     let hand_stats = parsed_val.0.game_summary.get_hand_stats();
+    let hints_enabled = parsed_val.0.settings.hints_enabled;
+    let hand_waits = hints_enabled.then(|| parsed_val.0.game_summary.get_hand_waits());
+    let shanten_analysis = parsed_val
+        .0
+        .game_summary
+        .get_shanten_analysis()
+        .filter(|_| hints_enabled);
+    let tiles_tracker = hints_enabled.then(|| parsed_val.0.game_summary.get_tiles_tracker());
 
     let rv = LibGetGamePlayingExtrasReturn(PlayingExtras {
         can_claim_tile,
@@ -67,12 +83,47 @@ pub fn get_game_playing_extras(param: JsValue) -> JsValue {
         can_say_mahjong,
         dealer_player,
         hand_stats,
+        hand_waits,
         players_visible_melds,
         players_winds,
         playing_player,
         possible_melds,
+        shanten_analysis,
+        tiles_tracker,
         turn_player,
     });
 
     serde_wasm_bindgen::to_value(&rv).unwrap()
 }
+
+#[derive(TS, Serialize, Deserialize)]
+#[ts(export)]
+struct DangerOverlay {
+    opponents_readiness: Vec<OpponentReadiness>,
+    tiles_danger: Vec<TileDanger>,
+}
+
+#[derive(TS, Serialize, Deserialize)]
+#[ts(export)]
+struct LibGetDangerOverlayParam(ServiceGameSummary);
+
+#[derive(TS, Serialize, Deserialize)]
+#[ts(export)]
+struct LibGetDangerOverlayReturn(Option<DangerOverlay>);
+
+// Separated from the playing extras because it is optional, for training the players. It is
+// missing when the game settings disable the hints.
+#[wasm_bindgen]
+pub fn get_danger_overlay(param: JsValue) -> JsValue {
+    let parsed_val: LibGetDangerOverlayParam = serde_wasm_bindgen::from_value(param).unwrap();
+
+    let game_summary = &parsed_val.0.game_summary;
+    let overlay = parsed_val.0.settings.hints_enabled.then(|| DangerOverlay {
+        opponents_readiness: game_summary.get_opponents_readiness(),
+        tiles_danger: game_summary.get_tiles_danger(),
+    });
+
+    let rv = LibGetDangerOverlayReturn(overlay);
+
+    serde_wasm_bindgen::to_value(&rv).unwrap()
+}